@@ -0,0 +1,239 @@
+use anyhow::{Context, Result};
+use clap::{Arg, Command, ValueEnum};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::CleanStrategyArg;
+
+/// 全局配置文件（`<config_dir>/purger/config.toml`，Linux 上为 `~/.config/purger/config.toml`）
+///
+/// 其中的值作为 scan/clean/watch 参数的默认值，命令行参数和 `PURGER_*` 环境变量优先。
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub(crate) struct Config {
+    pub max_depth: Option<usize>,
+    pub keep_days: Option<u32>,
+    pub older_than: Option<String>,
+    pub keep_size: Option<String>,
+    pub min_size: Option<String>,
+    pub ignore: Vec<PathBuf>,
+    pub exclude: Vec<String>,
+    pub jobs: Option<usize>,
+    /// 只对 clean/watch 生效
+    pub strategy: Option<CleanStrategyArg>,
+}
+
+impl Config {
+    /// 默认位置的配置文件，无法确定系统配置目录时为 `None`
+    pub(crate) fn default_path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("purger").join("config.toml"))
+    }
+
+    /// 读取默认位置的配置文件，文件不存在时使用空配置
+    pub(crate) fn load_default() -> Result<Self> {
+        match Self::default_path() {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// 读取配置文件，文件不存在时使用空配置
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("读取配置文件失败: {}", path.display()));
+            }
+        };
+        toml::from_str(&content).with_context(|| format!("解析配置文件失败: {}", path.display()))
+    }
+
+    /// 把配置写成各子命令同名参数的默认值
+    pub(crate) fn apply_defaults(&self, mut command: Command) -> Command {
+        let names: Vec<String> = command
+            .get_subcommands()
+            .map(|sub| sub.get_name().to_string())
+            .collect();
+        for name in names {
+            command = command.mut_subcommand(name, |sub| sub.mut_args(|arg| self.apply_to(arg)));
+        }
+        command
+    }
+
+    fn apply_to(&self, arg: Arg) -> Arg {
+        let value = match arg.get_id().as_str() {
+            "max_depth" => self.max_depth.map(|v| v.to_string()),
+            "keep_days" => self.keep_days.map(|v| v.to_string()),
+            "older_than" => self.older_than.clone(),
+            "keep_size" => self.keep_size.clone(),
+            "min_size" => self.min_size.clone(),
+            "jobs" => self.jobs.map(|v| v.to_string()),
+            "strategy" => self
+                .strategy
+                .as_ref()
+                .and_then(|s| s.to_possible_value())
+                .map(|v| v.get_name().to_string()),
+            "ignore_paths" if !self.ignore.is_empty() => {
+                return arg.default_values(self.ignore.iter().map(|p| p.as_os_str().to_owned()));
+            }
+            "exclude_patterns" if !self.exclude.is_empty() => {
+                return arg.default_values(self.exclude.clone());
+            }
+            _ => None,
+        };
+        match value {
+            Some(value) => arg.default_value(value),
+            None => arg,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cli, Commands};
+    use clap::{CommandFactory, FromArgMatches};
+    use tempfile::TempDir;
+
+    fn parse(config: &Config, args: &[&str]) -> Cli {
+        let matches = config
+            .apply_defaults(Cli::command())
+            .try_get_matches_from(args)
+            .unwrap();
+        Cli::from_arg_matches(&matches).unwrap()
+    }
+
+    #[test]
+    fn test_config_defaults_and_precedence() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+keep-days = 14
+strategy = "direct-delete"
+ignore = ["/data/vendor"]
+exclude = ["**/fixtures/**"]
+"#,
+        )?;
+        let config = Config::load(&path)?;
+
+        let Commands::Clean {
+            keep_days,
+            strategy,
+            ignore_paths,
+            exclude_patterns,
+            ..
+        } = parse(&config, &["purger", "clean", "--keep-days", "3"]).command
+        else {
+            panic!("expected clean");
+        };
+        // 命令行参数覆盖配置文件，其余取配置文件中的值
+        assert_eq!(keep_days, Some(3));
+        assert!(matches!(strategy, CleanStrategyArg::DirectDelete));
+        assert_eq!(ignore_paths, vec![PathBuf::from("/data/vendor")]);
+        assert_eq!(exclude_patterns, vec!["**/fixtures/**".to_string()]);
+
+        let Commands::Clean {
+            strategy,
+            ignore_paths,
+            ..
+        } = parse(
+            &config,
+            &["purger", "clean", "-S", "trash", "--ignore", "/other"],
+        )
+        .command
+        else {
+            panic!("expected clean");
+        };
+        assert!(matches!(strategy, CleanStrategyArg::Trash));
+        assert_eq!(ignore_paths, vec![PathBuf::from("/other")]);
+
+        // scan 没有 --strategy，只应用共有的选项
+        let Commands::Scan { keep_days, .. } = parse(&config, &["purger", "scan"]).command else {
+            panic!("expected scan");
+        };
+        assert_eq!(keep_days, Some(14));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_satisfies_argument_requirements() -> Result<()> {
+        // 配置文件中的 older-than 满足 --remove-if-stale 的要求
+        let config = Config {
+            older_than: Some("30d".to_string()),
+            ..Default::default()
+        };
+        let cli = parse(&config, &["purger", "clean", "--remove-if-stale"]);
+        cli.command.validate()?;
+        let cli = parse(
+            &Config::default(),
+            &["purger", "clean", "--remove-if-stale"],
+        );
+        assert!(cli.command.validate().is_err());
+
+        // 配置文件中的策略同样要和 --package 等选项匹配
+        let config = Config {
+            strategy: Some(CleanStrategyArg::DirectDelete),
+            ..Default::default()
+        };
+        let cli = parse(&config, &["purger", "clean", "--package", "foo"]);
+        assert!(cli.command.validate().is_err());
+        let cli = parse(&config, &["purger", "clean", "--profile", "debug"]);
+        cli.command.validate()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_ignore_keeps_projects() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        for name in ["keep", "other"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target"))?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+        }
+        let config = Config {
+            ignore: vec![temp_dir.path().join("keep")],
+            ..Default::default()
+        };
+        let root = temp_dir.path().to_string_lossy().to_string();
+        let Commands::Clean { ignore_paths, .. } =
+            parse(&config, &["purger", "clean", &root]).command
+        else {
+            panic!("expected clean");
+        };
+
+        // 配置文件中的 ignore 和 --ignore 一样：被忽略的项目不清理
+        let scan_config = crate::create_scan_config(crate::ScanConfigArgs {
+            ignore_paths,
+            ..crate::tests::default_scan_args()
+        })?;
+        let projects =
+            crate::find_clean_candidates(&scan_config, &[temp_dir.path().to_path_buf()])?;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["other"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_missing_or_invalid() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let config = Config::load(&temp_dir.path().join("missing.toml"))?;
+        assert!(config.keep_days.is_none() && config.ignore.is_empty());
+
+        let path = temp_dir.path().join("config.toml");
+        std::fs::write(&path, "keep-dayz = 3\n")?;
+        assert!(Config::load(&path).is_err());
+        std::fs::write(&path, "strategy = \"shred\"\n")?;
+        assert!(Config::load(&path).is_err());
+
+        Ok(())
+    }
+}
@@ -1,63 +1,207 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::io::{self, Write};
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime};
+
+mod config;
+mod metrics;
+mod watch;
 
 use purger_core::{
-    CleanStrategy, DirectDeleteBackend, ProjectCleaner, ProjectFilter, ProjectScanner,
-    cleaner::CleanConfig, scanner::ScanConfig,
+    BatchCleanEvent, CleanProgress, CleanResult, CleanStrategy, DirectDeleteBackend,
+    ExecutableFilter, ProjectCleanOutcome, ProjectCleaner, ProjectFilter, ProjectScanner,
+    RustProject, SizeMode, SortKey, TimeBasis, cleaner::CleanConfig, scanner::ScanConfig,
 };
 
 /// 扫描命令的参数配置
 #[derive(Debug)]
 struct ScanCommandArgs {
-    path: PathBuf,
+    paths: Vec<PathBuf>,
     max_depth: Option<usize>,
+    min_depth: Option<usize>,
     target_only: bool,
+    orphans: bool,
     sort_by_size: bool,
+    sort: Option<SortKeyArg>,
+    reverse: bool,
     keep_days: Option<u32>,
+    older_than: Option<String>,
+    time_basis: TimeBasisArg,
+    size_mode: SizeModeArg,
     keep_size: Option<String>,
+    min_size: Option<String>,
     ignore_paths: Vec<PathBuf>,
+    manifest_globs: Vec<String>,
+    extra_target_names: Vec<String>,
+    exclude_patterns: Vec<String>,
     no_parallel: bool,
+    jobs: Option<usize>,
+    no_cache: bool,
     follow_symlinks: bool,
     include_hidden: bool,
     no_gitignore: bool,
+    no_purgerignore: bool,
+    force: bool,
+    verbose: bool,
+    print0: bool,
+    total_only: bool,
+    group_by: Option<GroupByArg>,
+    breakdown: Option<usize>,
+    format: OutputFormat,
 }
 
 /// 清理命令的参数配置
 #[derive(Debug)]
 struct CleanCommandArgs {
-    path: PathBuf,
+    paths: Vec<PathBuf>,
     max_depth: Option<usize>,
+    min_depth: Option<usize>,
     strategy: CleanStrategyArg,
     direct_delete_backend: DirectDeleteBackendArg,
+    profiles: Vec<String>,
+    triples: Vec<String>,
+    packages: Vec<String>,
     dry_run: bool,
     keep_days: Option<u32>,
+    older_than: Option<String>,
+    time_basis: TimeBasisArg,
+    size_mode: SizeModeArg,
     keep_size: Option<String>,
+    min_size: Option<String>,
     ignore_paths: Vec<PathBuf>,
+    manifest_globs: Vec<String>,
+    extra_target_names: Vec<String>,
+    exclude_patterns: Vec<String>,
     no_parallel: bool,
+    jobs: Option<usize>,
+    no_cache: bool,
     follow_symlinks: bool,
     include_hidden: bool,
     no_gitignore: bool,
+    no_purgerignore: bool,
+    force: bool,
     yes: bool,
     keep_executable: bool,
     executable_backup_dir: Option<PathBuf>,
+    executable_filter: ExecutableFilterArg,
     timeout: u64,
+    only_if_helps: bool,
+    free_threshold: String,
+    metrics_file: Option<PathBuf>,
+    repair: bool,
+    max_delete_files: Option<usize>,
+    max_delete_bytes: Option<String>,
+    show_files: bool,
+    by_member: bool,
+    format: OutputFormat,
+    progress: Option<ProgressFormat>,
+    interactive: bool,
+    confirm_each: bool,
+    only: Vec<String>,
+    only_globs: Vec<String>,
+    ignore_failures: bool,
+    remove_if_stale: bool,
+    verbose: bool,
 }
 
-/// 扫描配置创建参数
-#[derive(Debug)]
-struct ScanConfigArgs {
+/// 扫描配置创建参数（watch 直接作为命令行参数使用）
+#[derive(Debug, Args)]
+pub struct ScanConfigArgs {
+    /// Maximum depth to scan
+    #[arg(short, long, env = "PURGER_MAX_DEPTH")]
     max_depth: Option<usize>,
+
+    /// Minimum depth (relative to PATH) of project directories to include; PATH itself is depth 0
+    #[arg(long, env = "PURGER_MIN_DEPTH")]
+    min_depth: Option<usize>,
+
+    /// Keep projects compiled in the last N days
+    #[arg(short = 'k', long, env = "PURGER_KEEP_DAYS")]
     keep_days: Option<u32>,
+
+    /// Clean projects not compiled within this duration, e.g. 30d (required unless --keep-days is given)
+    #[arg(long, value_name = "DURATION")]
+    older_than: Option<String>,
+
+    /// What "last compiled" is based on for --keep-days
+    #[arg(long, value_enum, default_value = "dir-mtime")]
+    time_basis: TimeBasisArg,
+
+    /// How target sizes are measured (`on-disk` counts allocated blocks on Unix, closer to the space actually freed)
+    #[arg(long, value_enum, default_value = "apparent")]
+    size_mode: SizeModeArg,
+
+    /// Keep projects with target size smaller than this
+    #[arg(short = 's', long, env = "PURGER_KEEP_SIZE")]
     keep_size: Option<String>,
+
+    /// Only include projects whose target is at least this large
+    #[arg(long)]
+    min_size: Option<String>,
+
+    /// Paths to ignore (can be specified multiple times; PURGER_IGNORE is a path list)
+    #[arg(
+        short = 'i',
+        long = "ignore",
+        action = clap::ArgAction::Append,
+        env = "PURGER_IGNORE",
+        value_delimiter = PATH_LIST_SEPARATOR
+    )]
     ignore_paths: Vec<PathBuf>,
+
+    /// Only treat Cargo.toml files matching this glob as projects (can be specified multiple times)
+    #[arg(long = "manifest-glob", action = clap::ArgAction::Append)]
+    manifest_globs: Vec<String>,
+
+    /// Also treat this directory (relative to each project, e.g. `target-wasm`) as build output to size and clean (can be specified multiple times)
+    #[arg(long = "extra-target", value_name = "NAME", action = clap::ArgAction::Append)]
+    extra_target_names: Vec<String>,
+
+    /// Skip directories matching this glob, e.g. `**/vendor/**` (can be specified multiple times)
+    #[arg(long = "exclude", action = clap::ArgAction::Append)]
+    exclude_patterns: Vec<String>,
+
+    /// Disable parallel processing
+    #[arg(long)]
     no_parallel: bool,
+
+    /// Number of worker threads for parallel scanning/cleaning (default: automatic)
+    #[arg(short = 'j', long)]
+    jobs: Option<usize>,
+
+    /// Don't read or update the on-disk cache of target sizes
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Follow symlinks
+    #[arg(long)]
     follow_symlinks: bool,
+
+    /// Don't ignore hidden files/directories
+    #[arg(long)]
     include_hidden: bool,
+
+    /// Don't respect .gitignore files
+    #[arg(long)]
     no_gitignore: bool,
+
+    /// Don't respect .purgerignore files
+    #[arg(long)]
+    no_purgerignore: bool,
+
+    /// Allow operating on protected system paths (e.g. `/`, `/usr`, `C:\Windows`)
+    #[arg(long)]
+    force: bool,
 }
 
+/// `PURGER_IGNORE` 等路径列表环境变量的分隔符（与 `PATH` 一致）
+const PATH_LIST_SEPARATOR: char = if cfg!(windows) { ';' } else { ':' };
+
 #[derive(Parser)]
 #[command(name = "purger")]
 #[command(about = "A tool for cleaning Rust project build directories")]
@@ -75,42 +219,102 @@ pub struct Cli {
     pub debug: bool,
 }
 
+// clap 的子命令参数直接作为字段展开，`Clean` 的参数远多于其他子命令
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 pub enum Commands {
     /// Scan for Rust projects in a directory
     Scan {
-        /// Directory to scan
-        #[arg(default_value = ".")]
-        path: PathBuf,
+        /// Directories to scan (projects found under several of them are listed once)
+        #[arg(default_value = ".", value_name = "PATH")]
+        paths: Vec<PathBuf>,
 
         /// Maximum depth to scan
-        #[arg(short, long)]
+        #[arg(short, long, env = "PURGER_MAX_DEPTH")]
         max_depth: Option<usize>,
 
+        /// Minimum depth (relative to PATH) of project directories to include; PATH itself is depth 0
+        #[arg(long, env = "PURGER_MIN_DEPTH")]
+        min_depth: Option<usize>,
+
         /// Show only projects with target directories
         #[arg(short, long)]
         target_only: bool,
 
-        /// Sort by size (largest first)
-        #[arg(short = 'S', long)]
+        /// Report cargo `target` directories whose parent has no (or an unparseable) Cargo.toml instead of projects
+        #[arg(long)]
+        orphans: bool,
+
+        /// Sort by size (largest first); same as `--sort size --reverse`
+        #[arg(short = 'S', long, conflicts_with = "sort")]
         sort_by_size: bool,
 
+        /// Sort projects by this key (ascending; projects without a target come last when sorting by size)
+        #[arg(long, value_enum, value_name = "KEY")]
+        sort: Option<SortKeyArg>,
+
+        /// Reverse the order of --sort
+        #[arg(long, requires = "sort")]
+        reverse: bool,
+
         /// Keep projects compiled in the last N days
-        #[arg(short = 'k', long)]
+        #[arg(short = 'k', long, env = "PURGER_KEEP_DAYS")]
         keep_days: Option<u32>,
 
+        /// Keep projects compiled within this duration, e.g. 36h, 90d, 2w, 6mo (with --keep-days the longer window wins)
+        #[arg(long, value_name = "DURATION")]
+        older_than: Option<String>,
+
+        /// What "last compiled" is based on for --keep-days
+        #[arg(long, value_enum, default_value = "dir-mtime")]
+        time_basis: TimeBasisArg,
+
+        /// How target sizes are measured (`on-disk` counts allocated blocks on Unix, closer to the space actually freed)
+        #[arg(long, value_enum, default_value = "apparent")]
+        size_mode: SizeModeArg,
+
         /// Keep projects with target size smaller than this
-        #[arg(short = 's', long)]
+        #[arg(short = 's', long, env = "PURGER_KEEP_SIZE")]
         keep_size: Option<String>,
 
-        /// Paths to ignore (can be specified multiple times)
-        #[arg(short = 'i', long = "ignore", action = clap::ArgAction::Append)]
+        /// Only include projects whose target is at least this large
+        #[arg(long)]
+        min_size: Option<String>,
+
+        /// Paths to ignore (can be specified multiple times; PURGER_IGNORE is a path list)
+        #[arg(
+            short = 'i',
+            long = "ignore",
+            action = clap::ArgAction::Append,
+            env = "PURGER_IGNORE",
+            value_delimiter = PATH_LIST_SEPARATOR
+        )]
         ignore_paths: Vec<PathBuf>,
 
+        /// Only treat Cargo.toml files matching this glob as projects (can be specified multiple times)
+        #[arg(long = "manifest-glob", action = clap::ArgAction::Append)]
+        manifest_globs: Vec<String>,
+
+        /// Also treat this directory (relative to each project, e.g. `target-wasm`) as build output to size and clean (can be specified multiple times)
+        #[arg(long = "extra-target", value_name = "NAME", action = clap::ArgAction::Append)]
+        extra_target_names: Vec<String>,
+
+        /// Skip directories matching this glob, e.g. `**/vendor/**` (can be specified multiple times)
+        #[arg(long = "exclude", action = clap::ArgAction::Append)]
+        exclude_patterns: Vec<String>,
+
         /// Disable parallel scanning
         #[arg(long)]
         no_parallel: bool,
 
+        /// Number of worker threads for parallel scanning/cleaning (default: automatic)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
+
+        /// Don't read or update the on-disk cache of target sizes
+        #[arg(long)]
+        no_cache: bool,
+
         /// Follow symlinks
         #[arg(long)]
         follow_symlinks: bool,
@@ -122,45 +326,142 @@ pub enum Commands {
         /// Don't respect .gitignore files
         #[arg(long)]
         no_gitignore: bool,
+
+        /// Don't respect .purgerignore files
+        #[arg(long)]
+        no_purgerignore: bool,
+
+        /// Allow operating on protected system paths (e.g. `/`, `/usr`, `C:\Windows`)
+        #[arg(long)]
+        force: bool,
+
+        /// Print only absolute project paths separated by NUL (for `xargs -0`)
+        #[arg(long)]
+        print0: bool,
+
+        /// Print only the total reclaimable size of the matching projects
+        #[arg(long, conflicts_with = "print0")]
+        total_only: bool,
+
+        /// After the project list, print the reclaimable size per group, largest first
+        #[arg(long, value_enum, value_name = "GROUP", conflicts_with_all = ["print0", "total_only"])]
+        group_by: Option<GroupByArg>,
+
+        /// After the project list, print the N largest subdirectories of each target (default 5)
+        #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "5", conflicts_with_all = ["print0", "total_only"])]
+        breakdown: Option<usize>,
+
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value = "table",
+            conflicts_with_all = ["print0", "total_only"]
+        )]
+        format: OutputFormat,
     },
     /// Clean Rust projects
     Clean {
-        /// Directory to scan and clean
-        #[arg(default_value = ".")]
-        path: PathBuf,
+        /// Directories to scan and clean (projects found under several of them are cleaned once)
+        #[arg(default_value = ".", value_name = "PATH")]
+        paths: Vec<PathBuf>,
 
         /// Maximum depth to scan
-        #[arg(short, long)]
+        #[arg(short, long, env = "PURGER_MAX_DEPTH")]
         max_depth: Option<usize>,
 
+        /// Minimum depth (relative to PATH) of project directories to include; PATH itself is depth 0
+        #[arg(long, env = "PURGER_MIN_DEPTH")]
+        min_depth: Option<usize>,
+
         /// Clean strategy
-        #[arg(short = 'S', long, value_enum, default_value = "cargo-clean")]
+        #[arg(
+            short = 'S',
+            long,
+            value_enum,
+            default_value = "cargo-clean",
+            env = "PURGER_STRATEGY"
+        )]
         strategy: CleanStrategyArg,
 
         /// Direct-delete backend (Windows turbo mode via cmd rmdir)
         #[arg(long, value_enum, default_value = "native")]
         direct_delete_backend: DirectDeleteBackendArg,
 
+        /// Only remove target/<PROFILE> (and target/<triple>/<PROFILE>); repeatable, needs --strategy direct-delete
+        #[arg(long = "profile", value_name = "PROFILE")]
+        profiles: Vec<String>,
+
+        /// Only remove target/<TRIPLE> (combined with --profile: target/<TRIPLE>/<PROFILE>); repeatable, needs --strategy direct-delete
+        #[arg(long = "triple", value_name = "TRIPLE")]
+        triples: Vec<String>,
+
+        /// For workspaces, only clean this member package via `cargo clean -p`; repeatable, needs --strategy cargo-clean
+        #[arg(long = "package", value_name = "PKG")]
+        packages: Vec<String>,
+
         /// Dry run - show what would be cleaned without actually cleaning
         #[arg(short = 'n', long)]
         dry_run: bool,
 
         /// Keep projects compiled in the last N days
-        #[arg(short = 'k', long)]
+        #[arg(short = 'k', long, env = "PURGER_KEEP_DAYS")]
         keep_days: Option<u32>,
 
+        /// Keep projects compiled within this duration, e.g. 36h, 90d, 2w, 6mo (with --keep-days the longer window wins)
+        #[arg(long, value_name = "DURATION")]
+        older_than: Option<String>,
+
+        /// What "last compiled" is based on for --keep-days
+        #[arg(long, value_enum, default_value = "dir-mtime")]
+        time_basis: TimeBasisArg,
+
+        /// How target sizes are measured (`on-disk` counts allocated blocks on Unix, closer to the space actually freed)
+        #[arg(long, value_enum, default_value = "apparent")]
+        size_mode: SizeModeArg,
+
         /// Keep projects with target size smaller than this
-        #[arg(short = 's', long)]
+        #[arg(short = 's', long, env = "PURGER_KEEP_SIZE")]
         keep_size: Option<String>,
 
-        /// Paths to ignore (can be specified multiple times)
-        #[arg(short = 'i', long = "ignore", action = clap::ArgAction::Append)]
+        /// Only include projects whose target is at least this large
+        #[arg(long)]
+        min_size: Option<String>,
+
+        /// Paths to ignore (can be specified multiple times; PURGER_IGNORE is a path list)
+        #[arg(
+            short = 'i',
+            long = "ignore",
+            action = clap::ArgAction::Append,
+            env = "PURGER_IGNORE",
+            value_delimiter = PATH_LIST_SEPARATOR
+        )]
         ignore_paths: Vec<PathBuf>,
 
+        /// Only treat Cargo.toml files matching this glob as projects (can be specified multiple times)
+        #[arg(long = "manifest-glob", action = clap::ArgAction::Append)]
+        manifest_globs: Vec<String>,
+
+        /// Also treat this directory (relative to each project, e.g. `target-wasm`) as build output to size and clean (can be specified multiple times)
+        #[arg(long = "extra-target", value_name = "NAME", action = clap::ArgAction::Append)]
+        extra_target_names: Vec<String>,
+
+        /// Skip directories matching this glob, e.g. `**/vendor/**` (can be specified multiple times)
+        #[arg(long = "exclude", action = clap::ArgAction::Append)]
+        exclude_patterns: Vec<String>,
+
         /// Disable parallel processing
         #[arg(long)]
         no_parallel: bool,
 
+        /// Number of worker threads for parallel scanning/cleaning (default: automatic)
+        #[arg(short = 'j', long)]
+        jobs: Option<usize>,
+
+        /// Don't read or update the on-disk cache of target sizes
+        #[arg(long)]
+        no_cache: bool,
+
         /// Follow symlinks
         #[arg(long)]
         follow_symlinks: bool,
@@ -173,6 +474,14 @@ pub enum Commands {
         #[arg(long)]
         no_gitignore: bool,
 
+        /// Don't respect .purgerignore files
+        #[arg(long)]
+        no_purgerignore: bool,
+
+        /// Allow operating on protected system paths (e.g. `/`, `/usr`, `C:\Windows`)
+        #[arg(long)]
+        force: bool,
+
         /// Skip confirmation prompt
         #[arg(short = 'y', long)]
         yes: bool,
@@ -185,13 +494,126 @@ pub enum Commands {
         #[arg(long)]
         executable_backup_dir: Option<PathBuf>,
 
+        /// Which executables --keep-executable backs up
+        #[arg(long, value_enum, default_value = "any", requires = "keep_executable")]
+        executable_filter: ExecutableFilterArg,
+
         /// Timeout for each project clean operation (seconds)
-        #[arg(long, default_value = "0")]
+        #[arg(long, default_value = "0", env = "PURGER_TIMEOUT")]
         timeout: u64,
+
+        /// Only clean projects whose target lives on a volume low on free space
+        #[arg(long)]
+        only_if_helps: bool,
+
+        /// Free-space threshold for --only-if-helps (volumes with more free space are skipped)
+        #[arg(long, default_value = "10GB", requires = "only_if_helps")]
+        free_threshold: String,
+
+        /// Write Prometheus textfile-collector metrics for this run to PATH
+        #[arg(long)]
+        metrics_file: Option<PathBuf>,
+
+        /// Only force-remove partial/stuck targets left by an interrupted clean or build
+        #[arg(long)]
+        repair: bool,
+
+        /// Skip (and warn about) projects whose target has more files than this, unless --force
+        #[arg(long)]
+        max_delete_files: Option<usize>,
+
+        /// Skip (and warn about) projects whose target is larger than this, unless --force
+        #[arg(long)]
+        max_delete_bytes: Option<String>,
+
+        /// With --dry-run, list every file/directory that would be removed, grouped by project
+        #[arg(long, visible_alias = "list", requires = "dry_run")]
+        show_files: bool,
+
+        /// For workspaces, break the freed space down by member crate (via `cargo metadata`)
+        #[arg(long)]
+        by_member: bool,
+
+        /// Output format (`json` prints the clean result on stdout and skips the confirmation prompt)
+        #[arg(
+            long,
+            value_enum,
+            default_value = "table",
+            conflicts_with_all = ["show_files", "by_member"]
+        )]
+        format: OutputFormat,
+
+        /// Stream live progress events to stderr while cleaning (projects are then cleaned one at a time)
+        #[arg(long, value_enum, value_name = "FORMAT", conflicts_with = "repair")]
+        progress: Option<ProgressFormat>,
+
+        /// Ask about each project (y/n/a/q) and only clean the ones selected
+        #[arg(long, conflicts_with_all = ["yes", "format"])]
+        interactive: bool,
+
+        /// Pause before each project while cleaning, showing its target path and size (y/n/a/q)
+        #[arg(long, conflicts_with_all = ["format", "interactive", "progress", "repair"])]
+        confirm_each: bool,
+
+        /// Clean every project matching the filters (the default; cannot be combined with per-project selection)
+        #[arg(long, conflicts_with_all = ["interactive", "confirm_each"])]
+        all: bool,
+
+        /// Only clean the project with this exact name (can be specified multiple times)
+        #[arg(long, value_name = "NAME")]
+        only: Vec<String>,
+
+        /// Only clean projects whose name matches this glob, e.g. `demo-*` (can be specified multiple times)
+        #[arg(long, value_name = "PATTERN")]
+        only_glob: Vec<String>,
+
+        /// Exit with status 0 even if some projects failed to clean
+        #[arg(long)]
+        ignore_failures: bool,
+
+        /// After cleaning, delete the whole directory of projects not compiled within --older-than (asks separately, --yes does not skip it)
+        #[arg(long)]
+        remove_if_stale: bool,
+    },
+    /// Periodically rescan and clean targets of projects not compiled recently; runs until interrupted (Ctrl+C)
+    Watch {
+        /// Directories to watch
+        #[arg(default_value = ".", value_name = "PATH")]
+        paths: Vec<PathBuf>,
+
+        /// Time between scans, e.g. 30m, 6h, 1d
+        #[arg(long, default_value = "6h", value_name = "DURATION")]
+        interval: String,
+
+        /// Clean strategy
+        #[arg(short = 'S', long, value_enum, default_value = "direct-delete")]
+        strategy: CleanStrategyArg,
+
+        /// Only report what each round would clean
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+
+        /// Skip the confirmation before the first clean
+        #[arg(short = 'y', long)]
+        yes: bool,
+
+        #[command(flatten)]
+        scan: ScanConfigArgs,
+    },
+    /// Restore executables backed up by `clean --keep-executable`
+    Restore {
+        /// Project directory whose executables should be restored
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Directory the executables were backed up to (same as `clean --executable-backup-dir`)
+        #[arg(long)]
+        executable_backup_dir: Option<PathBuf>,
     },
 }
 
-#[derive(Debug, Clone, ValueEnum)]
+#[derive(Debug, Clone, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum CleanStrategyArg {
     /// Use cargo clean command
     #[value(name = "cargo-clean")]
@@ -199,6 +621,26 @@ pub enum CleanStrategyArg {
     /// Directly delete target directories
     #[value(name = "direct-delete")]
     DirectDelete,
+    /// Move target directories to the system recycle bin
+    #[value(name = "trash")]
+    Trash,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable table
+    #[value(name = "table")]
+    Table,
+    /// JSON on stdout (for scripting, e.g. piping to `jq`)
+    #[value(name = "json")]
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ProgressFormat {
+    /// One JSON object per line for each progress/completion event
+    #[value(name = "ndjson")]
+    Ndjson,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -211,11 +653,95 @@ pub enum DirectDeleteBackendArg {
     CmdRmdir,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TimeBasisArg {
+    /// Modification time of the target directory itself
+    #[value(name = "dir-mtime")]
+    DirMtime,
+    /// Newest modification time of any file inside target (slower, walks target)
+    #[value(name = "newest-file-mtime")]
+    NewestFileMtime,
+}
+
+impl From<TimeBasisArg> for TimeBasis {
+    fn from(arg: TimeBasisArg) -> Self {
+        match arg {
+            TimeBasisArg::DirMtime => TimeBasis::DirMtime,
+            TimeBasisArg::NewestFileMtime => TimeBasis::NewestFileMtime,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SizeModeArg {
+    /// Sum of file lengths
+    #[value(name = "apparent")]
+    Apparent,
+    /// Allocated disk blocks (Unix; same as apparent elsewhere)
+    #[value(name = "on-disk")]
+    OnDisk,
+}
+
+impl From<SizeModeArg> for SizeMode {
+    fn from(arg: SizeModeArg) -> Self {
+        match arg {
+            SizeModeArg::Apparent => SizeMode::Apparent,
+            SizeModeArg::OnDisk => SizeMode::OnDisk,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExecutableFilterArg {
+    /// Only binaries named after the package, its bin targets or those of workspace members
+    #[value(name = "crate-binaries")]
+    CrateBinaries,
+    /// Every file with the executable bit (`.exe` on Windows)
+    #[value(name = "any")]
+    Any,
+}
+
+impl From<ExecutableFilterArg> for ExecutableFilter {
+    fn from(arg: ExecutableFilterArg) -> Self {
+        match arg {
+            ExecutableFilterArg::CrateBinaries => ExecutableFilter::CrateBinaries,
+            ExecutableFilterArg::Any => ExecutableFilter::Any,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupByArg {
+    /// First path component under the scan root
+    #[value(name = "top-level")]
+    TopLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SortKeyArg {
+    Name,
+    Size,
+    Modified,
+    Path,
+}
+
+impl From<SortKeyArg> for SortKey {
+    fn from(arg: SortKeyArg) -> Self {
+        match arg {
+            SortKeyArg::Name => SortKey::Name,
+            SortKeyArg::Size => SortKey::Size,
+            SortKeyArg::Modified => SortKey::Modified,
+            SortKeyArg::Path => SortKey::Path,
+        }
+    }
+}
+
 impl From<CleanStrategyArg> for CleanStrategy {
     fn from(arg: CleanStrategyArg) -> Self {
         match arg {
             CleanStrategyArg::CargoClean => CleanStrategy::CargoClean,
             CleanStrategyArg::DirectDelete => CleanStrategy::DirectDelete,
+            CleanStrategyArg::Trash => CleanStrategy::Trash,
         }
     }
 }
@@ -230,7 +756,11 @@ impl From<DirectDeleteBackendArg> for DirectDeleteBackend {
 }
 
 pub fn run_cli() -> Result<()> {
-    let cli = Cli::parse();
+    // 配置文件中的值作为参数默认值，命令行参数和环境变量优先
+    let config = config::Config::load_default()?;
+    let matches = config.apply_defaults(Cli::command()).get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    cli.command.validate()?;
 
     // 设置日志级别
     let log_level = if cli.debug {
@@ -241,88 +771,251 @@ pub fn run_cli() -> Result<()> {
         "warn"
     };
 
+    // 日志写到 stderr，保证 stdout 只包含命令输出（便于 --format json / --print0 管道处理）
     tracing_subscriber::fmt()
         .with_env_filter(format!("purger={log_level}"))
+        .with_writer(io::stderr)
         .init();
 
     match cli.command {
         Commands::Scan {
-            path,
+            paths,
             max_depth,
+            min_depth,
             target_only,
+            orphans,
             sort_by_size,
+            sort,
+            reverse,
             keep_days,
+            older_than,
+            time_basis,
+            size_mode,
             keep_size,
+            min_size,
             ignore_paths,
+            manifest_globs,
+            extra_target_names,
+            exclude_patterns,
             no_parallel,
+            jobs,
+            no_cache,
             follow_symlinks,
             include_hidden,
             no_gitignore,
+            no_purgerignore,
+            force,
+            print0,
+            total_only,
+            group_by,
+            breakdown,
+            format,
         } => handle_scan_command(ScanCommandArgs {
-            path,
+            paths,
             max_depth,
+            min_depth,
             target_only,
+            orphans,
             sort_by_size,
+            sort,
+            reverse,
             keep_days,
+            older_than,
+            time_basis,
+            size_mode,
             keep_size,
+            min_size,
             ignore_paths,
+            manifest_globs,
+            extra_target_names,
+            exclude_patterns,
             no_parallel,
+            jobs,
+            no_cache,
             follow_symlinks,
             include_hidden,
             no_gitignore,
+            no_purgerignore,
+            force,
+            verbose: cli.verbose,
+            print0,
+            total_only,
+            group_by,
+            breakdown,
+            format,
         }),
         Commands::Clean {
-            path,
+            paths,
             max_depth,
+            min_depth,
             strategy,
             direct_delete_backend,
+            profiles,
+            triples,
+            packages,
             dry_run,
             keep_days,
+            older_than,
+            time_basis,
+            size_mode,
             keep_size,
+            min_size,
             ignore_paths,
+            manifest_globs,
+            extra_target_names,
+            exclude_patterns,
             no_parallel,
+            jobs,
+            no_cache,
             follow_symlinks,
             include_hidden,
             no_gitignore,
+            no_purgerignore,
+            force,
             yes,
             keep_executable,
             executable_backup_dir,
+            executable_filter,
             timeout,
+            only_if_helps,
+            free_threshold,
+            metrics_file,
+            repair,
+            max_delete_files,
+            max_delete_bytes,
+            show_files,
+            by_member,
+            format,
+            progress,
+            interactive,
+            confirm_each,
+            // 没有逐项选择时本来就清理全部匹配的项目，--all 只是显式写出
+            all: _,
+            only,
+            only_glob,
+            ignore_failures,
+            remove_if_stale,
         } => handle_clean_command(CleanCommandArgs {
-            path,
+            paths,
             max_depth,
+            min_depth,
             strategy,
             direct_delete_backend,
+            profiles,
+            triples,
+            packages,
             dry_run,
             keep_days,
+            older_than,
+            time_basis,
+            size_mode,
             keep_size,
+            min_size,
             ignore_paths,
+            manifest_globs,
+            extra_target_names,
+            exclude_patterns,
             no_parallel,
+            jobs,
+            no_cache,
             follow_symlinks,
             include_hidden,
             no_gitignore,
+            no_purgerignore,
+            force,
             yes,
             keep_executable,
             executable_backup_dir,
+            executable_filter,
             timeout,
+            only_if_helps,
+            free_threshold,
+            metrics_file,
+            repair,
+            max_delete_files,
+            max_delete_bytes,
+            show_files,
+            by_member,
+            format,
+            progress,
+            interactive,
+            confirm_each,
+            only,
+            only_globs: only_glob,
+            ignore_failures,
+            remove_if_stale,
+            verbose: cli.verbose,
+        }),
+        Commands::Watch {
+            paths,
+            interval,
+            strategy,
+            dry_run,
+            yes,
+            scan,
+        } => watch::handle_watch_command(watch::WatchCommandArgs {
+            paths,
+            interval,
+            strategy,
+            dry_run,
+            yes,
+            scan,
         }),
+        Commands::Restore {
+            path,
+            executable_backup_dir,
+        } => handle_restore_command(&path, executable_backup_dir),
     }
 }
 
+fn handle_restore_command(
+    path: &std::path::Path,
+    executable_backup_dir: Option<PathBuf>,
+) -> Result<()> {
+    let project = purger_core::RustProject::from_path(path)?;
+    let cleaner = ProjectCleaner::new(CleanConfig {
+        executable_backup_dir,
+        ..Default::default()
+    });
+    let restored = cleaner.restore_executables(&project)?;
+    println!(
+        "Restored {} executables to {}",
+        restored,
+        project.target_path().display()
+    );
+    Ok(())
+}
+
 fn handle_scan_command(args: ScanCommandArgs) -> Result<()> {
     let config = create_scan_config(ScanConfigArgs {
         max_depth: args.max_depth,
+        min_depth: args.min_depth,
         keep_days: args.keep_days,
+        older_than: args.older_than,
+        time_basis: args.time_basis,
+        size_mode: args.size_mode,
         keep_size: args.keep_size,
+        min_size: args.min_size,
         ignore_paths: args.ignore_paths,
+        manifest_globs: args.manifest_globs,
+        extra_target_names: args.extra_target_names,
+        exclude_patterns: args.exclude_patterns,
         no_parallel: args.no_parallel,
+        jobs: args.jobs,
+        no_cache: args.no_cache,
         follow_symlinks: args.follow_symlinks,
         include_hidden: args.include_hidden,
         no_gitignore: args.no_gitignore,
+        no_purgerignore: args.no_purgerignore,
+        force: args.force,
     })?;
 
     let scanner = ProjectScanner::new(config.clone());
-    let mut projects = scanner.scan(&args.path)?;
+    let mut projects = if args.orphans {
+        scan_orphans_many(&scanner, &args.paths)?
+    } else {
+        scanner.scan_many(&args.paths)?
+    };
 
     if args.target_only {
         projects = ProjectScanner::filter_with_target(projects);
@@ -330,60 +1023,383 @@ fn handle_scan_command(args: ScanCommandArgs) -> Result<()> {
 
     if args.sort_by_size {
         projects = ProjectScanner::sort_by_size(projects);
+    } else if let Some(key) = args.sort {
+        purger_core::sort_projects(&mut projects, key.into(), args.reverse);
     }
 
     // 应用过滤器
-    if config.keep_days.is_some() || config.keep_size.is_some() || !config.ignore_paths.is_empty() {
-        let filter = ProjectFilter::new(config);
+    if config.keep_threshold().is_some()
+        || config.keep_size.is_some()
+        || config.min_clean_size.is_some()
+        || !config.ignore_paths.is_empty()
+    {
+        let filter = ProjectFilter::new(config.clone());
         projects = filter.filter_projects(projects);
     }
 
-    display_projects(&projects, &args.path)?;
+    if args.print0 {
+        print_paths_nul(&projects, &mut std::io::stdout().lock())?;
+        return Ok(());
+    }
+
+    if args.total_only {
+        print_total_only(&projects, &mut io::stdout().lock())?;
+        return Ok(());
+    }
+
+    match args.format {
+        OutputFormat::Table => {
+            display_projects(&projects, display_base(&args.paths), args.verbose)?;
+            if let Some(GroupByArg::TopLevel) = args.group_by {
+                display_group_summary(&group_by_top_level(&projects, &args.paths));
+            }
+            if let Some(top) = args.breakdown {
+                display_target_breakdowns(
+                    &projects,
+                    display_base(&args.paths),
+                    top,
+                    config.size_mode,
+                );
+            }
+        }
+        OutputFormat::Json => print_projects_json(&projects, &mut io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+/// `scan --group-by` 汇总中的一组
+#[derive(Debug, PartialEq, Eq)]
+struct GroupSummary {
+    /// 扫描根下的第一级目录（项目就在扫描根时为扫描根本身）
+    group: PathBuf,
+    project_count: usize,
+    reclaimable: u64,
+}
+
+/// 按扫描根下的第一级目录汇总可回收空间（有 target 的项目 target 大小之和），从大到小排列
+fn group_by_top_level(projects: &[RustProject], roots: &[PathBuf]) -> Vec<GroupSummary> {
+    let mut groups: Vec<GroupSummary> = Vec::new();
+    for project in projects {
+        let group = roots
+            .iter()
+            .find_map(|root| {
+                let relative = project.path.strip_prefix(root).ok()?;
+                Some(match relative.components().next() {
+                    Some(first) => root.join(first),
+                    None => root.clone(),
+                })
+            })
+            .unwrap_or_else(|| project.path.clone());
+        let reclaimable = if project.has_target {
+            project.target_size
+        } else {
+            0
+        };
+
+        match groups.iter_mut().find(|summary| summary.group == group) {
+            Some(summary) => {
+                summary.project_count += 1;
+                summary.reclaimable += reclaimable;
+            }
+            None => groups.push(GroupSummary {
+                group,
+                project_count: 1,
+                reclaimable,
+            }),
+        }
+    }
+
+    groups.sort_by(|a, b| {
+        b.reclaimable
+            .cmp(&a.reclaimable)
+            .then_with(|| a.group.cmp(&b.group))
+    });
+    groups
+}
+
+fn display_group_summary(groups: &[GroupSummary]) {
+    if groups.is_empty() {
+        return;
+    }
+
+    println!("\nReclaimable space by top-level folder:");
+    println!("{:<40} {:<10} {:<15}", "Folder", "Projects", "Size");
+    println!("{}", "-".repeat(65));
+    for summary in groups {
+        println!(
+            "{:<40} {:<10} {:<15}",
+            summary.group.display(),
+            summary.project_count,
+            purger_core::format_bytes(summary.reclaimable)
+        );
+    }
+}
+
+/// 每个有 target 的项目中最大的 `top` 个 target 子目录
+fn display_target_breakdowns(
+    projects: &[RustProject],
+    base_path: &std::path::Path,
+    top: usize,
+    size_mode: SizeMode,
+) {
+    let projects: Vec<_> = projects.iter().filter(|p| p.has_target).collect();
+    if projects.is_empty() || top == 0 {
+        return;
+    }
+
+    println!("\nLargest target subdirectories:");
+    for project in projects {
+        let relative_path = project.relative_path(base_path);
+        if relative_path.as_os_str().is_empty() {
+            println!("{}", project.name);
+        } else {
+            println!("{} ({})", project.name, relative_path.display());
+        }
+        for (name, size) in project.target_subdir_sizes(size_mode).into_iter().take(top) {
+            println!("  {:<30} {}", name, purger_core::format_bytes(size));
+        }
+    }
+}
+
+/// `scan --format json` 的输出结构
+#[derive(serde::Serialize)]
+struct ScanJsonOutput<'a> {
+    projects: &'a [purger_core::RustProject],
+    project_count: usize,
+    total_target_size: u64,
+}
+
+/// 以 JSON 输出扫描结果（stdout 上不混入其他内容）
+fn print_projects_json(
+    projects: &[purger_core::RustProject],
+    out: &mut impl std::io::Write,
+) -> Result<()> {
+    let output = ScanJsonOutput {
+        projects,
+        project_count: projects.len(),
+        total_target_size: projects.iter().map(|p| p.target_size).sum(),
+    };
+    serde_json::to_writer_pretty(&mut *out, &output)?;
+    writeln!(out)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// 只输出可回收空间总量（有 target 的项目的 target 大小之和）
+fn print_total_only(
+    projects: &[purger_core::RustProject],
+    out: &mut impl std::io::Write,
+) -> Result<()> {
+    let total: u64 = projects
+        .iter()
+        .filter(|project| project.has_target)
+        .map(|project| project.target_size)
+        .sum();
+    writeln!(out, "{}", purger_core::format_bytes(total))?;
+    out.flush()?;
+    Ok(())
+}
+
+/// 显示相对路径时使用的基准目录（多个根时显示完整路径）
+fn display_base(paths: &[PathBuf]) -> &std::path::Path {
+    match paths {
+        [path] => path,
+        _ => std::path::Path::new(""),
+    }
+}
+
+/// 以 NUL 分隔输出项目的绝对路径（类似 `find -print0`），不输出其他内容
+fn print_paths_nul(
+    projects: &[purger_core::RustProject],
+    out: &mut impl std::io::Write,
+) -> Result<()> {
+    for project in projects {
+        let path = std::path::absolute(&project.path).unwrap_or_else(|_| project.path.clone());
+        out.write_all(path.as_os_str().as_encoded_bytes())?;
+        out.write_all(b"\0")?;
+    }
+    out.flush()?;
     Ok(())
 }
 
+impl Commands {
+    /// 检查参数之间的依赖关系
+    ///
+    /// 配置文件中的值以默认值的形式注入，clap 的 `requires` 看不到它们，所以在合并后的值上检查。
+    fn validate(&self) -> Result<()> {
+        if let Commands::Clean {
+            strategy,
+            profiles,
+            triples,
+            packages,
+            older_than,
+            remove_if_stale,
+            ..
+        } = self
+        {
+            if !profiles.is_empty() && !matches!(strategy, CleanStrategyArg::DirectDelete) {
+                anyhow::bail!("--profile requires --strategy direct-delete");
+            }
+            if !triples.is_empty() && !matches!(strategy, CleanStrategyArg::DirectDelete) {
+                anyhow::bail!("--triple requires --strategy direct-delete");
+            }
+            if !packages.is_empty() && !matches!(strategy, CleanStrategyArg::CargoClean) {
+                anyhow::bail!("--package requires --strategy cargo-clean");
+            }
+            if *remove_if_stale && older_than.is_none() {
+                anyhow::bail!("--remove-if-stale requires --older-than");
+            }
+        }
+        Ok(())
+    }
+}
+
 fn handle_clean_command(args: CleanCommandArgs) -> Result<()> {
     let scan_config = create_scan_config(ScanConfigArgs {
         max_depth: args.max_depth,
+        min_depth: args.min_depth,
         keep_days: args.keep_days,
+        older_than: args.older_than.clone(),
+        time_basis: args.time_basis,
+        size_mode: args.size_mode,
         keep_size: args.keep_size.clone(),
+        min_size: args.min_size,
         ignore_paths: args.ignore_paths,
+        manifest_globs: args.manifest_globs,
+        extra_target_names: args.extra_target_names,
+        exclude_patterns: args.exclude_patterns,
         no_parallel: args.no_parallel,
+        jobs: args.jobs,
+        no_cache: args.no_cache,
         follow_symlinks: args.follow_symlinks,
         include_hidden: args.include_hidden,
         no_gitignore: args.no_gitignore,
+        no_purgerignore: args.no_purgerignore,
+        force: args.force,
     })?;
 
-    let scanner = ProjectScanner::new(scan_config.clone());
-    let mut projects = scanner.scan(&args.path)?;
+    let mut projects = find_clean_candidates(&scan_config, &args.paths)?;
 
-    // 只保留有target目录的项目
-    projects = ProjectScanner::filter_with_target(projects);
+    // JSON 模式下 stdout 只输出最终结果
+    let json = args.format == OutputFormat::Json;
+
+    // 只清理指定名称的项目
+    if !args.only.is_empty() || !args.only_globs.is_empty() {
+        projects = select_projects_by_name(projects, &args.only, &args.only_globs)?;
+    }
+
+    // 跳过带有 .purger-keep 标记的项目
+    if !args.force {
+        let (unpinned, pinned) = ProjectScanner::partition_pinned(projects);
+        if !json {
+            display_skipped_pinned(&pinned, display_base(&args.paths));
+        }
+        projects = unpinned;
+    }
 
     // 应用过滤器
-    if scan_config.keep_days.is_some()
+    if scan_config.keep_threshold().is_some()
         || scan_config.keep_size.is_some()
+        || scan_config.min_clean_size.is_some()
         || !scan_config.ignore_paths.is_empty()
     {
-        let filter = ProjectFilter::new(scan_config);
+        let filter = ProjectFilter::new(scan_config.clone());
         projects = filter.filter_projects(projects);
     }
 
+    // 只清理可用空间紧张的卷上的项目
+    if args.only_if_helps {
+        let threshold = ProjectFilter::parse_size_string(&args.free_threshold)?;
+        let (helpful, skipped) = purger_core::disk::partition_by_free_space(projects, threshold);
+        if !json {
+            display_skipped_not_helpful(&skipped, display_base(&args.paths), threshold);
+        }
+        projects = helpful;
+    }
+
+    // --package：跳过不包含任何指定包的项目，避免把它们当作已清理（0 字节）报告
+    if !args.packages.is_empty() {
+        let selector = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::CargoClean,
+            packages: Some(args.packages.clone()),
+            ..Default::default()
+        });
+        let (selected, skipped): (Vec<_>, Vec<_>) = projects
+            .into_iter()
+            .partition(|project| selector.selects_project(project));
+        if !json {
+            display_skipped_without_packages(&skipped, display_base(&args.paths));
+        }
+        projects = selected;
+    }
+
+    // 修复模式：只处理残缺/卡住的 target
+    if args.repair {
+        let partial: Vec<_> = projects
+            .into_iter()
+            .filter_map(|project| {
+                purger_core::repair::detect_partial_target(&project.target_path())
+                    .map(|issue| (project, issue))
+            })
+            .collect();
+
+        if !partial.is_empty() && !json {
+            println!("Found {} partial targets:", partial.len());
+            for (project, issue) in &partial {
+                println!(
+                    "  - {} ({}): {issue}",
+                    project.name,
+                    project.relative_path(display_base(&args.paths)).display()
+                );
+            }
+        }
+        projects = partial.into_iter().map(|(project, _)| project).collect();
+    }
+
     if projects.is_empty() {
-        println!("No projects found to clean.");
+        let result = purger_core::CleanResult::new();
+        if json {
+            print_clean_result_json(&result, &mut io::stdout().lock())?;
+        } else {
+            println!("No projects found to clean.");
+        }
+        if let Some(metrics_file) = &args.metrics_file {
+            metrics::write_metrics_file(metrics_file, &result)?;
+        }
         return Ok(());
     }
 
     // 显示将要清理的项目
-    println!("Found {} projects to clean:", projects.len());
-    display_projects(&projects, &args.path)?;
+    if !json {
+        println!("Found {} projects to clean:", projects.len());
+        display_projects(&projects, display_base(&args.paths), false)?;
+    }
+
+    // 交互式逐项选择，未选中的项目直接跳过
+    if args.interactive {
+        projects = select_projects_interactive(
+            projects,
+            &mut io::stdin().lock(),
+            &mut io::stdout().lock(),
+        )?;
+        if projects.is_empty() {
+            println!("No projects selected.");
+            return Ok(());
+        }
+    }
 
-    // 确认清理
-    if !args.yes && !args.dry_run && !confirm_clean(&projects)? {
+    // 确认清理（JSON 模式用于无人值守任务，不提示）
+    if !args.yes && !args.dry_run && !json && !args.interactive && !confirm_clean(&projects)? {
         println!("Cleaning cancelled.");
         return Ok(());
     }
 
+    let max_delete_bytes = args
+        .max_delete_bytes
+        .as_deref()
+        .map(ProjectFilter::parse_size_string)
+        .transpose()?;
+
     // 执行清理
     let clean_config = CleanConfig {
         strategy: args.strategy.into(),
@@ -393,16 +1409,297 @@ fn handle_clean_command(args: CleanCommandArgs) -> Result<()> {
         direct_delete_backend: args.direct_delete_backend.into(),
         keep_executable: args.keep_executable,
         executable_backup_dir: args.executable_backup_dir,
+        executable_filter: args.executable_filter.into(),
+        force: args.force,
+        max_delete_files: args.max_delete_files,
+        max_delete_bytes,
+        profiles: (!args.profiles.is_empty()).then_some(args.profiles),
+        triples: (!args.triples.is_empty()).then_some(args.triples),
+        packages: (!args.packages.is_empty()).then_some(args.packages),
+        jobs: args.jobs,
+        ..Default::default()
     };
 
-    let cleaner = ProjectCleaner::new(clean_config);
-    let result = cleaner.clean_projects(&projects);
+    // 清理前先统计工作区成员归属，清理后文件就不存在了
+    let attributions = if args.by_member {
+        workspace_attributions(&projects)
+    } else {
+        Vec::new()
+    };
 
-    // 显示结果
-    display_clean_result(&result);
+    // Ctrl+C 时完成当前项目后停止并输出已完成部分的汇总；逐项确认和修复模式不拦截
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    if !args.confirm_each && !args.repair {
+        install_interrupt_handler(stop_flag.clone());
+    }
 
-    Ok(())
-}
+    let cleaner = ProjectCleaner::new(clean_config).with_stop_flag(stop_flag.clone());
+    let result = if args.repair {
+        cleaner.repair_projects(&projects)
+    } else if args.confirm_each {
+        clean_with_confirm_each(
+            &cleaner,
+            &projects,
+            &mut io::stdin().lock(),
+            &mut io::stdout().lock(),
+        )?
+    } else if args.progress == Some(ProgressFormat::Ndjson) {
+        clean_with_ndjson_progress(&cleaner, &projects, &mut io::stderr().lock())
+    } else {
+        cleaner.clean_projects(&projects)
+    };
+
+    if args.dry_run && args.show_files {
+        display_removed_files(&cleaner, &projects, display_base(&args.paths));
+    }
+
+    // 显示结果
+    if json {
+        print_clean_result_json(&result, &mut io::stdout().lock())?;
+    } else {
+        display_clean_result(&result, args.verbose);
+        display_member_attributions(&attributions, &result, display_base(&args.paths));
+    }
+
+    if let Some(metrics_file) = &args.metrics_file {
+        metrics::write_metrics_file(metrics_file, &result)?;
+    }
+
+    if stop_flag.load(Ordering::Relaxed) {
+        anyhow::bail!(
+            "Interrupted: processed {} of {} projects",
+            result.outcomes.len(),
+            projects.len()
+        );
+    }
+
+    // 删除整个废弃项目目录：必须显式开启，并单独确认
+    if let Some(older_than) = &args.older_than {
+        let older_than = ProjectFilter::parse_duration_string(older_than)?;
+        let stale = stale_projects_to_remove(
+            &projects,
+            &result,
+            &scan_config,
+            args.remove_if_stale,
+            older_than,
+            SystemTime::now(),
+        )?;
+        if !stale.is_empty() {
+            let base = display_base(&args.paths);
+            if json {
+                eprintln!(
+                    "--remove-if-stale needs an interactive confirmation; skipped in JSON mode"
+                );
+            } else if args.dry_run {
+                println!("\nWould remove {} stale project directories:", stale.len());
+                for project in &stale {
+                    println!("  - {}", project.relative_path(base).display());
+                }
+            } else if confirm_remove_stale(
+                &stale,
+                base,
+                &mut io::stdin().lock(),
+                &mut io::stdout().lock(),
+            )? {
+                remove_stale_projects(&stale, base);
+            } else {
+                println!("Stale project directories kept.");
+            }
+        }
+    }
+
+    check_clean_failures(&result, args.ignore_failures)
+}
+
+/// 扫描并选出可以清理的项目：有 target，且通过扫描器应用的时间/大小/路径过滤
+/// （`--keep-days`/`--older-than` 时只剩超过保留时长未编译的项目）
+fn find_clean_candidates(scan_config: &ScanConfig, paths: &[PathBuf]) -> Result<Vec<RustProject>> {
+    let scanner = ProjectScanner::new(scan_config.clone());
+    Ok(ProjectScanner::filter_with_target(
+        scanner.scan_many(paths)?,
+    ))
+}
+
+/// 第一次 Ctrl+C 置位停止标志（当前项目完成后停止），再按一次立即退出
+fn install_interrupt_handler(stop_flag: Arc<AtomicBool>) {
+    let result = ctrlc::set_handler(move || {
+        if stop_flag.swap(true, Ordering::SeqCst) {
+            std::process::exit(130);
+        }
+        eprintln!(
+            "\nInterrupted: finishing the current project (press Ctrl+C again to exit immediately)"
+        );
+    });
+    if let Err(e) = result {
+        tracing::warn!("无法注册 Ctrl+C 处理: {e}");
+    }
+}
+
+/// `--remove-if-stale` 要删除的项目：本次清理成功、超过 `older_than` 未编译且没有 `.purger-keep` 标记；
+/// 嵌套在另一个待删除项目中的（如工作区成员）随外层目录一起删除，不单独列出。
+///
+/// 目录中只要还有其他不在待删除列表里的项目（带标记、最近编译过或没有 target），整个目录都不删除；
+/// 为此会不带任何过滤条件重新扫描每个待删除目录。
+fn stale_projects_to_remove<'a>(
+    projects: &'a [RustProject],
+    result: &CleanResult,
+    scan_config: &ScanConfig,
+    remove_if_stale: bool,
+    older_than: Duration,
+    now: SystemTime,
+) -> Result<Vec<&'a RustProject>> {
+    if !remove_if_stale {
+        return Ok(Vec::new());
+    }
+
+    let cleaned: std::collections::HashSet<_> = result
+        .outcomes
+        .iter()
+        .filter(|outcome| outcome.error.is_none())
+        .map(|outcome| outcome.path.as_path())
+        .collect();
+    let candidates: Vec<&RustProject> = projects
+        .iter()
+        .filter(|project| !project.pinned && cleaned.contains(project.path.as_path()))
+        .filter(|project| !overlaps_ignore_paths(&project.path, &scan_config.ignore_paths))
+        .filter(|project| {
+            now.duration_since(project.last_modified)
+                .is_ok_and(|elapsed| elapsed >= older_than)
+        })
+        .collect();
+
+    let candidate_paths: std::collections::HashSet<_> = candidates
+        .iter()
+        .map(|project| project.path.as_path())
+        .collect();
+    // min_depth = 1：只找目录内部嵌套的项目，项目本身不计入
+    let scanner = ProjectScanner::new(ScanConfig {
+        max_depth: None,
+        min_depth: Some(1),
+        respect_gitignore: false,
+        purgerignore: false,
+        ignore_hidden: false,
+        lazy_size_calculation: true,
+        keep_days: None,
+        keep_duration: None,
+        keep_size: None,
+        min_clean_size: None,
+        ignore_paths: Vec::new(),
+        manifest_globs: Vec::new(),
+        exclude_patterns: Vec::new(),
+        prune_unmodified_since: None,
+        use_cache: false,
+        ..scan_config.clone()
+    });
+    let mut removable = Vec::new();
+    for project in candidates {
+        let kept = scanner
+            .scan(&project.path)?
+            .into_iter()
+            .filter(|inner| !candidate_paths.contains(inner.path.as_path()))
+            .filter(|inner| !project.members.contains(&inner.name))
+            .count();
+        if kept == 0 {
+            removable.push(project);
+        } else {
+            tracing::warn!(
+                "{} 中还有 {} 个不删除的项目，保留整个目录",
+                project.path.display(),
+                kept
+            );
+        }
+    }
+
+    Ok(removable
+        .iter()
+        .filter(|project| {
+            !removable
+                .iter()
+                .any(|outer| outer.path != project.path && project.path.starts_with(&outer.path))
+        })
+        .copied()
+        .collect())
+}
+
+/// 项目目录位于某个 `--ignore` 路径中，或者包含某个 `--ignore` 路径
+///
+/// 不依赖 `ProjectFilter`：删除整个目录的操作必须自己确认不会碰到忽略路径。
+fn overlaps_ignore_paths(project_path: &std::path::Path, ignore_paths: &[PathBuf]) -> bool {
+    let canonical = |path: &std::path::Path| {
+        path.canonicalize()
+            .unwrap_or_else(|_| std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()))
+    };
+    let project_path = canonical(project_path);
+    ignore_paths.iter().any(|ignore_path| {
+        let ignore_path = canonical(ignore_path);
+        project_path.starts_with(&ignore_path) || ignore_path.starts_with(&project_path)
+    })
+}
+
+/// 删除整个项目目录前的额外确认：需要输入 `delete`，普通的 y/yes 不算
+fn confirm_remove_stale(
+    projects: &[&RustProject],
+    base: &std::path::Path,
+    input: &mut impl io::BufRead,
+    out: &mut impl Write,
+) -> Result<bool> {
+    writeln!(
+        out,
+        "\nThe following {} project directories have not been compiled within --older-than:",
+        projects.len()
+    )?;
+    for project in projects {
+        writeln!(out, "  - {}", project.relative_path(base).display())?;
+    }
+    write!(
+        out,
+        "This permanently deletes the WHOLE project directories, including sources. Type 'delete' to confirm: "
+    )?;
+    out.flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(answer.trim() == "delete")
+}
+
+fn remove_stale_projects(projects: &[&RustProject], base: &std::path::Path) {
+    let mut removed = 0;
+    for project in projects {
+        match std::fs::remove_dir_all(&project.path) {
+            Ok(()) => removed += 1,
+            Err(err) => eprintln!(
+                "Failed to remove {}: {err}",
+                project.relative_path(base).display()
+            ),
+        }
+    }
+    println!("Removed {removed} stale project directories.");
+}
+
+/// 在每个根路径下查找孤立的 target，根路径重叠时同一个目录只保留一次
+fn scan_orphans_many(scanner: &ProjectScanner, roots: &[PathBuf]) -> Result<Vec<RustProject>> {
+    let mut seen = std::collections::HashSet::new();
+    let mut orphans = Vec::new();
+    for root in roots {
+        for orphan in scanner.scan_orphans(root)? {
+            if seen.insert(orphan.target_path()) {
+                orphans.push(orphan);
+            }
+        }
+    }
+    Ok(orphans)
+}
+
+/// 有项目清理失败时返回错误，使进程以非零状态退出（在打印完整结果之后调用）
+fn check_clean_failures(result: &CleanResult, ignore_failures: bool) -> Result<()> {
+    if ignore_failures || result.failed_projects.is_empty() {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "{} project(s) failed to clean (use --ignore-failures to exit with status 0)",
+        result.failed_projects.len()
+    )
+}
 
 fn create_scan_config(args: ScanConfigArgs) -> Result<ScanConfig> {
     let keep_size_bytes = if let Some(size_str) = args.keep_size {
@@ -413,20 +1710,44 @@ fn create_scan_config(args: ScanConfigArgs) -> Result<ScanConfig> {
 
     Ok(ScanConfig {
         max_depth: args.max_depth,
+        min_depth: args.min_depth,
         parallel: !args.no_parallel,
         follow_links: args.follow_symlinks,
         ignore_hidden: !args.include_hidden,
         respect_gitignore: !args.no_gitignore,
+        purgerignore: !args.no_purgerignore,
         lazy_size_calculation: false, // 默认不启用延迟计算
         keep_days: args.keep_days,
+        keep_duration: args
+            .older_than
+            .as_deref()
+            .map(purger_core::ProjectFilter::parse_duration_string)
+            .transpose()?,
         keep_size: keep_size_bytes,
+        min_clean_size: args
+            .min_size
+            .as_deref()
+            .map(purger_core::ProjectFilter::parse_size_string)
+            .transpose()?,
         ignore_paths: args.ignore_paths,
+        manifest_globs: args.manifest_globs,
+        exclude_patterns: args.exclude_patterns,
+        prune_unmodified_since: None,
+        extra_target_names: args.extra_target_names,
+        dedup_hardlinks: false,
+        time_basis: args.time_basis.into(),
+        size_mode: args.size_mode.into(),
+        jobs: args.jobs,
+        use_cache: !args.no_cache,
+        cache_dir: None,
+        force: args.force,
     })
 }
 
 fn display_projects(
     projects: &[purger_core::RustProject],
     base_path: &std::path::Path,
+    verbose: bool,
 ) -> Result<()> {
     if projects.is_empty() {
         println!("No projects found.");
@@ -447,6 +1768,12 @@ fn display_projects(
             project.formatted_size(),
             relative_path.display()
         );
+        if verbose && let Some(largest) = &project.largest_file {
+            println!("    largest file: {largest}");
+        }
+        if verbose && !project.members.is_empty() {
+            println!("    members: {}", project.members.join(", "));
+        }
     }
 
     println!("{}", "-".repeat(75));
@@ -455,6 +1782,301 @@ fn display_projects(
     Ok(())
 }
 
+fn display_skipped_pinned(pinned: &[purger_core::RustProject], base_path: &std::path::Path) {
+    if pinned.is_empty() {
+        return;
+    }
+
+    println!(
+        "\nSkipped {} pinned projects ({} present, use --force to override):",
+        pinned.len(),
+        purger_core::KEEP_MARKER
+    );
+    for project in pinned {
+        println!(
+            "  - {} ({})",
+            project.name,
+            project.relative_path(base_path).display()
+        );
+    }
+}
+
+fn display_skipped_without_packages(
+    skipped: &[purger_core::RustProject],
+    base_path: &std::path::Path,
+) {
+    if skipped.is_empty() {
+        return;
+    }
+
+    println!(
+        "\nSkipped {} projects (none of the --package packages belong to them):",
+        skipped.len()
+    );
+    for project in skipped {
+        println!(
+            "  - {} ({})",
+            project.name,
+            project.relative_path(base_path).display()
+        );
+    }
+}
+
+fn display_skipped_not_helpful(
+    skipped: &[(purger_core::RustProject, u64)],
+    base_path: &std::path::Path,
+    threshold: u64,
+) {
+    if skipped.is_empty() {
+        return;
+    }
+
+    println!(
+        "\nSkipped {} projects (volume has more than {} free, cleaning would not help):",
+        skipped.len(),
+        purger_core::format_bytes(threshold)
+    );
+    for (project, free) in skipped {
+        println!(
+            "  - {} ({}): {} free",
+            project.name,
+            project.relative_path(base_path).display(),
+            purger_core::format_bytes(*free)
+        );
+    }
+}
+
+fn display_removed_files(
+    cleaner: &ProjectCleaner,
+    projects: &[purger_core::RustProject],
+    base_path: &std::path::Path,
+) {
+    println!("\nFiles that would be removed:");
+    for (project, files) in cleaner.dry_run_manifest(projects) {
+        println!(
+            "\n{} ({}):",
+            project.name,
+            project.relative_path(base_path).display()
+        );
+        match files {
+            Ok(files) if files.is_empty() => println!("  (nothing)"),
+            Ok(files) => {
+                for file in files {
+                    println!(
+                        "  {}",
+                        file.strip_prefix(&project.path).unwrap_or(&file).display()
+                    );
+                }
+            }
+            Err(e) => println!("  failed to list files: {e}"),
+        }
+    }
+}
+
+/// 为每个工作区项目统计 target 中各成员的占用
+fn workspace_attributions(
+    projects: &[purger_core::RustProject],
+) -> Vec<(
+    &purger_core::RustProject,
+    Result<purger_core::workspace::MemberAttribution>,
+)> {
+    projects
+        .iter()
+        .filter(|project| project.is_workspace_root)
+        .map(|project| {
+            let attribution =
+                purger_core::workspace::workspace_member_names(&project.path).map(|members| {
+                    purger_core::workspace::attribute_target(&project.target_path(), &members)
+                });
+            (project, attribution)
+        })
+        .collect()
+}
+
+fn display_member_attributions(
+    attributions: &[(
+        &purger_core::RustProject,
+        Result<purger_core::workspace::MemberAttribution>,
+    )],
+    result: &purger_core::CleanResult,
+    base_path: &std::path::Path,
+) {
+    for (project, attribution) in attributions {
+        let failed = result
+            .failures
+            .iter()
+            .any(|failure| failure.project_path == project.path);
+        if failed {
+            continue;
+        }
+
+        println!(
+            "\nFreed space by member in {} ({}):",
+            project.name,
+            project.relative_path(base_path).display()
+        );
+        match attribution {
+            Ok(attribution) => {
+                for (member, size) in &attribution.members {
+                    println!("  {:<30} {}", member, purger_core::format_bytes(*size));
+                }
+                println!(
+                    "  {:<30} {}",
+                    "shared/unattributed",
+                    purger_core::format_bytes(attribution.unattributed)
+                );
+            }
+            Err(e) => println!("  failed to read workspace members: {e}"),
+        }
+    }
+}
+
+/// 逐项目询问的回答：y 当前项目，n 跳过，a 当前及剩余全部，q 放弃当前及剩余全部
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProjectAnswer {
+    Yes,
+    No,
+    All,
+    Quit,
+}
+
+/// 解析 `[y/n/a/q]` 提示的回答（不区分大小写，忽略首尾空白），无法识别时返回 `None`
+fn parse_project_answer(line: &str) -> Option<ProjectAnswer> {
+    match line.trim().to_lowercase().as_str() {
+        "y" | "yes" => Some(ProjectAnswer::Yes),
+        "n" | "no" => Some(ProjectAnswer::No),
+        "a" | "all" => Some(ProjectAnswer::All),
+        "q" | "quit" => Some(ProjectAnswer::Quit),
+        _ => None,
+    }
+}
+
+/// 显示提示并读取回答，无法识别时重新询问；输入结束时视为放弃剩余项目
+fn ask_project(
+    prompt: &str,
+    input: &mut impl io::BufRead,
+    out: &mut impl Write,
+) -> Result<ProjectAnswer> {
+    loop {
+        write!(out, "{prompt} [y/n/a/q]: ")?;
+        out.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(ProjectAnswer::Quit);
+        }
+        if let Some(answer) = parse_project_answer(&line) {
+            return Ok(answer);
+        }
+        writeln!(out, "Please answer y, n, a or q.")?;
+    }
+}
+
+/// 只保留名称与 `names` 之一完全相同或匹配 `globs` 之一的项目
+fn select_projects_by_name(
+    projects: Vec<RustProject>,
+    names: &[String],
+    globs: &[String],
+) -> Result<Vec<RustProject>> {
+    let mut builder = globset::GlobSetBuilder::new();
+    for pattern in globs {
+        builder.add(
+            globset::Glob::new(pattern)
+                .map_err(|e| anyhow::anyhow!("无效的 --only-glob 模式 {pattern:?}: {e}"))?,
+        );
+    }
+    let globs = builder.build()?;
+
+    Ok(projects
+        .into_iter()
+        .filter(|project| names.contains(&project.name) || globs.is_match(&project.name))
+        .collect())
+}
+
+/// 逐个询问是否清理项目：y 选中，n 跳过，a 选中剩余全部，q 跳过剩余全部
+fn select_projects_interactive(
+    projects: Vec<purger_core::RustProject>,
+    input: &mut impl io::BufRead,
+    out: &mut impl Write,
+) -> Result<Vec<purger_core::RustProject>> {
+    let total = projects.len();
+    let mut selected = Vec::new();
+    let mut select_rest = false;
+
+    for (index, project) in projects.into_iter().enumerate() {
+        if select_rest {
+            selected.push(project);
+            continue;
+        }
+
+        let prompt = format!(
+            "[{}/{}] Clean {} ({})?",
+            index + 1,
+            total,
+            project.name,
+            project.formatted_size()
+        );
+        match ask_project(&prompt, input, out)? {
+            ProjectAnswer::Yes => selected.push(project),
+            ProjectAnswer::All => {
+                selected.push(project);
+                select_rest = true;
+            }
+            ProjectAnswer::Quit => break,
+            ProjectAnswer::No => {}
+        }
+    }
+
+    Ok(selected)
+}
+
+/// 清理过程中在每个项目之前确认（显示路径和大小），被跳过的项目不计入结果
+fn clean_with_confirm_each(
+    cleaner: &ProjectCleaner,
+    projects: &[RustProject],
+    input: &mut impl io::BufRead,
+    out: &mut impl Write,
+) -> Result<CleanResult> {
+    let start_time = Instant::now();
+    let mut result = CleanResult::new();
+    let mut clean_rest = false;
+
+    for (index, project) in projects.iter().enumerate() {
+        if !clean_rest {
+            let prompt = format!(
+                "[{}/{}] Delete target of {} at {} ({})?",
+                index + 1,
+                projects.len(),
+                project.name,
+                project.target_path().display(),
+                project.formatted_size()
+            );
+            match ask_project(&prompt, input, out)? {
+                ProjectAnswer::Yes => {}
+                ProjectAnswer::No => continue,
+                ProjectAnswer::All => clean_rest = true,
+                ProjectAnswer::Quit => break,
+            }
+        }
+
+        let project_start = Instant::now();
+        let (bytes_freed, error) = match cleaner.clean_project(project) {
+            Ok(bytes) => (bytes, None),
+            Err(err) => (0, Some(err.to_string())),
+        };
+        result.add_outcome(ProjectCleanOutcome {
+            name: project.name.clone(),
+            path: project.path.clone(),
+            bytes_freed,
+            duration_ms: project_start.elapsed().as_millis() as u64,
+            error,
+        });
+    }
+
+    result.duration_ms = start_time.elapsed().as_millis() as u64;
+    Ok(result)
+}
+
 fn confirm_clean(projects: &[purger_core::RustProject]) -> Result<bool> {
     let total_size: u64 = projects.iter().map(|p| p.target_size).sum();
 
@@ -472,30 +2094,128 @@ fn confirm_clean(projects: &[purger_core::RustProject]) -> Result<bool> {
     Ok(input.trim().to_lowercase() == "y" || input.trim().to_lowercase() == "yes")
 }
 
-fn display_clean_result(result: &purger_core::CleanResult) {
-    println!("\nCleaning completed!");
-    println!("Projects cleaned: {}", result.cleaned_projects);
-    println!("Size freed: {}", result.format_size());
+/// `--progress ndjson` 在 stderr 上逐行输出的事件
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum ProgressEvent<'a> {
+    Progress(&'a CleanProgress),
+    ProjectDone {
+        project_name: &'a str,
+        bytes_freed: u64,
+        error: Option<&'a str>,
+    },
+}
+
+fn write_progress_event(out: &mut impl Write, event: &ProgressEvent) -> Result<()> {
+    serde_json::to_writer(&mut *out, event)?;
+    writeln!(out)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// 逐个清理项目，并把每个进度回调和项目完成事件写成一行 JSON
+fn clean_with_ndjson_progress(
+    cleaner: &ProjectCleaner,
+    projects: &[RustProject],
+    out: &mut impl Write,
+) -> CleanResult {
+    let out = RefCell::new(out);
+    let emit = |event: &ProgressEvent| {
+        // 进度流只是辅助输出，写失败（如读端已关闭）不影响清理本身
+        let _ = write_progress_event(&mut *out.borrow_mut(), event);
+    };
+
+    cleaner.clean_projects_with_progress(projects, None, |event| match event {
+        BatchCleanEvent::ProjectStarted { .. } => {}
+        BatchCleanEvent::Progress(progress) => emit(&ProgressEvent::Progress(&progress)),
+        BatchCleanEvent::ProjectCompleted {
+            project,
+            bytes_freed,
+            ..
+        } => emit(&ProgressEvent::ProjectDone {
+            project_name: &project.name,
+            bytes_freed,
+            error: None,
+        }),
+        BatchCleanEvent::ProjectFailed { project, error, .. } => {
+            let error = error.to_string();
+            emit(&ProgressEvent::ProjectDone {
+                project_name: &project.name,
+                bytes_freed: 0,
+                error: Some(&error),
+            });
+        }
+    })
+}
+
+/// 以 JSON 输出清理结果（包含逐项目结果）
+fn print_clean_result_json(
+    result: &purger_core::CleanResult,
+    out: &mut impl std::io::Write,
+) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, result)?;
+    writeln!(out)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn display_clean_result(result: &purger_core::CleanResult, verbose: bool) {
+    let _ = write_clean_summary(result, verbose, &mut io::stdout().lock());
+}
+
+/// 清理结果摘要；`verbose` 时按释放空间从大到小列出每个成功项目的释放大小和耗时
+fn write_clean_summary(
+    result: &purger_core::CleanResult,
+    verbose: bool,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(out, "\nCleaning completed!")?;
+    writeln!(out, "Projects cleaned: {}", result.cleaned_projects)?;
+    writeln!(out, "Size freed: {}", result.format_size())?;
+
+    if verbose {
+        let mut cleaned: Vec<_> = result
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.error.is_none())
+            .collect();
+        cleaned.sort_by_key(|outcome| std::cmp::Reverse(outcome.bytes_freed));
+        if !cleaned.is_empty() {
+            writeln!(out, "\nPer project:")?;
+        }
+        for outcome in cleaned {
+            writeln!(
+                out,
+                "  - {:<40} {:>12} {:>8} ms",
+                outcome.name,
+                purger_core::format_bytes(outcome.bytes_freed),
+                outcome.duration_ms
+            )?;
+        }
+    }
 
     if !result.failures.is_empty() {
-        println!("\nFailed to clean {} projects:", result.failures.len());
+        writeln!(out, "\nFailed to clean {} projects:", result.failures.len())?;
         for failure in &result.failures {
-            println!(
+            writeln!(
+                out,
                 "  - {} ({}): {}",
                 failure.project_name,
                 failure.project_path.display(),
                 failure.error
-            );
+            )?;
         }
     } else if !result.failed_projects.is_empty() {
-        println!(
+        writeln!(
+            out,
             "\nFailed to clean {} projects:",
             result.failed_projects.len()
-        );
+        )?;
         for project in &result.failed_projects {
-            println!("  - {project}");
+            writeln!(out, "  - {project}")?;
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -503,6 +2223,7 @@ mod tests {
     use super::*;
     use clap::Parser;
     use std::path::PathBuf;
+    use std::time::Duration;
     use tempfile::TempDir;
 
     #[test]
@@ -519,12 +2240,12 @@ mod tests {
 
         match cli.command {
             Commands::Scan {
-                path,
+                paths,
                 max_depth,
                 target_only,
                 ..
             } => {
-                assert_eq!(path, PathBuf::from("/tmp"));
+                assert_eq!(paths, vec![PathBuf::from("/tmp")]);
                 assert_eq!(max_depth, Some(3));
                 assert!(target_only);
             }
@@ -547,13 +2268,13 @@ mod tests {
 
         match cli.command {
             Commands::Clean {
-                path,
+                paths,
                 strategy,
                 dry_run,
                 yes,
                 ..
             } => {
-                assert_eq!(path, PathBuf::from("/tmp"));
+                assert_eq!(paths, vec![PathBuf::from("/tmp")]);
                 assert!(matches!(strategy, CleanStrategyArg::DirectDelete));
                 assert!(dry_run);
                 assert!(yes);
@@ -562,28 +2283,164 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_sort() {
+        let cli =
+            Cli::try_parse_from(["purger", "scan", "--sort", "modified", "--reverse"]).unwrap();
+        match cli.command {
+            Commands::Scan { sort, reverse, .. } => {
+                assert_eq!(sort, Some(SortKeyArg::Modified));
+                assert!(reverse);
+            }
+            _ => panic!("Expected Scan command"),
+        }
+
+        assert!(Cli::try_parse_from(["purger", "scan", "--reverse"]).is_err());
+        assert!(Cli::try_parse_from(["purger", "scan", "--sort", "size", "-S"]).is_err());
+        assert!(Cli::try_parse_from(["purger", "scan", "--sort", "date"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_clean_all() {
+        let cli = Cli::try_parse_from(["purger", "clean", "--all"]).unwrap();
+        assert!(matches!(cli.command, Commands::Clean { all: true, .. }));
+
+        let cli = Cli::try_parse_from(["purger", "clean"]).unwrap();
+        assert!(matches!(cli.command, Commands::Clean { all: false, .. }));
+
+        assert!(Cli::try_parse_from(["purger", "clean", "--all", "--interactive"]).is_err());
+        assert!(Cli::try_parse_from(["purger", "clean", "--all", "--confirm-each"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_show_files_requires_dry_run() {
+        assert!(Cli::try_parse_from(["purger", "clean", "--show-files"]).is_err());
+
+        let cli = Cli::try_parse_from(["purger", "clean", "--dry-run", "--show-files"]).unwrap();
+        match cli.command {
+            Commands::Clean { show_files, .. } => assert!(show_files),
+            _ => panic!("Expected Clean command"),
+        }
+
+        let cli = Cli::try_parse_from(["purger", "clean", "--dry-run", "--list"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Clean {
+                show_files: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_only_if_helps() {
+        let cli = Cli::try_parse_from([
+            "purger",
+            "clean",
+            "--only-if-helps",
+            "--free-threshold",
+            "50GB",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Clean {
+                only_if_helps,
+                free_threshold,
+                ..
+            } => {
+                assert!(only_if_helps);
+                assert_eq!(free_threshold, "50GB");
+            }
+            _ => panic!("Expected Clean command"),
+        }
+
+        // --free-threshold 需要配合 --only-if-helps
+        assert!(Cli::try_parse_from(["purger", "clean", "--free-threshold", "1GB"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_args_read_env() {
+        use clap::CommandFactory;
+
+        let command = Cli::command();
+        let env_of = |subcommand: &str, arg: &str| {
+            command
+                .find_subcommand(subcommand)
+                .and_then(|sub| sub.get_arguments().find(|a| a.get_id() == arg))
+                .and_then(|a| a.get_env())
+                .map(|env| env.to_string_lossy().to_string())
+        };
+
+        assert_eq!(
+            env_of("clean", "strategy").as_deref(),
+            Some("PURGER_STRATEGY")
+        );
+        for subcommand in ["scan", "clean"] {
+            assert_eq!(
+                env_of(subcommand, "keep_days").as_deref(),
+                Some("PURGER_KEEP_DAYS")
+            );
+            assert_eq!(
+                env_of(subcommand, "keep_size").as_deref(),
+                Some("PURGER_KEEP_SIZE")
+            );
+            assert_eq!(
+                env_of(subcommand, "ignore_paths").as_deref(),
+                Some("PURGER_IGNORE")
+            );
+        }
+    }
+
     #[test]
     fn test_create_scan_config() {
         let config = create_scan_config(ScanConfigArgs {
             max_depth: Some(5),
+            min_depth: Some(2),
             keep_days: Some(7),
+            older_than: Some("2w".to_string()),
+            time_basis: TimeBasisArg::NewestFileMtime,
+            size_mode: SizeModeArg::OnDisk,
             keep_size: Some("1MB".to_string()),
+            min_size: Some("100KB".to_string()),
             ignore_paths: vec![PathBuf::from("/ignore")],
+            manifest_globs: vec!["crates/**/Cargo.toml".to_string()],
+            extra_target_names: vec!["target-wasm".to_string()],
+            exclude_patterns: vec!["**/vendor/**".to_string()],
             no_parallel: false,
+            jobs: Some(2),
+            no_cache: true,
             follow_symlinks: true,
             include_hidden: false,
             no_gitignore: true,
+            no_purgerignore: true,
+            force: false,
         })
         .unwrap();
 
         assert_eq!(config.max_depth, Some(5));
+        assert_eq!(config.min_depth, Some(2));
+        assert_eq!(config.jobs, Some(2));
+        assert!(!config.use_cache);
         assert_eq!(config.keep_days, Some(7));
+        assert_eq!(config.keep_duration, Some(Duration::from_secs(14 * 86_400)));
+        assert_eq!(config.keep_threshold(), config.keep_duration);
+        assert_eq!(config.time_basis, TimeBasis::NewestFileMtime);
+        assert_eq!(config.size_mode, SizeMode::OnDisk);
         assert_eq!(config.keep_size, Some(1_000_000));
+        assert_eq!(config.min_clean_size, Some(100_000));
         assert_eq!(config.ignore_paths, vec![PathBuf::from("/ignore")]);
+        assert_eq!(
+            config.manifest_globs,
+            vec!["crates/**/Cargo.toml".to_string()]
+        );
+        assert_eq!(config.extra_target_names, vec!["target-wasm".to_string()]);
+        assert_eq!(config.exclude_patterns, vec!["**/vendor/**".to_string()]);
         assert!(config.parallel);
         assert!(config.follow_links);
         assert!(config.ignore_hidden);
         assert!(!config.respect_gitignore);
+        assert!(!config.purgerignore);
+        assert!(!config.force);
     }
 
     #[test]
@@ -596,13 +2453,816 @@ mod tests {
             CleanStrategy::from(CleanStrategyArg::DirectDelete),
             CleanStrategy::DirectDelete
         ));
+        assert!(matches!(
+            CleanStrategy::from(CleanStrategyArg::Trash),
+            CleanStrategy::Trash
+        ));
+    }
+
+    #[test]
+    fn test_print_paths_nul() {
+        use purger_core::RustProject;
+        use std::time::SystemTime;
+
+        let temp_dir = TempDir::new().unwrap();
+        let odd = temp_dir.path().join("with space\nnewline");
+        let projects = vec![
+            RustProject {
+                path: odd.clone(),
+                name: "odd".to_string(),
+                target_size: 0,
+                last_modified: SystemTime::now(),
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
+                has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
+            },
+            RustProject {
+                path: temp_dir.path().join("plain"),
+                name: "plain".to_string(),
+                target_size: 0,
+                last_modified: SystemTime::now(),
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
+                has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
+            },
+        ];
+
+        let mut out = Vec::new();
+        print_paths_nul(&projects, &mut out).unwrap();
+
+        let parts: Vec<_> = out.split(|b| *b == 0).collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], odd.as_os_str().as_encoded_bytes());
+        assert!(parts[2].is_empty());
+    }
+
+    #[test]
+    fn test_print_projects_json() {
+        use purger_core::RustProject;
+
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["a", "b"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target")).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+            std::fs::write(dir.join("target").join("out"), "12345").unwrap();
+        }
+        let projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+
+        let mut out = Vec::new();
+        print_projects_json(&projects, &mut out).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let parsed: Vec<RustProject> = serde_json::from_value(value["projects"].clone()).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(value["project_count"], 2);
+        assert_eq!(value["total_target_size"], 10);
+    }
+
+    #[test]
+    fn test_print_total_only() {
+        let temp_dir = TempDir::new().unwrap();
+        for (name, with_target) in [("a", true), ("b", true), ("c", false)] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+            if with_target {
+                std::fs::create_dir_all(dir.join("target")).unwrap();
+                std::fs::write(dir.join("target").join("out"), "12345").unwrap();
+            }
+        }
+        let projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+        assert_eq!(projects.len(), 3);
+
+        let mut out = Vec::new();
+        print_total_only(&projects, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "10 B\n");
+
+        let cli = Cli::try_parse_from(["purger", "scan", "--total-only"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Scan {
+                total_only: true,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["purger", "scan", "--total-only", "--print0"]).is_err());
+    }
+
+    #[test]
+    fn test_group_by_top_level() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        let projects_spec = [
+            ("work/api", 300),
+            ("work/web", 200),
+            ("oss/tool", 400),
+            ("scratch/a/b", 0),
+        ];
+        for (path, size) in projects_spec {
+            let dir = root.join(path);
+            std::fs::create_dir_all(&dir).unwrap();
+            let name = path.rsplit('/').next().unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+            if size > 0 {
+                std::fs::create_dir_all(dir.join("target")).unwrap();
+                std::fs::write(dir.join("target").join("out"), vec![0u8; size]).unwrap();
+            }
+        }
+        let projects = ProjectScanner::default().scan(&root).unwrap();
+
+        let groups = group_by_top_level(&projects, std::slice::from_ref(&root));
+        assert_eq!(
+            groups,
+            [
+                GroupSummary {
+                    group: root.join("work"),
+                    project_count: 2,
+                    reclaimable: 500,
+                },
+                GroupSummary {
+                    group: root.join("oss"),
+                    project_count: 1,
+                    reclaimable: 400,
+                },
+                GroupSummary {
+                    group: root.join("scratch"),
+                    project_count: 1,
+                    reclaimable: 0,
+                },
+            ]
+        );
+
+        // 项目就在扫描根时归入扫描根本身
+        let work = root.join("work").join("api");
+        let groups = group_by_top_level(&projects, std::slice::from_ref(&work));
+        assert!(
+            groups
+                .iter()
+                .any(|g| g.group == work && g.reclaimable == 300)
+        );
+
+        assert!(Cli::try_parse_from(["purger", "scan", "--group-by", "top-level"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["purger", "scan", "--group-by", "top-level", "--print0"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_format_conflicts_with_print0() {
+        let cli = Cli::try_parse_from(["purger", "scan", "--format", "json"]).unwrap();
+        match cli.command {
+            Commands::Scan { format, .. } => assert_eq!(format, OutputFormat::Json),
+            _ => panic!("Expected Scan command"),
+        }
+        assert!(Cli::try_parse_from(["purger", "scan", "--format", "json", "--print0"]).is_err());
+    }
+
+    #[test]
+    fn test_print_clean_result_json_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("proj");
+        std::fs::create_dir_all(dir.join("target")).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"proj\"\n").unwrap();
+        std::fs::write(dir.join("target").join("out"), "12345").unwrap();
+        let projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let result = cleaner.clean_projects(&projects);
+
+        let mut out = Vec::new();
+        print_clean_result_json(&result, &mut out).unwrap();
+        let parsed: purger_core::CleanResult = serde_json::from_slice(&out).unwrap();
+        assert_eq!(parsed.cleaned_projects, 1);
+        assert_eq!(parsed.total_size_freed, 5);
+        assert_eq!(parsed.outcomes.len(), 1);
+        assert_eq!(parsed.outcomes[0].name, "proj");
+        assert_eq!(parsed.outcomes[0].bytes_freed, 5);
+
+        let cli = Cli::try_parse_from(["purger", "clean", "--format", "json"]).unwrap();
+        match cli.command {
+            Commands::Clean { format, .. } => assert_eq!(format, OutputFormat::Json),
+            _ => panic!("Expected Clean command"),
+        }
+    }
+
+    #[test]
+    fn test_clean_with_ndjson_progress() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("proj");
+        std::fs::create_dir_all(dir.join("target")).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"proj\"\n").unwrap();
+        std::fs::write(dir.join("target").join("out"), "12345").unwrap();
+        let projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let mut out = Vec::new();
+        let result = clean_with_ndjson_progress(&cleaner, &projects, &mut out);
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(result.total_size_freed, 5);
+
+        let events: Vec<serde_json::Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(events.len() > 2);
+
+        let progress = &events[..events.len() - 1];
+        assert!(progress.iter().all(|event| {
+            event["event"] == "progress"
+                && event["project_name"] == "proj"
+                && event["files_processed"].is_u64()
+                && event.get("total_files").is_some()
+        }));
+        assert_eq!(progress[0]["phase"], "starting");
+        assert_eq!(progress[progress.len() - 1]["phase"], "complete");
+
+        let done = &events[events.len() - 1];
+        assert_eq!(done["event"], "project_done");
+        assert_eq!(done["project_name"], "proj");
+        assert_eq!(done["bytes_freed"], 5);
+        assert!(done["error"].is_null());
+
+        let cli = Cli::try_parse_from(["purger", "clean", "--progress", "ndjson"]).unwrap();
+        match cli.command {
+            Commands::Clean { progress, .. } => {
+                assert_eq!(progress, Some(ProgressFormat::Ndjson))
+            }
+            _ => panic!("Expected Clean command"),
+        }
+    }
+
+    #[test]
+    fn test_select_projects_interactive() {
+        let project = |name: &str| purger_core::RustProject {
+            path: PathBuf::from(format!("/test/{name}")),
+            name: name.to_string(),
+            target_size: 1000,
+            last_modified: std::time::SystemTime::now(),
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
+            has_target: true,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
+        };
+        let select = |answers: &str| -> Vec<String> {
+            let projects = ["a", "b", "c", "d"].map(project).to_vec();
+            let mut out = Vec::new();
+            select_projects_interactive(projects, &mut answers.as_bytes(), &mut out)
+                .unwrap()
+                .into_iter()
+                .map(|p| p.name)
+                .collect()
+        };
+
+        assert_eq!(select("y\nn\ny\nn\n"), vec!["a", "c"]);
+        // a 选中当前及剩余全部
+        assert_eq!(select("n\na\n"), vec!["b", "c", "d"]);
+        // q 放弃当前及剩余全部
+        assert_eq!(select("y\nq\n"), vec!["a"]);
+        // 无法识别的回答会重新询问
+        assert_eq!(select("maybe\nY\nno\nyes\n\nN\n"), vec!["a", "c"]);
+        // 输入提前结束视为 q
+        assert_eq!(select("y\n"), vec!["a"]);
+        assert!(select("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_project_answer() {
+        for (line, expected) in [
+            ("y", ProjectAnswer::Yes),
+            ("YES\n", ProjectAnswer::Yes),
+            ("n", ProjectAnswer::No),
+            ("  No ", ProjectAnswer::No),
+            ("a", ProjectAnswer::All),
+            ("all\r\n", ProjectAnswer::All),
+            ("q", ProjectAnswer::Quit),
+            ("Quit", ProjectAnswer::Quit),
+        ] {
+            assert_eq!(parse_project_answer(line), Some(expected), "{line:?}");
+        }
+        for line in ["", "\n", "maybe", "yy", "y n"] {
+            assert_eq!(parse_project_answer(line), None, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_select_projects_by_name() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["foo", "bar", "baz", "demo-one", "demo-two"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target")).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+        }
+        let projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+        let selected_names = |names: &[&str], globs: &[&str]| {
+            let names: Vec<String> = names.iter().map(|s| s.to_string()).collect();
+            let globs: Vec<String> = globs.iter().map(|s| s.to_string()).collect();
+            let mut selected: Vec<String> =
+                select_projects_by_name(projects.clone(), &names, &globs)
+                    .unwrap()
+                    .into_iter()
+                    .map(|project| project.name)
+                    .collect();
+            selected.sort();
+            selected
+        };
+
+        assert_eq!(selected_names(&["foo", "bar"], &[]), ["bar", "foo"]);
+        // 名称必须完全相同
+        assert!(selected_names(&["fo", "demo"], &[]).is_empty());
+        assert_eq!(selected_names(&[], &["demo-*"]), ["demo-one", "demo-two"]);
+        assert_eq!(selected_names(&["baz"], &["*-two"]), ["baz", "demo-two"]);
+
+        let cli = Cli::try_parse_from([
+            "purger",
+            "clean",
+            "--only",
+            "foo",
+            "--only",
+            "bar",
+            "--only-glob",
+            "demo-*",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Clean {
+                only, only_glob, ..
+            } => {
+                assert_eq!(only, ["foo", "bar"]);
+                assert_eq!(only_glob, ["demo-*"]);
+            }
+            _ => panic!("Expected Clean command"),
+        }
+    }
+
+    #[test]
+    fn test_check_clean_failures() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["ok", "pinned"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target")).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+        }
+        // 带 .purger-keep 的项目在没有 --force 时清理失败
+        std::fs::write(
+            temp_dir
+                .path()
+                .join("pinned")
+                .join(purger_core::KEEP_MARKER),
+            "",
+        )
+        .unwrap();
+        let projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let result = cleaner.clean_projects(&projects);
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(result.failed_projects.len(), 1);
+
+        let err = check_clean_failures(&result, false).unwrap_err();
+        assert!(err.to_string().contains("1 project(s) failed"));
+        assert!(check_clean_failures(&result, true).is_ok());
+        assert!(check_clean_failures(&CleanResult::new(), false).is_ok());
+    }
+
+    #[test]
+    fn test_verbose_clean_summary() {
+        let mut result = CleanResult::new();
+        for (name, bytes_freed, error) in [
+            ("small", 1024, None),
+            ("large", 10 * 1024 * 1024, None),
+            ("broken", 0, Some("boom".to_string())),
+        ] {
+            result.add_outcome(ProjectCleanOutcome {
+                name: name.to_string(),
+                path: PathBuf::from(format!("/p/{name}")),
+                bytes_freed,
+                duration_ms: 42,
+                error,
+            });
+        }
+
+        let mut out = Vec::new();
+        write_clean_summary(&result, true, &mut out).unwrap();
+        let verbose = String::from_utf8(out).unwrap();
+        assert!(verbose.contains("Projects cleaned: 2"));
+        for name in ["small", "large", "broken"] {
+            assert!(verbose.contains(name), "{name} missing from:\n{verbose}");
+        }
+        // 按释放空间从大到小排列
+        assert!(verbose.find("large").unwrap() < verbose.find("small").unwrap());
+        assert!(verbose.contains("10.00 MiB"));
+        assert!(verbose.contains("42 ms"));
+
+        let mut out = Vec::new();
+        write_clean_summary(&result, false, &mut out).unwrap();
+        let brief = String::from_utf8(out).unwrap();
+        assert!(!brief.contains("Per project"));
+        assert!(!brief.contains("small"));
+    }
+
+    /// 只设置了 `--older-than` 的扫描配置
+    fn older_than_scan_config(older_than: &str) -> Result<ScanConfig> {
+        create_scan_config(ScanConfigArgs {
+            older_than: Some(older_than.to_string()),
+            ..default_scan_args()
+        })
+    }
+
+    /// 所有扫描参数都取命令行默认值
+    pub(crate) fn default_scan_args() -> ScanConfigArgs {
+        ScanConfigArgs {
+            max_depth: None,
+            min_depth: None,
+            keep_days: None,
+            older_than: None,
+            time_basis: TimeBasisArg::DirMtime,
+            size_mode: SizeModeArg::Apparent,
+            keep_size: None,
+            min_size: None,
+            ignore_paths: Vec::new(),
+            manifest_globs: Vec::new(),
+            extra_target_names: Vec::new(),
+            exclude_patterns: Vec::new(),
+            no_parallel: false,
+            jobs: None,
+            no_cache: true,
+            follow_symlinks: false,
+            include_hidden: false,
+            no_gitignore: false,
+            no_purgerignore: false,
+            force: false,
+        }
+    }
+
+    #[test]
+    fn test_clean_ignore_skips_project() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        for name in ["keepme", "cleanme"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target/debug"))?;
+            std::fs::write(dir.join("target/debug/app"), "binary")?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+        }
+
+        let scan_config = create_scan_config(ScanConfigArgs {
+            ignore_paths: vec![temp_dir.path().join("keepme")],
+            ..default_scan_args()
+        })?;
+        let projects = find_clean_candidates(&scan_config, &[temp_dir.path().to_path_buf()])?;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["cleanme"]);
+
+        ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        })
+        .clean_projects(&projects);
+        assert!(temp_dir.path().join("keepme/target/debug/app").exists());
+        assert!(!temp_dir.path().join("cleanme/target").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_older_than_selects_stale() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        for name in ["fresh", "stale"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target/debug"))?;
+            std::fs::write(dir.join("target/debug/app"), "binary")?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+        }
+        let old = SystemTime::now() - Duration::from_secs(90 * 86_400);
+        std::fs::File::open(temp_dir.path().join("stale/target"))?.set_modified(old)?;
+
+        let scan_config = older_than_scan_config("30d")?;
+        let projects = find_clean_candidates(&scan_config, &[temp_dir.path().to_path_buf()])?;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["stale"]);
+
+        let result = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        })
+        .clean_projects(&projects);
+        assert_eq!(result.cleaned_projects, 1);
+        assert!(!temp_dir.path().join("stale/target").exists());
+        assert!(temp_dir.path().join("fresh/target/debug/app").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_if_stale_is_opt_in() {
+        // 只能和 --older-than 一起使用
+        let cli = Cli::try_parse_from(["purger", "clean", "--remove-if-stale"]).unwrap();
+        assert!(cli.command.validate().is_err());
+        let cli = Cli::try_parse_from([
+            "purger",
+            "clean",
+            "--older-than",
+            "30d",
+            "--remove-if-stale",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Clean {
+                remove_if_stale: true,
+                ..
+            }
+        ));
+
+        // outer 本身很久没编译，但里面嵌套的 inner 最近编译过
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["fresh", "stale", "failed", "outer", "outer/inner"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target")).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\n", name.replace('/', "-")),
+            )
+            .unwrap();
+        }
+        // 工作区成员随工作区根一起删除，不算需要保留的项目
+        let ws = temp_dir.path().join("ws");
+        std::fs::create_dir_all(ws.join("target")).unwrap();
+        std::fs::create_dir_all(ws.join("member")).unwrap();
+        std::fs::write(
+            ws.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"member\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            ws.join("member/Cargo.toml"),
+            "[package]\nname = \"member\"\n",
+        )
+        .unwrap();
+        let now = SystemTime::now();
+        let month = Duration::from_secs(30 * 24 * 3600);
+        for name in ["stale", "failed", "outer", "ws"] {
+            std::fs::File::open(temp_dir.path().join(name).join("target"))
+                .unwrap()
+                .set_modified(now - 2 * month)
+                .unwrap();
+        }
+
+        let scan_config = older_than_scan_config("30d").unwrap();
+        let projects =
+            find_clean_candidates(&scan_config, &[temp_dir.path().to_path_buf()]).unwrap();
+        let mut names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, ["failed", "outer", "stale", "ws"]);
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let mut result = cleaner.clean_projects(&projects);
+        // 清理失败的项目不会被整个删除
+        let failed = result
+            .outcomes
+            .iter_mut()
+            .find(|outcome| outcome.name == "failed")
+            .unwrap();
+        failed.error = Some("boom".to_string());
+
+        // 没有 --remove-if-stale 时普通清理从不删除项目目录
+        assert!(
+            stale_projects_to_remove(&projects, &result, &scan_config, false, month, now)
+                .unwrap()
+                .is_empty()
+        );
+
+        // outer 中还有不删除的 inner，整个目录保留
+        let stale =
+            stale_projects_to_remove(&projects, &result, &scan_config, true, month, now).unwrap();
+        let mut names: Vec<_> = stale.iter().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, ["stale", "ws"]);
+
+        // 需要输入 delete，y 不算确认
+        let mut out = Vec::new();
+        assert!(
+            !confirm_remove_stale(&stale, temp_dir.path(), &mut &b"y\n"[..], &mut out).unwrap()
+        );
+        assert!(String::from_utf8(out).unwrap().contains("stale"));
+        assert!(
+            confirm_remove_stale(
+                &stale,
+                temp_dir.path(),
+                &mut &b"delete\n"[..],
+                &mut Vec::new()
+            )
+            .unwrap()
+        );
+
+        remove_stale_projects(&stale, temp_dir.path());
+        assert!(!temp_dir.path().join("stale").exists());
+        assert!(!ws.exists());
+        assert!(temp_dir.path().join("fresh").join("Cargo.toml").exists());
+        assert!(temp_dir.path().join("failed").join("Cargo.toml").exists());
+        assert!(temp_dir.path().join("outer/inner/target").exists());
+    }
+
+    #[test]
+    fn test_remove_if_stale_skips_ignored() {
+        let temp_dir = TempDir::new().unwrap();
+        let now = SystemTime::now();
+        let month = Duration::from_secs(30 * 24 * 3600);
+        for name in ["ignored", "stale"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target")).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+            std::fs::File::open(dir.join("target"))
+                .unwrap()
+                .set_modified(now - 2 * month)
+                .unwrap();
+        }
+
+        // 即使候选列表里混进了被忽略的项目，也绝不整个删除它
+        let projects = find_clean_candidates(
+            &older_than_scan_config("30d").unwrap(),
+            &[temp_dir.path().to_path_buf()],
+        )
+        .unwrap();
+        assert_eq!(projects.len(), 2);
+        let result = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        })
+        .clean_projects(&projects);
+
+        let scan_config = create_scan_config(ScanConfigArgs {
+            older_than: Some("30d".to_string()),
+            ignore_paths: vec![temp_dir.path().join("ignored")],
+            ..default_scan_args()
+        })
+        .unwrap();
+        let stale =
+            stale_projects_to_remove(&projects, &result, &scan_config, true, month, now).unwrap();
+        let names: Vec<_> = stale.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["stale"]);
+    }
+
+    #[test]
+    fn test_clean_with_confirm_each() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["a", "b", "c"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target")).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )
+            .unwrap();
+            std::fs::write(dir.join("target").join("out"), "12345").unwrap();
+        }
+        let mut projects = ProjectScanner::default().scan(temp_dir.path()).unwrap();
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let mut out = Vec::new();
+        let result = clean_with_confirm_each(
+            &cleaner,
+            &projects,
+            &mut "n\nmaybe\ny\nq\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(result.outcomes[0].name, "b");
+        assert!(projects[0].target_path().exists());
+        assert!(!projects[1].target_path().exists());
+        assert!(projects[2].target_path().exists());
+
+        let prompts = String::from_utf8(out).unwrap();
+        assert!(prompts.contains(&projects[0].target_path().display().to_string()));
+        assert!(prompts.contains("Please answer y, n, a or q."));
+    }
+
+    #[test]
+    fn test_cli_parse_executable_filter() {
+        let cli = Cli::try_parse_from(["purger", "clean", "--keep-executable"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Clean {
+                executable_filter: ExecutableFilterArg::Any,
+                ..
+            }
+        ));
+
+        let cli = Cli::try_parse_from([
+            "purger",
+            "clean",
+            "--keep-executable",
+            "--executable-filter",
+            "crate-binaries",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Clean {
+                executable_filter: ExecutableFilterArg::CrateBinaries,
+                ..
+            }
+        ));
+
+        assert!(
+            Cli::try_parse_from(["purger", "clean", "--executable-filter", "crate-binaries"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_parse_restore_command() {
+        let cli = Cli::try_parse_from([
+            "purger",
+            "restore",
+            "/tmp/project",
+            "--executable-backup-dir",
+            "/tmp/backup",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Restore {
+                path,
+                executable_backup_dir,
+            } => {
+                assert_eq!(path, PathBuf::from("/tmp/project"));
+                assert_eq!(executable_backup_dir, Some(PathBuf::from("/tmp/backup")));
+            }
+            _ => panic!("Expected Restore command"),
+        }
     }
 
     #[test]
     fn test_display_projects_empty() {
         let projects = vec![];
         let temp_dir = TempDir::new().unwrap();
-        let result = display_projects(&projects, temp_dir.path());
+        let result = display_projects(&projects, temp_dir.path(), true);
         assert!(result.is_ok());
     }
 
@@ -617,16 +3277,30 @@ mod tests {
                 name: "test1".to_string(),
                 target_size: 1000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: PathBuf::from("/test2"),
                 name: "test2".to_string(),
                 target_size: 2000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
         ];
 
@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use purger_core::CleanResult;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 将清理结果渲染为 Prometheus 文本格式（node_exporter textfile collector 兼容）
+pub(crate) fn render_prometheus(result: &CleanResult, finished_at: SystemTime) -> String {
+    let failures = result.failures.len().max(result.failed_projects.len());
+    let timestamp = finished_at
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let metrics: [(&str, &str, String); 5] = [
+        (
+            "purger_projects_cleaned",
+            "Number of projects cleaned in the last run.",
+            result.cleaned_projects.to_string(),
+        ),
+        (
+            "purger_bytes_freed",
+            "Bytes freed in the last run.",
+            result.total_size_freed.to_string(),
+        ),
+        (
+            "purger_failures",
+            "Number of projects that failed to clean in the last run.",
+            failures.to_string(),
+        ),
+        (
+            "purger_duration_seconds",
+            "Duration of the last run in seconds.",
+            format!("{:.3}", result.duration_ms as f64 / 1000.0),
+        ),
+        (
+            "purger_last_run_timestamp",
+            "Unix timestamp of the last run.",
+            timestamp.to_string(),
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, help, value) in metrics {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        let _ = writeln!(out, "{name} {value}");
+    }
+    out
+}
+
+/// 写入指标文件（先写临时文件再重命名，避免 collector 读到半截内容）
+pub(crate) fn write_metrics_file(path: &Path, result: &CleanResult) -> Result<()> {
+    let content = render_prometheus(result, SystemTime::now());
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    std::fs::write(&tmp_path, content)
+        .with_context(|| format!("Failed to write metrics file: {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("Failed to write metrics file: {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn sample_result() -> CleanResult {
+        let mut result = CleanResult::new();
+        result.add_success(1024);
+        result.add_success(2048);
+        result.add_failure("broken".to_string());
+        result.duration_ms = 1500;
+        result
+    }
+
+    #[test]
+    fn test_render_prometheus() {
+        let finished_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let text = render_prometheus(&sample_result(), finished_at);
+
+        assert!(text.contains("# TYPE purger_projects_cleaned gauge\npurger_projects_cleaned 2\n"));
+        assert!(text.contains("\npurger_bytes_freed 3072\n"));
+        assert!(text.contains("\npurger_failures 1\n"));
+        assert!(text.contains("\npurger_duration_seconds 1.500\n"));
+        assert!(text.contains("\npurger_last_run_timestamp 1700000000\n"));
+
+        // 每个指标都有 HELP 和 TYPE
+        assert_eq!(text.matches("# HELP ").count(), 5);
+        assert_eq!(text.matches("# TYPE ").count(), 5);
+        assert!(text.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_write_metrics_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("purger.prom");
+
+        write_metrics_file(&path, &sample_result()).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("purger_projects_cleaned 2"));
+        assert!(!temp_dir.path().join("purger.prom.tmp").exists());
+    }
+}
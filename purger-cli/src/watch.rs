@@ -0,0 +1,254 @@
+use anyhow::Result;
+use purger_core::{
+    CleanResult, ProjectCleaner, ProjectFilter, ProjectScanner, RustProject, cleaner::CleanConfig,
+    scanner::ScanConfig,
+};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::{
+    CleanStrategyArg, ScanConfigArgs, confirm_clean, create_scan_config, display_base,
+    display_clean_result, display_projects, find_clean_candidates, install_interrupt_handler,
+};
+
+/// watch 命令的参数配置
+#[derive(Debug)]
+pub(crate) struct WatchCommandArgs {
+    pub paths: Vec<PathBuf>,
+    pub interval: String,
+    pub strategy: CleanStrategyArg,
+    pub dry_run: bool,
+    pub yes: bool,
+    pub scan: ScanConfigArgs,
+}
+
+/// 解析 `--interval`，不接受零间隔
+pub(crate) fn parse_interval(interval: &str) -> Result<Duration> {
+    let duration = ProjectFilter::parse_duration_string(interval)?;
+    if duration.is_zero() {
+        anyhow::bail!("--interval must be greater than zero");
+    }
+    Ok(duration)
+}
+
+pub(crate) fn handle_watch_command(args: WatchCommandArgs) -> Result<()> {
+    if args.scan.older_than.is_none() && args.scan.keep_days.is_none() {
+        anyhow::bail!("watch requires --older-than (or --keep-days)");
+    }
+    let interval = parse_interval(&args.interval)?;
+    let scan_config = create_scan_config(args.scan)?;
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let cleaner = ProjectCleaner::new(CleanConfig {
+        strategy: args.strategy.into(),
+        dry_run: args.dry_run,
+        parallel: scan_config.parallel,
+        jobs: scan_config.jobs,
+        ..Default::default()
+    })
+    .with_stop_flag(stop_flag.clone());
+
+    // 第一次真正清理前确认一次，之后各轮自动清理；确认后才拦截 Ctrl+C，
+    // 收到后完成当前项目、结束本轮并退出
+    let mut confirmed = args.yes || args.dry_run;
+    if confirmed {
+        install_interrupt_handler(stop_flag.clone());
+    }
+    loop {
+        let step = watch_step(&scan_config, &args.paths, &cleaner, |projects| {
+            if confirmed {
+                return Ok(true);
+            }
+            confirmed = confirm_clean(projects)?;
+            if confirmed {
+                install_interrupt_handler(stop_flag.clone());
+            }
+            Ok(confirmed)
+        });
+        match step {
+            Ok(Some(result)) => display_clean_result(&result, false),
+            Ok(None) => {
+                println!("Watch cancelled.");
+                return Ok(());
+            }
+            // 单轮失败（如路径暂时不可用）不终止 watch
+            Err(e) => eprintln!("Watch round failed: {e:#}"),
+        }
+
+        if !cleaner.stop_requested() {
+            println!("Next scan in {}.", args.interval);
+        }
+        if !wait_interval(interval, &stop_flag) {
+            println!("Watch stopped.");
+            return Ok(());
+        }
+    }
+}
+
+/// 等待下一轮，分段睡眠以便及时响应 Ctrl+C；等满间隔返回 `true`，被中断返回 `false`
+fn wait_interval(interval: Duration, stop_flag: &AtomicBool) -> bool {
+    let deadline = Instant::now() + interval;
+    while !stop_flag.load(Ordering::SeqCst) {
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        std::thread::sleep((deadline - now).min(Duration::from_millis(200)));
+    }
+    false
+}
+
+/// watch 的单轮：按与 `clean` 相同的条件选出项目，确认后清理
+///
+/// `confirm` 返回 `false` 时不清理并返回 `None`；没有需要清理的项目时不调用 `confirm`，返回空结果。
+pub(crate) fn watch_step(
+    scan_config: &ScanConfig,
+    paths: &[PathBuf],
+    cleaner: &ProjectCleaner,
+    mut confirm: impl FnMut(&[RustProject]) -> Result<bool>,
+) -> Result<Option<CleanResult>> {
+    let mut projects = find_clean_candidates(scan_config, paths)?;
+    if !scan_config.force {
+        projects = ProjectScanner::partition_pinned(projects).0;
+    }
+
+    if projects.is_empty() {
+        println!("No stale projects found.");
+        return Ok(Some(CleanResult::new()));
+    }
+
+    println!("Found {} stale projects:", projects.len());
+    display_projects(&projects, display_base(paths), false)?;
+    if !confirm(&projects)? {
+        return Ok(None);
+    }
+    Ok(Some(cleaner.clean_projects(&projects)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use purger_core::CleanStrategy;
+    use std::time::SystemTime;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("6h").unwrap(), Duration::from_secs(6 * 3600));
+        assert_eq!(parse_interval("30m").unwrap(), Duration::from_secs(30 * 60));
+        assert_eq!(parse_interval("1d").unwrap(), Duration::from_secs(86_400));
+        assert!(parse_interval("0h").is_err());
+        assert!(parse_interval("6").is_err());
+        assert!(parse_interval("soon").is_err());
+    }
+
+    #[test]
+    fn test_wait_interval() {
+        let stop_flag = AtomicBool::new(false);
+        assert!(wait_interval(Duration::from_millis(10), &stop_flag));
+        stop_flag.store(true, Ordering::SeqCst);
+        assert!(!wait_interval(Duration::from_secs(3600), &stop_flag));
+    }
+
+    #[test]
+    fn test_watch_step() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        for name in ["fresh", "stale"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target/debug"))?;
+            std::fs::write(dir.join("target/debug/app"), "binary")?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+        }
+        let stale_target = temp_dir.path().join("stale/target");
+        let old = SystemTime::now() - Duration::from_secs(60 * 86_400);
+        std::fs::File::open(&stale_target)?.set_modified(old)?;
+
+        let scan_config = ScanConfig {
+            keep_duration: Some(Duration::from_secs(30 * 86_400)),
+            use_cache: false,
+            ..Default::default()
+        };
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let paths = vec![temp_dir.path().to_path_buf()];
+
+        // 拒绝确认时不清理
+        let mut asked = Vec::new();
+        let declined = watch_step(&scan_config, &paths, &cleaner, |projects| {
+            asked.extend(projects.iter().map(|p| p.name.clone()));
+            Ok(false)
+        })?;
+        assert!(declined.is_none());
+        assert_eq!(asked, vec!["stale".to_string()]);
+        assert!(stale_target.exists());
+
+        let result = watch_step(&scan_config, &paths, &cleaner, |_| Ok(true))?.unwrap();
+        assert_eq!(result.cleaned_projects, 1);
+        assert!(!stale_target.exists());
+        assert!(temp_dir.path().join("fresh/target/debug/app").exists());
+
+        // 下一轮没有可清理的项目，也不会再询问
+        let result = watch_step(&scan_config, &paths, &cleaner, |_| {
+            panic!("nothing to confirm")
+        })?
+        .unwrap();
+        assert_eq!(result.cleaned_projects, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_watch_ignore() -> Result<()> {
+        use clap::Parser;
+
+        let temp_dir = TempDir::new()?;
+        let old = SystemTime::now() - Duration::from_secs(60 * 86_400);
+        for name in ["ignored", "stale"] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target"))?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+            std::fs::File::open(dir.join("target"))?.set_modified(old)?;
+        }
+
+        let ignored = temp_dir.path().join("ignored");
+        let root = temp_dir.path().to_path_buf();
+        let cli = crate::Cli::try_parse_from([
+            "purger".as_ref(),
+            "watch".as_ref(),
+            "--older-than".as_ref(),
+            "30d".as_ref(),
+            "--no-cache".as_ref(),
+            "--ignore".as_ref(),
+            ignored.as_os_str(),
+            root.as_os_str(),
+        ])?;
+        let crate::Commands::Watch { paths, scan, .. } = cli.command else {
+            panic!("expected watch");
+        };
+        let scan_config = create_scan_config(scan)?;
+
+        // 被 --ignore 的项目不会被选出
+        let mut asked = Vec::new();
+        watch_step(
+            &scan_config,
+            &paths,
+            &ProjectCleaner::new(CleanConfig::default()),
+            |projects| {
+                asked.extend(projects.iter().map(|p| p.name.clone()));
+                Ok(false)
+            },
+        )?;
+        assert_eq!(asked, vec!["stale".to_string()]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::project::{LargestFile, RustProject, SizeMode};
+
+/// 磁盘缓存中的单个项目：target 修改时间不变时直接复用上次计算的大小
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedSize {
+    pub path: PathBuf,
+    pub target_mtime: SystemTime,
+    /// target 内前两层条目（如 `debug/deps/`、`debug/.fingerprint/`）中最新的修改时间
+    #[serde(default)]
+    pub contents_mtime: Option<SystemTime>,
+    pub target_size: u64,
+    pub largest_file: Option<LargestFile>,
+}
+
+/// 按扫描根目录保存的项目大小缓存（`<cache_dir>/purger/<root hash>.json`）
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct SizeCache {
+    root: PathBuf,
+    /// 大小是否按硬链接去重计算，与当前配置不一致时整个缓存作废
+    dedup_hardlinks: bool,
+    /// 大小的统计方式，与当前配置不一致时整个缓存作废
+    #[serde(default)]
+    size_mode: SizeMode,
+    projects: Vec<CachedSize>,
+    #[serde(skip)]
+    index: HashMap<PathBuf, usize>,
+}
+
+impl SizeCache {
+    /// 扫描根对应的缓存文件，`cache_dir` 为 `None` 时使用系统缓存目录
+    pub(crate) fn file_for(cache_dir: Option<&Path>, root: &Path) -> Option<PathBuf> {
+        let dir = match cache_dir {
+            Some(dir) => dir.to_path_buf(),
+            None => dirs::cache_dir()?.join("purger"),
+        };
+        let mut hasher = DefaultHasher::new();
+        Self::root_key(root).hash(&mut hasher);
+        Some(dir.join(format!("{:016x}.json", hasher.finish())))
+    }
+
+    /// 读取缓存，文件不存在、损坏或与当前扫描不匹配时返回空缓存
+    pub(crate) fn load(
+        file: &Path,
+        root: &Path,
+        dedup_hardlinks: bool,
+        size_mode: SizeMode,
+    ) -> Self {
+        let cache: Option<Self> = std::fs::read_to_string(file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+        let mut cache = cache
+            .filter(|cache| {
+                cache.root == Self::root_key(root)
+                    && cache.dedup_hardlinks == dedup_hardlinks
+                    && cache.size_mode == size_mode
+            })
+            .unwrap_or_default();
+        cache.index = cache
+            .projects
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (entry.path.clone(), i))
+            .collect();
+        cache
+    }
+
+    /// 用本次扫描的结果生成新的缓存
+    pub(crate) fn from_projects(
+        root: &Path,
+        dedup_hardlinks: bool,
+        size_mode: SizeMode,
+        projects: &[RustProject],
+    ) -> Self {
+        let projects = projects
+            .iter()
+            .filter(|project| project.has_target)
+            .filter_map(|project| {
+                Some(CachedSize {
+                    path: project.path.clone(),
+                    target_mtime: target_mtime(project)?,
+                    contents_mtime: latest_mtime(&project.target_path(), 2),
+                    target_size: project.target_size,
+                    largest_file: project.largest_file.clone(),
+                })
+            })
+            .collect();
+        Self {
+            root: Self::root_key(root),
+            dedup_hardlinks,
+            size_mode,
+            projects,
+            index: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn save(&self, file: &Path) -> Result<()> {
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).context("创建缓存目录失败")?;
+        }
+        let content = serde_json::to_string(self)?;
+        std::fs::write(file, content).context("写入扫描缓存失败")
+    }
+
+    /// 查找 target 及其内容的修改时间都与缓存一致的项目
+    ///
+    /// 重新编译通常只改动 `target/<profile>/deps` 等子目录，target 本身的修改时间不变，因此还要比较内容。
+    pub(crate) fn lookup(&self, project: &RustProject) -> Option<&CachedSize> {
+        let entry = &self.projects[*self.index.get(&project.path)?];
+        (target_mtime(project)? == entry.target_mtime
+            && latest_mtime(&project.target_path(), 2) == entry.contents_mtime)
+            .then_some(entry)
+    }
+
+    fn root_key(root: &Path) -> PathBuf {
+        root.canonicalize().unwrap_or_else(|_| root.to_path_buf())
+    }
+}
+
+fn target_mtime(project: &RustProject) -> Option<SystemTime> {
+    std::fs::metadata(project.target_path())
+        .ok()?
+        .modified()
+        .ok()
+}
+
+/// 目录下 `depth` 层以内条目的最新修改时间，不跟随符号链接
+fn latest_mtime(dir: &Path, depth: usize) -> Option<SystemTime> {
+    if depth == 0 {
+        return None;
+    }
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            let mtime = metadata.modified().ok()?;
+            let nested = metadata
+                .is_dir()
+                .then(|| latest_mtime(&entry.path(), depth - 1))
+                .flatten();
+            Some(nested.map_or(mtime, |nested| nested.max(mtime)))
+        })
+        .max()
+}
@@ -1,18 +1,19 @@
 use anyhow::{Context, Result};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use std::collections::HashSet;
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, info_span, warn};
 use walkdir::WalkDir;
 
+use crate::error::{PurgerError, PurgerResult};
 use crate::project::RustProject;
-use crate::{CleanFailure, CleanResult};
+use crate::{CleanFailure, CleanResult, ProjectCleanOutcome};
 
 /// 清理策略
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
@@ -22,6 +23,18 @@ pub enum CleanStrategy {
     CargoClean,
     /// 直接删除target目录
     DirectDelete,
+    /// 将target目录移到系统回收站（可恢复）
+    Trash,
+}
+
+/// 备份目录中记录原始位置的清单文件名
+const BACKUP_MANIFEST: &str = "purger-backup.json";
+
+/// 备份清单中的一项：备份文件（相对备份目录）及其在 target 中的原始相对路径
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BackupEntry {
+    backup: PathBuf,
+    original: PathBuf,
 }
 
 /// Backend for `CleanStrategy::DirectDelete`.
@@ -35,26 +48,146 @@ pub enum DirectDeleteBackend {
     CmdRmdir,
 }
 
+/// target 超出单次删除上限（见 [`CleanConfig::max_delete_files`]/[`CleanConfig::max_delete_bytes`]）
+#[derive(Debug, thiserror::Error)]
+#[error("skipped {path:?}: {reason} (use --force to override)")]
+pub struct DeleteLimitExceeded {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// 项目目录中有 `.purger-keep` 标记
+#[derive(Debug, thiserror::Error)]
+#[error("skipped {path:?}: pinned by .purger-keep (use --force to override)")]
+pub struct ProjectPinned {
+    pub path: PathBuf,
+}
+
+/// target 正在被 cargo 使用（构建进行中）
+#[derive(Debug, thiserror::Error)]
+#[error("build in progress: {lock:?} is locked by cargo (use --force to override)")]
+pub struct BuildInProgress {
+    pub lock: PathBuf,
+}
+
+/// `keep_executable` 时挑选要备份的可执行文件的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExecutableFilter {
+    /// 只备份以包名或 bin 目标命名的二进制，跳过动态库和 build 脚本产物
+    CrateBinaries,
+    /// 备份所有带可执行权限（Windows 上为 `.exe`）的文件（默认）
+    #[default]
+    Any,
+}
+
+/// 扫描后 target 发生明显变化时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ChangedPolicy {
+    /// 不重新检查（默认）
+    #[default]
+    Ignore,
+    /// 记录警告，按最新的大小继续清理
+    Warn,
+    /// 跳过该项目，返回 [`TargetChanged`] 错误
+    Skip,
+    /// 返回 [`TargetChanged`] 错误，由调用方向用户重新确认后再清理
+    Confirm,
+}
+
+/// 清理前 target 的大小相对扫描结果变化超过阈值
+#[derive(Debug, thiserror::Error)]
+#[error("target changed since scan: {path:?} ({before} -> {after} bytes)")]
+pub struct TargetChanged {
+    pub path: PathBuf,
+    pub before: u64,
+    pub after: u64,
+}
+
 /// 清理进度信息
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CleanProgress {
     pub project_name: String,
     pub current_file: Option<String>,
     pub files_processed: usize,
     pub total_files: Option<usize>,
     pub phase: CleanPhase,
+    /// 当前项目开始清理以来的耗时（毫秒），由 [`ProjectCleaner::clean_project_with_progress_and_cancel`] 统一填写
+    pub elapsed_ms: u64,
+}
+
+impl CleanProgress {
+    /// 按已处理文件数估算当前项目的剩余时间，总文件数未知或尚未处理任何文件时返回 None
+    pub fn eta(&self) -> Option<Duration> {
+        estimate_remaining(
+            self.files_processed as u64,
+            self.total_files? as u64,
+            Duration::from_millis(self.elapsed_ms),
+        )
+    }
+}
+
+/// 按匀速估算剩余时间：已完成 `done`/`total`，耗时 `elapsed`
+///
+/// 还没有任何进度（无从估算）时返回 None，已完成时返回 0。
+pub fn estimate_remaining(done: u64, total: u64, elapsed: Duration) -> Option<Duration> {
+    if done >= total {
+        return Some(Duration::ZERO);
+    }
+    if done == 0 {
+        return None;
+    }
+    let remaining = (total - done) as f64 / done as f64;
+    Some(elapsed.mul_f64(remaining))
 }
 
 /// 清理阶段
-#[derive(Debug, Clone, PartialEq)]
+///
+/// 两种清理策略都会依次经历全部阶段（dry run 除外）。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CleanPhase {
+    /// 开始处理项目
     Starting,
+    /// 分析 target 目录（计算大小、备份可执行文件等）
     Analyzing,
+    /// 正在删除/执行 cargo clean
     Cleaning,
+    /// 删除后的收尾工作（统计剩余大小、校验结果等）
     Finalizing,
+    /// 项目清理完成
     Complete,
 }
 
+/// [`ProjectCleaner::clean_projects_with_progress`] 在批量清理过程中发出的事件
+#[derive(Debug)]
+pub enum BatchCleanEvent<'a> {
+    /// 开始清理第 `index` 个项目（从 0 开始）
+    ProjectStarted {
+        index: usize,
+        total: usize,
+        project: &'a RustProject,
+    },
+    /// 当前项目内部的进度
+    Progress(CleanProgress),
+    /// 项目清理成功，`total_freed` 为本批次累计释放的空间
+    ProjectCompleted {
+        index: usize,
+        total: usize,
+        project: &'a RustProject,
+        bytes_freed: u64,
+        total_freed: u64,
+    },
+    /// 项目清理失败（取消不算失败，不会发出此事件）
+    ProjectFailed {
+        index: usize,
+        total: usize,
+        project: &'a RustProject,
+        error: &'a PurgerError,
+        total_freed: u64,
+    },
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("clean cancelled")]
 pub struct CleanCancelled;
@@ -86,6 +219,39 @@ pub struct CleanConfig {
     pub keep_executable: bool,
     /// 可执行文件备份目录（如果为None，则在项目目录下创建executables文件夹）
     pub executable_backup_dir: Option<PathBuf>,
+    /// 哪些可执行文件需要备份
+    pub executable_filter: ExecutableFilter,
+
+    /// 允许清理受保护的系统路径（见 [`crate::safety::is_dangerous_root`]）
+    pub force: bool,
+
+    /// 清理前重新统计 target，大小变化超过阈值时的处理方式
+    pub on_changed: ChangedPolicy,
+    /// 视为"明显变化"的大小变化百分比
+    pub changed_threshold_percent: f64,
+
+    /// 单个项目最多删除的文件数，超出时跳过并警告（`force` 时不限制）
+    pub max_delete_files: Option<usize>,
+    /// 单个项目最多删除的字节数，超出时跳过并警告（`force` 时不限制）
+    pub max_delete_bytes: Option<u64>,
+
+    /// 只删除这些 profile 的输出目录（`target/<profile>` 与 `target/<triple>/<profile>`），
+    /// 仅对 DirectDelete 生效；`None` 时删除整个 target
+    pub profiles: Option<Vec<String>>,
+
+    /// 只删除这些目标三元组的输出目录（`target/<triple>`，与 `profiles` 同时指定时为
+    /// `target/<triple>/<profile>`），仅对 DirectDelete 生效；`None` 时不按三元组筛选
+    pub triples: Option<Vec<String>>,
+
+    /// 清理 workspace 时只清理这些成员包的产物（逐个执行 `cargo clean -p <包名>`），
+    /// 仅对 CargoClean 生效；`None` 时清理整个 target
+    pub packages: Option<Vec<String>>,
+
+    /// 删除失败（如文件被杀毒软件或 rust-analyzer 短暂占用）后最多重试的次数，重试间隔逐次递增
+    pub max_retries: usize,
+
+    /// 并行清理使用的线程数（`None` 时使用 rayon 默认值）
+    pub jobs: Option<usize>,
 }
 
 impl Default for CleanConfig {
@@ -100,23 +266,142 @@ impl Default for CleanConfig {
             // 可执行文件保留选项默认值
             keep_executable: false,
             executable_backup_dir: None,
+            executable_filter: ExecutableFilter::Any,
+
+            force: false,
+
+            on_changed: ChangedPolicy::Ignore,
+            changed_threshold_percent: 10.0,
+
+            max_delete_files: None,
+            max_delete_bytes: None,
+
+            profiles: None,
+            triples: None,
+            packages: None,
+
+            max_retries: 2,
+
+            jobs: None,
+        }
+    }
+}
+
+/// 项目的 bin 目标名：包名、`[[bin]]` 名称、`src/bin` 下自动发现的目标，workspace 成员同样如此
+fn binary_target_names(project: &RustProject) -> HashSet<String> {
+    let mut names: HashSet<String> = std::iter::once(project.name.clone())
+        .chain(project.members.iter().cloned())
+        .collect();
+
+    add_package_binary_names(&project.path, &mut names);
+    for member in project.member_dirs() {
+        if let Some(name) = RustProject::extract_project_name(&member.join("Cargo.toml")) {
+            names.insert(name);
+        }
+        add_package_binary_names(&member, &mut names);
+    }
+
+    names
+}
+
+/// 包目录中 `[[bin]]` 声明的目标和 `src/bin` 下自动发现的目标
+fn add_package_binary_names(package_dir: &Path, names: &mut HashSet<String>) {
+    let manifest = std::fs::read_to_string(package_dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok());
+    if let Some(bins) = manifest
+        .as_ref()
+        .and_then(|manifest| manifest.get("bin"))
+        .and_then(|bins| bins.as_array())
+    {
+        names.extend(
+            bins.iter()
+                .filter_map(|bin| bin.get("name")?.as_str())
+                .map(str::to_string),
+        );
+    }
+
+    if let Ok(entries) = std::fs::read_dir(package_dir.join("src").join("bin")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = if path.is_dir() {
+                path.join("main.rs").is_file().then(|| path.file_name())
+            } else {
+                (path.extension().is_some_and(|ext| ext == "rs")).then(|| path.file_stem())
+            };
+            if let Some(name) = name.flatten() {
+                names.insert(name.to_string_lossy().into_owned());
+            }
         }
     }
 }
 
+/// 是否为以 bin 目标命名的二进制（跳过动态库与 build 脚本产物）
+fn is_crate_binary(path: &Path, binary_names: &HashSet<String>) -> bool {
+    let is_shared_library = path
+        .extension()
+        .is_some_and(|ext| ext == "so" || ext == "dylib" || ext == "dll");
+    let is_build_script = path.file_name().is_some_and(|name| {
+        let name = name.to_string_lossy();
+        name.starts_with("build-script-") || name.starts_with("build_script_")
+    });
+    if is_shared_library || is_build_script {
+        return false;
+    }
+
+    path.file_stem()
+        .is_some_and(|stem| binary_names.contains(stem.to_string_lossy().as_ref()))
+}
+
+/// 修复模式下强制删除的重试次数
+const REPAIR_RETRIES: usize = 3;
+
+/// 删除失败后首次重试前的等待时间，之后每次递增
+const DELETE_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// cargo clean 运行期间采样 target 大小的最小间隔
+const CARGO_PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// 项目清理器
 pub struct ProjectCleaner {
     config: CleanConfig,
+    // 暂停标志：置位时在下一个取消检查点阻塞，直到复位或取消
+    pause_flag: Option<Arc<AtomicBool>>,
+    // 停止标志：置位后批量清理不再开始新的项目，正在清理的项目照常完成
+    stop_flag: Option<Arc<AtomicBool>>,
 }
 
 impl ProjectCleaner {
     /// 创建新的清理器
     pub fn new(config: CleanConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            pause_flag: None,
+            stop_flag: None,
+        }
+    }
+
+    /// 设置暂停标志（在文件/项目之间的检查点生效，运行中的 `cargo clean` 不会被暂停）
+    pub fn with_pause_flag(mut self, pause_flag: Arc<AtomicBool>) -> Self {
+        self.pause_flag = Some(pause_flag);
+        self
+    }
+
+    /// 设置停止标志（如 Ctrl+C），批量清理在项目之间检查，已开始的项目不会被中断
+    pub fn with_stop_flag(mut self, stop_flag: Arc<AtomicBool>) -> Self {
+        self.stop_flag = Some(stop_flag);
+        self
+    }
+
+    /// 是否已通过停止标志请求停止
+    pub fn stop_requested(&self) -> bool {
+        self.stop_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
     }
 
     /// 清理单个项目
-    pub fn clean_project(&self, project: &RustProject) -> Result<u64> {
+    pub fn clean_project(&self, project: &RustProject) -> PurgerResult<u64> {
         self.clean_project_with_progress(project, |_| {})
     }
 
@@ -125,7 +410,7 @@ impl ProjectCleaner {
         &self,
         project: &RustProject,
         progress_callback: F,
-    ) -> Result<u64>
+    ) -> PurgerResult<u64>
     where
         F: Fn(CleanProgress),
     {
@@ -137,14 +422,35 @@ impl ProjectCleaner {
         project: &RustProject,
         cancel_flag: Option<&AtomicBool>,
         progress_callback: F,
-    ) -> Result<u64>
+    ) -> PurgerResult<u64>
     where
         F: Fn(CleanProgress),
     {
+        // 每个项目的日志都带上结构化的 project/path 字段，便于按项目过滤或输出为 JSON
+        let span = info_span!(
+            "clean",
+            project = %project.name,
+            path = %project.path.display()
+        );
+        let _enter = span.enter();
+
+        let started = Instant::now();
+        let progress_callback = |mut progress: CleanProgress| {
+            progress.elapsed_ms = started.elapsed().as_millis() as u64;
+            progress_callback(progress);
+        };
         match self.clean_project_with_progress_impl(project, cancel_flag, &progress_callback) {
-            Ok(bytes) => Ok(bytes),
+            Ok(bytes) => {
+                info!(
+                    bytes_freed = bytes,
+                    dry_run = self.config.dry_run,
+                    "项目清理完成"
+                );
+                Ok(bytes)
+            }
             Err(err) => {
-                if !err.is::<CleanCancelled>() {
+                let err = PurgerError::clean_failed(&project.name, err);
+                if !matches!(err, PurgerError::Cancelled) {
                     error!("清理项目失败 {}: {}", project.name, err);
                 }
                 Err(err)
@@ -162,12 +468,28 @@ impl ProjectCleaner {
         F: Fn(CleanProgress),
     {
         self.check_cancel(cancel_flag)?;
+        if project.pinned && !self.config.force {
+            warn!("项目 {} 带有 .purger-keep 标记，跳过", project.name);
+            anyhow::bail!(ProjectPinned {
+                path: project.path.clone()
+            });
+        }
+        crate::safety::ensure_not_dangerous(&project.target_path(), self.config.force)?;
+        self.check_delete_limits(project)?;
+        if !self.config.force
+            && let Some(lock) = build_in_progress(&project.target_path())
+        {
+            warn!("项目 {} 正在构建，跳过: {:?}", project.name, lock);
+            anyhow::bail!(BuildInProgress { lock });
+        }
 
         if self.config.dry_run {
-            let size = if project.has_target {
-                project.get_target_size()
-            } else {
+            let size = if !project.has_target {
                 0
+            } else if let Some(dirs) = self.profile_dirs_to_delete(&project.target_path()) {
+                dirs.iter().map(|dir| target_stats(dir).1).sum()
+            } else {
+                project.get_target_size()
             };
             info!(
                 "DRY RUN: 将清理项目 {} ({})",
@@ -177,7 +499,10 @@ impl ProjectCleaner {
             return Ok(size);
         }
 
-        if !project.has_target && self.config.strategy == CleanStrategy::DirectDelete {
+        let refreshed = self.refresh_if_changed(project)?;
+        let project = refreshed.as_ref().unwrap_or(project);
+
+        if !project.has_target && self.config.strategy != CleanStrategy::CargoClean {
             debug!("项目 {} 没有target目录，跳过", project.name);
             return Ok(0);
         }
@@ -194,16 +519,31 @@ impl ProjectCleaner {
             files_processed: 0,
             total_files: None,
             phase: CleanPhase::Starting,
+            elapsed_ms: 0,
         });
 
-        let bytes_freed = match self.config.strategy {
+        let mut bytes_freed = match self.config.strategy {
             CleanStrategy::CargoClean => {
                 self.clean_with_cargo_progress(project, cancel_flag, progress_callback)?
             }
             CleanStrategy::DirectDelete => {
                 self.clean_with_delete_progress(project, cancel_flag, progress_callback)?
             }
+            CleanStrategy::Trash => {
+                self.clean_with_trash_progress(project, cancel_flag, progress_callback)?
+            }
         };
+        if self
+            .profile_dirs_to_delete(&project.target_path())
+            .is_none()
+            && self.selected_packages(project).is_none()
+        {
+            bytes_freed = bytes_freed.saturating_add(self.clean_extra_targets(
+                project,
+                cancel_flag,
+                progress_callback,
+            )?);
+        }
 
         progress_callback(CleanProgress {
             project_name: project.name.clone(),
@@ -211,9 +551,9 @@ impl ProjectCleaner {
             files_processed: 0,
             total_files: None,
             phase: CleanPhase::Complete,
+            elapsed_ms: 0,
         });
 
-        info!("成功清理项目: {}", project.name);
         Ok(bytes_freed)
     }
 
@@ -224,14 +564,22 @@ impl ProjectCleaner {
 
         info!("开始清理 {} 个项目", projects.len());
 
-        if self.config.parallel {
-            self.clean_projects_parallel(projects, &mut result);
-        } else {
-            self.clean_projects_sequential(projects, &mut result);
-        }
+        crate::with_thread_pool(self.config.jobs, || {
+            if self.config.parallel {
+                self.clean_projects_parallel(projects, &mut result);
+            } else {
+                self.clean_projects_sequential(projects, &mut result);
+            }
+        });
 
         result.duration_ms = start_time.elapsed().as_millis() as u64;
 
+        if result.outcomes.len() < projects.len() {
+            warn!(
+                "清理被中止，{} 个项目未处理",
+                projects.len() - result.outcomes.len()
+            );
+        }
         info!(
             "清理完成: 成功 {} 个，失败 {} 个，释放空间 {}，耗时 {}ms",
             result.cleaned_projects,
@@ -243,68 +591,197 @@ impl ProjectCleaner {
         result
     }
 
+    /// 逐个清理项目，并通过 `on_event` 同时汇报整体进度和单个项目的进度
+    ///
+    /// `cancel_flag` 被置位后停止处理后续项目（正在清理的项目也会尽快中止），返回已处理项目的结果。
+    pub fn clean_projects_with_progress<F>(
+        &self,
+        projects: &[RustProject],
+        cancel_flag: Option<&AtomicBool>,
+        on_event: F,
+    ) -> CleanResult
+    where
+        F: Fn(BatchCleanEvent<'_>),
+    {
+        let start_time = Instant::now();
+        let mut result = CleanResult::new();
+        let total = projects.len();
+
+        for (index, project) in projects.iter().enumerate() {
+            // 同时处理暂停：暂停期间阻塞在这里
+            if self.check_cancel(cancel_flag).is_err() || self.stop_requested() {
+                break;
+            }
+
+            on_event(BatchCleanEvent::ProjectStarted {
+                index,
+                total,
+                project,
+            });
+            let project_start = Instant::now();
+            let outcome =
+                self.clean_project_with_progress_and_cancel(project, cancel_flag, |progress| {
+                    on_event(BatchCleanEvent::Progress(progress))
+                });
+            let (bytes_freed, error) = match outcome {
+                Ok(bytes_freed) => {
+                    on_event(BatchCleanEvent::ProjectCompleted {
+                        index,
+                        total,
+                        project,
+                        bytes_freed,
+                        total_freed: result.total_size_freed + bytes_freed,
+                    });
+                    (bytes_freed, None)
+                }
+                Err(PurgerError::Cancelled) => break,
+                Err(err) => {
+                    on_event(BatchCleanEvent::ProjectFailed {
+                        index,
+                        total,
+                        project,
+                        error: &err,
+                        total_freed: result.total_size_freed,
+                    });
+                    (0, Some(err.to_string()))
+                }
+            };
+            result.add_outcome(ProjectCleanOutcome {
+                name: project.name.clone(),
+                path: project.path.clone(),
+                bytes_freed,
+                duration_ms: project_start.elapsed().as_millis() as u64,
+                error,
+            });
+        }
+
+        result.duration_ms = start_time.elapsed().as_millis() as u64;
+        result
+    }
+
     /// 串行清理项目
     fn clean_projects_sequential(&self, projects: &[RustProject], result: &mut CleanResult) {
         for project in projects {
-            match self.clean_project(project) {
-                Ok(size_freed) => result.add_success(size_freed),
-                Err(err) => result.add_failure_detail(CleanFailure {
-                    project_name: project.name.clone(),
-                    project_path: project.path.clone(),
-                    error: err.to_string(),
-                }),
+            if self.stop_requested() {
+                break;
             }
+            result.add_outcome(self.clean_project_outcome(project));
         }
     }
 
-    /// 并行清理项目（注意：这里简化实现，实际可能需要更复杂的并行控制）
+    /// 并行清理项目（各项目的失败互不影响，汇总结果与串行一致；停止后未开始的项目不计入结果）
     fn clean_projects_parallel(&self, projects: &[RustProject], result: &mut CleanResult) {
-        let (successes, total_freed, failures): (usize, u64, Vec<CleanFailure>) = projects
+        let outcomes: Vec<ProjectCleanOutcome> = projects
             .par_iter()
-            .map(|project| match self.clean_project(project) {
-                Ok(size_freed) => Ok(size_freed),
-                Err(err) => Err(CleanFailure {
-                    project_name: project.name.clone(),
-                    project_path: project.path.clone(),
-                    error: err.to_string(),
-                }),
-            })
-            .fold(
-                || (0usize, 0u64, Vec::new()),
-                |mut acc, item| {
-                    match item {
-                        Ok(size_freed) => {
-                            acc.0 += 1;
-                            acc.1 += size_freed;
-                        }
-                        Err(failure) => {
-                            acc.2.push(failure);
-                        }
-                    }
-                    acc
-                },
-            )
-            .reduce(
-                || (0usize, 0u64, Vec::new()),
-                |mut a, b| {
-                    a.0 += b.0;
-                    a.1 += b.1;
-                    a.2.extend(b.2);
-                    a
-                },
-            );
+            .filter(|_| !self.stop_requested())
+            .map(|project| self.clean_project_outcome(project))
+            .collect();
+
+        for outcome in outcomes {
+            result.add_outcome(outcome);
+        }
+    }
+
+    /// 清理单个项目并记录耗时与结果
+    fn clean_project_outcome(&self, project: &RustProject) -> ProjectCleanOutcome {
+        let start_time = Instant::now();
+        let (bytes_freed, error) = match self.clean_project(project) {
+            Ok(size_freed) => (size_freed, None),
+            Err(err) => (0, Some(err.to_string())),
+        };
+        ProjectCleanOutcome {
+            name: project.name.clone(),
+            path: project.path.clone(),
+            bytes_freed,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+            error,
+        }
+    }
+
+    /// 检查 target 是否超出单次删除上限（安全阀，防止误选了巨大的目录）
+    fn check_delete_limits(&self, project: &RustProject) -> Result<()> {
+        if self.config.force || !project.has_target {
+            return Ok(());
+        }
+
+        let mut reason = None;
+        if let Some(max_bytes) = self.config.max_delete_bytes {
+            let size = project.get_target_size();
+            if size > max_bytes {
+                reason = Some(format!(
+                    "target is {} (limit {})",
+                    crate::format_bytes(size),
+                    crate::format_bytes(max_bytes)
+                ));
+            }
+        }
+        if reason.is_none()
+            && let Some(max_files) = self.config.max_delete_files
+        {
+            let (files, _) = target_stats(&project.target_path());
+            if files > max_files {
+                reason = Some(format!("target has {files} files (limit {max_files})"));
+            }
+        }
 
-        result.cleaned_projects += successes;
-        result.total_size_freed += total_freed;
-        for failure in failures {
-            result.add_failure_detail(failure);
+        if let Some(reason) = reason {
+            warn!("超出删除上限，跳过项目 {}: {}", project.name, reason);
+            anyhow::bail!(DeleteLimitExceeded {
+                path: project.target_path(),
+                reason,
+            });
         }
+        Ok(())
     }
 
-    /// 使用cargo clean清理
-    #[allow(dead_code)]
-    fn clean_with_cargo(&self, project: &RustProject) -> Result<u64> {
-        self.clean_with_cargo_progress(project, None, &|_| {})
+    /// 清理前重新统计 target 的大小和修改时间（缩小扫描与清理之间的时间差）
+    ///
+    /// 返回刷新后的项目；变化超过阈值时按 [`ChangedPolicy`] 警告或返回 [`TargetChanged`]。
+    fn refresh_if_changed(&self, project: &RustProject) -> Result<Option<RustProject>> {
+        // 大小未知（延迟计算）时无从比较
+        if self.config.on_changed == ChangedPolicy::Ignore
+            || !project.has_target
+            || project.target_size == 0
+        {
+            return Ok(None);
+        }
+
+        let target_path = project.target_path();
+        let after = std::iter::once(&target_path)
+            .chain(&project.extra_targets)
+            .map(|dir| target_stats(dir).1)
+            .sum();
+        let before = project.target_size;
+
+        let mut refreshed = project.clone();
+        refreshed.target_size = after;
+        refreshed.has_target =
+            target_path.exists() || project.extra_targets.iter().any(|dir| dir.exists());
+        if let Ok(modified) = std::fs::metadata(&target_path).and_then(|m| m.modified()) {
+            refreshed.last_modified = modified;
+        }
+
+        let change_percent = before.abs_diff(after) as f64 / before as f64 * 100.0;
+        if change_percent > self.config.changed_threshold_percent {
+            match self.config.on_changed {
+                ChangedPolicy::Ignore => {}
+                ChangedPolicy::Warn => warn!(
+                    "项目 {} 的 target 自扫描后发生变化: {} -> {}",
+                    project.name,
+                    crate::format_bytes(before),
+                    crate::format_bytes(after)
+                ),
+                ChangedPolicy::Skip | ChangedPolicy::Confirm => {
+                    anyhow::bail!(TargetChanged {
+                        path: project.path.clone(),
+                        before,
+                        after,
+                    })
+                }
+            }
+        }
+
+        Ok(Some(refreshed))
     }
 
     /// 使用cargo clean清理（带进度回调）
@@ -327,73 +804,123 @@ impl ProjectCleaner {
             files_processed: 0,
             total_files: None,
             phase: CleanPhase::Analyzing,
+            elapsed_ms: 0,
         });
 
         let target_path = project.target_path();
-        let size_before = if target_path.exists() {
-            project.get_target_size()
-        } else {
-            0
-        };
+        // 记录清理前 target 中的文件清单，运行期间统计其中已被 cargo 删除的文件作为真实进度；
+        // 结束后与剩余大小相减得到实际释放的空间
+        let files = target_files(&target_path);
+        let files_before = files.len();
+        let bytes_before: u64 = files.iter().map(|(_, len)| len).sum();
 
         progress_callback(CleanProgress {
             project_name: project.name.clone(),
             current_file: Some("cargo clean".to_string()),
             files_processed: 0,
-            total_files: None,
+            total_files: Some(files_before),
             phase: CleanPhase::Cleaning,
+            elapsed_ms: 0,
         });
 
-        let mut cmd = Command::new("cargo");
-        cmd.arg("clean")
-            .current_dir(&project.path)
-            .stdin(Stdio::null())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+        let last_poll = std::cell::Cell::new(Instant::now());
+        for args in self.cargo_clean_invocations(project) {
+            self.check_cancel(cancel_flag)?;
+            let mut cmd = Command::new("cargo");
+            cmd.args(&args)
+                .current_dir(&project.path)
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped());
+
+            let output = self.run_command_with_timeout_and_cancel(
+                cmd,
+                self.timeout(),
+                cancel_flag,
+                |_elapsed| {
+                    // 统计目录大小需要遍历，限制采样频率（慢速/网络磁盘上尤其重要）
+                    if last_poll.get().elapsed() < CARGO_PROGRESS_POLL_INTERVAL {
+                        return;
+                    }
+                    last_poll.set(Instant::now());
 
-        let output = self.run_command_with_timeout_and_cancel(
-            cmd,
-            self.timeout(),
-            cancel_flag,
-            |elapsed| {
-                let ticks = (elapsed.as_millis() / 250) as usize;
-                progress_callback(CleanProgress {
-                    project_name: project.name.clone(),
-                    current_file: Some(format!("cargo clean ({:?})", elapsed)),
-                    files_processed: ticks,
-                    total_files: None,
-                    phase: CleanPhase::Cleaning,
-                });
-            },
-        )?;
+                    let (files_removed, bytes_freed) = removed_stats(&files);
+                    progress_callback(CleanProgress {
+                        project_name: project.name.clone(),
+                        current_file: Some(format!(
+                            "cargo {} ({} freed)",
+                            args.join(" "),
+                            crate::format_bytes(bytes_freed)
+                        )),
+                        files_processed: files_removed,
+                        total_files: Some(files_before),
+                        phase: CleanPhase::Cleaning,
+                        elapsed_ms: 0,
+                    });
+                },
+            )?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("cargo clean失败: {}", stderr.trim());
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                anyhow::bail!("cargo {}失败: {}", args.join(" "), stderr.trim());
+            }
         }
 
         // 最终进度更新
+        let (files_removed, _) = removed_stats(&files);
         progress_callback(CleanProgress {
             project_name: project.name.clone(),
             current_file: None,
-            files_processed: 0,
-            total_files: None,
+            files_processed: files_removed,
+            total_files: Some(files_before),
             phase: CleanPhase::Finalizing,
+            elapsed_ms: 0,
         });
 
-        let size_after = if target_path.exists() {
-            project.get_target_size()
-        } else {
-            0
+        let (_, bytes_after) = target_stats(&target_path);
+        Ok(bytes_before.saturating_sub(bytes_after))
+    }
+
+    /// 要执行的 cargo 参数列表：默认一次完整的 `cargo clean`；配置了 `packages` 时，
+    /// workspace 根对其中属于该 workspace 的每个包执行一次 `cargo clean -p`，单独的包不在列表中时不清理
+    fn cargo_clean_invocations(&self, project: &RustProject) -> Vec<Vec<String>> {
+        let Some(packages) = self.selected_packages(project) else {
+            return vec![vec!["clean".to_string()]];
         };
+        packages
+            .into_iter()
+            .map(|package| vec!["clean".to_string(), "-p".to_string(), package])
+            .collect()
+    }
 
-        Ok(size_before.saturating_sub(size_after))
+    /// 配置了 `packages` 时项目中是否有要清理的包；没有时清理该项目什么也不会做，调用方应跳过它
+    pub fn selects_project(&self, project: &RustProject) -> bool {
+        self.selected_packages(project)
+            .is_none_or(|packages| !packages.is_empty())
     }
 
-    /// 直接删除target目录
-    #[allow(dead_code)]
-    fn clean_with_delete(&self, project: &RustProject) -> Result<u64> {
-        self.clean_with_delete_progress(project, None, &|_| {})
+    /// 要单独清理的包，`None` 表示清理整个 target
+    fn selected_packages(&self, project: &RustProject) -> Option<Vec<String>> {
+        let packages = self.config.packages.as_ref()?;
+        if self.config.strategy != CleanStrategy::CargoClean {
+            return None;
+        }
+        if !project.is_workspace_root {
+            // 单独的包：在列表中时完整清理，否则不清理
+            return (!packages.contains(&project.name)).then(Vec::new);
+        }
+
+        let mut selected = Vec::new();
+        for package in packages {
+            let is_member = project.members.contains(package)
+                || (project.is_package && project.name == *package);
+            if is_member {
+                selected.push(package.clone());
+            } else {
+                debug!("包 {} 不属于 workspace {}，跳过", package, project.name);
+            }
+        }
+        Some(selected)
     }
 
     /// 直接删除target目录（带进度回调）
@@ -422,6 +949,7 @@ impl ProjectCleaner {
             files_processed: 0,
             total_files: None,
             phase: CleanPhase::Analyzing,
+            elapsed_ms: 0,
         });
 
         // 如果需要保留可执行文件，先备份
@@ -435,32 +963,40 @@ impl ProjectCleaner {
             files_processed: 0,
             total_files: None,
             phase: CleanPhase::Cleaning,
+            elapsed_ms: 0,
         });
 
         let timeout = self.timeout();
-        let bytes_freed = match self.config.direct_delete_backend {
-            DirectDeleteBackend::Native => {
-                if cancel_flag.is_some() || self.config.keep_executable {
-                    self.delete_directory_tree_with_progress(
-                        project,
-                        &target_path,
-                        cancel_flag,
-                        timeout,
-                        progress_callback,
-                    )?
-                } else {
-                    let size_before = project.get_target_size();
-                    std::fs::remove_dir_all(&target_path).context("删除target目录失败")?;
-                    size_before
+        let bytes_freed = if let Some(dirs) = self.profile_dirs_to_delete(&target_path) {
+            self.clean_profile_dirs(project, &target_path, &dirs, cancel_flag, progress_callback)?
+        } else {
+            match self.config.direct_delete_backend {
+                DirectDeleteBackend::Native => {
+                    // remove_dir_all 无法中途停止，需要取消或超时时逐个文件删除
+                    if cancel_flag.is_some() || timeout.is_some() || self.config.keep_executable {
+                        self.delete_directory_tree_with_progress(
+                            project,
+                            &target_path,
+                            cancel_flag,
+                            timeout,
+                            progress_callback,
+                        )?
+                    } else {
+                        // 删除前重新统计，扫描后 target 可能已变化
+                        let (_, size_before) = target_stats(&target_path);
+                        self.remove_dir_all_with_retry(&target_path)
+                            .context("删除target目录失败")?;
+                        size_before
+                    }
                 }
+                DirectDeleteBackend::CmdRmdir => self.clean_with_windows_rmdir(
+                    project,
+                    &target_path,
+                    cancel_flag,
+                    timeout,
+                    progress_callback,
+                )?,
             }
-            DirectDeleteBackend::CmdRmdir => self.clean_with_windows_rmdir(
-                project,
-                &target_path,
-                cancel_flag,
-                timeout,
-                progress_callback,
-            )?,
         };
 
         progress_callback(CleanProgress {
@@ -469,37 +1005,218 @@ impl ProjectCleaner {
             files_processed: 0,
             total_files: None,
             phase: CleanPhase::Finalizing,
+            elapsed_ms: 0,
         });
 
+        if self.config.profiles.is_none() && self.config.triples.is_none() && target_path.exists() {
+            warn!("target目录删除后仍然存在: {:?}", target_path);
+        }
+
         Ok(bytes_freed)
     }
 
-    fn clean_with_windows_rmdir<F>(
+    /// DirectDelete 且指定了 profiles 或 triples 时，需要删除的输出目录
+    fn profile_dirs_to_delete(&self, target_path: &Path) -> Option<Vec<PathBuf>> {
+        if self.config.strategy != CleanStrategy::DirectDelete {
+            return None;
+        }
+        match (&self.config.profiles, &self.config.triples) {
+            (profiles, Some(triples)) => {
+                Some(triple_dirs(target_path, triples, profiles.as_deref()))
+            }
+            (Some(profiles), None) => Some(profile_dirs(target_path, profiles)),
+            (None, None) => None,
+        }
+    }
+
+    /// 删除项目的额外构建输出目录（`RustProject::extra_targets`）；Trash 策略移到回收站，其余策略直接删除
+    ///
+    /// 没有 cargo 标记文件的目录不删除。
+    fn clean_extra_targets<F>(
         &self,
         project: &RustProject,
-        target_path: &std::path::Path,
         cancel_flag: Option<&AtomicBool>,
-        timeout: Option<Duration>,
         progress_callback: &F,
     ) -> Result<u64>
     where
         F: Fn(CleanProgress),
     {
-        #[cfg(windows)]
-        {
+        let mut bytes_freed = 0u64;
+        for dir in &project.extra_targets {
+            if !dir.exists() {
+                continue;
+            }
+            if !is_cargo_target_dir(dir) {
+                warn!("{:?} 不是 cargo 生成的目录，跳过", dir);
+                continue;
+            }
             self.check_cancel(cancel_flag)?;
-            self.validate_safe_target_directory(project, target_path)?;
+            crate::safety::ensure_not_dangerous(dir, self.config.force)?;
+            self.validate_safe_target_directory(project, dir)?;
 
-            let size_before = project.get_target_size();
-            let target_str = target_path.display().to_string();
-            if target_str.contains('"') {
-                return self.delete_directory_tree_with_progress(
-                    project,
-                    target_path,
-                    cancel_flag,
-                    timeout,
-                    progress_callback,
-                );
+            debug!("删除额外的构建输出目录: {:?}", dir);
+            progress_callback(CleanProgress {
+                project_name: project.name.clone(),
+                current_file: Some(
+                    dir.strip_prefix(&project.path)
+                        .unwrap_or(dir)
+                        .display()
+                        .to_string(),
+                ),
+                files_processed: 0,
+                total_files: None,
+                phase: CleanPhase::Cleaning,
+                elapsed_ms: 0,
+            });
+
+            let (_, size_before) = target_stats(dir);
+            if self.config.strategy == CleanStrategy::Trash {
+                trash::delete(dir)
+                    .with_context(|| format!("移动构建输出目录到回收站失败: {dir:?}"))?;
+            } else {
+                self.remove_dir_all_with_retry(dir)
+                    .with_context(|| format!("删除构建输出目录失败: {dir:?}"))?;
+            }
+            bytes_freed = bytes_freed.saturating_add(size_before);
+        }
+        Ok(bytes_freed)
+    }
+
+    /// 逐个删除 profile 目录，保留 target 中的其他内容
+    fn clean_profile_dirs<F>(
+        &self,
+        project: &RustProject,
+        target_path: &Path,
+        dirs: &[PathBuf],
+        cancel_flag: Option<&AtomicBool>,
+        progress_callback: &F,
+    ) -> Result<u64>
+    where
+        F: Fn(CleanProgress),
+    {
+        let timeout = self.timeout();
+        let mut bytes_freed = 0u64;
+        for dir in dirs {
+            self.check_cancel(cancel_flag)?;
+            debug!("删除 profile 目录: {:?}", dir);
+            progress_callback(CleanProgress {
+                project_name: project.name.clone(),
+                current_file: Some(
+                    dir.strip_prefix(target_path)
+                        .unwrap_or(dir)
+                        .display()
+                        .to_string(),
+                ),
+                files_processed: 0,
+                total_files: None,
+                phase: CleanPhase::Cleaning,
+                elapsed_ms: 0,
+            });
+
+            let freed = if cancel_flag.is_some() || timeout.is_some() {
+                self.delete_directory_tree_with_progress(
+                    project,
+                    dir,
+                    cancel_flag,
+                    timeout,
+                    progress_callback,
+                )?
+            } else {
+                let (_, size_before) = target_stats(dir);
+                self.remove_dir_all_with_retry(dir)
+                    .with_context(|| format!("删除 profile 目录失败: {dir:?}"))?;
+                size_before
+            };
+            bytes_freed = bytes_freed.saturating_add(freed);
+        }
+        Ok(bytes_freed)
+    }
+
+    /// 将target目录移到系统回收站（带进度回调）
+    fn clean_with_trash_progress<F>(
+        &self,
+        project: &RustProject,
+        cancel_flag: Option<&AtomicBool>,
+        progress_callback: &F,
+    ) -> Result<u64>
+    where
+        F: Fn(CleanProgress),
+    {
+        debug!("移动target目录到回收站: {}", project.name);
+
+        let target_path = project.target_path();
+        if !target_path.exists() {
+            return Ok(0);
+        }
+
+        self.check_cancel(cancel_flag)?;
+        self.validate_safe_target_directory(project, &target_path)?;
+
+        progress_callback(CleanProgress {
+            project_name: project.name.clone(),
+            current_file: None,
+            files_processed: 0,
+            total_files: None,
+            phase: CleanPhase::Analyzing,
+            elapsed_ms: 0,
+        });
+
+        if self.config.keep_executable {
+            self.backup_executables(project, cancel_flag, progress_callback)?;
+        }
+        let (files, size_before) = target_stats(&target_path);
+
+        self.check_cancel(cancel_flag)?;
+        progress_callback(CleanProgress {
+            project_name: project.name.clone(),
+            current_file: Some("target".to_string()),
+            files_processed: 0,
+            total_files: Some(files),
+            phase: CleanPhase::Cleaning,
+            elapsed_ms: 0,
+        });
+
+        trash::delete(&target_path)
+            .with_context(|| format!("移动target目录到回收站失败: {target_path:?}"))?;
+
+        progress_callback(CleanProgress {
+            project_name: project.name.clone(),
+            current_file: None,
+            files_processed: files,
+            total_files: Some(files),
+            phase: CleanPhase::Finalizing,
+            elapsed_ms: 0,
+        });
+
+        Ok(size_before)
+    }
+
+    fn clean_with_windows_rmdir<F>(
+        &self,
+        project: &RustProject,
+        target_path: &std::path::Path,
+        cancel_flag: Option<&AtomicBool>,
+        timeout: Option<Duration>,
+        progress_callback: &F,
+    ) -> Result<u64>
+    where
+        F: Fn(CleanProgress),
+    {
+        #[cfg(windows)]
+        {
+            self.check_cancel(cancel_flag)?;
+            self.validate_safe_target_directory(project, target_path)?;
+
+            let (_, size_before) = target_stats(target_path);
+            let target_str = target_path.display().to_string();
+            if target_str.contains('"') {
+                return self.delete_directory_tree_with_progress(
+                    project,
+                    target_path,
+                    cancel_flag,
+                    timeout,
+                    progress_callback,
+                );
             }
 
             let mut cmd = Command::new("cmd");
@@ -514,6 +1231,7 @@ impl ProjectCleaner {
                         files_processed: 0,
                         total_files: None,
                         phase: CleanPhase::Cleaning,
+                        elapsed_ms: 0,
                     });
                 })?;
 
@@ -550,7 +1268,7 @@ impl ProjectCleaner {
                     progress_callback,
                 )
             } else {
-                let size_before = project.get_target_size();
+                let (_, size_before) = target_stats(target_path);
                 std::fs::remove_dir_all(target_path).context("删除target目录失败")?;
                 Ok(size_before)
             }
@@ -568,7 +1286,7 @@ impl ProjectCleaner {
         F: Fn(CleanProgress),
     {
         let target_path = project.target_path();
-        let executables = self.find_executables(&target_path)?;
+        let executables = self.find_executables(project)?;
 
         if executables.is_empty() {
             debug!("项目 {} 没有找到可执行文件", project.name);
@@ -585,13 +1303,24 @@ impl ProjectCleaner {
         let backup_dir = self.get_backup_directory(project)?;
         std::fs::create_dir_all(&backup_dir).context("创建备份目录失败")?;
 
-        // 备份每个可执行文件
+        // 按 target 下的相对路径备份（debug/foo 与 release/foo 不会互相覆盖），
+        // 并记录原始位置以便恢复
+        let mut entries = read_backup_manifest(&backup_dir);
         for (i, exe_path) in executables.iter().enumerate() {
             self.check_cancel(cancel_flag)?;
             let file_name = exe_path
                 .file_name()
                 .ok_or_else(|| anyhow::anyhow!("无效的可执行文件路径"))?;
-            let backup_path = backup_dir.join(file_name);
+            let original = exe_path
+                .strip_prefix(&target_path)
+                .unwrap_or(Path::new(file_name))
+                .to_path_buf();
+            let backup_path = backup_dir.join(&original);
+            entries.retain(|entry| entry.backup != original);
+            entries.push(BackupEntry {
+                backup: original.clone(),
+                original,
+            });
 
             progress_callback(CleanProgress {
                 project_name: project.name.clone(),
@@ -599,14 +1328,18 @@ impl ProjectCleaner {
                 files_processed: i,
                 total_files: Some(executables.len()),
                 phase: CleanPhase::Cleaning,
+                elapsed_ms: 0,
             });
 
-            std::fs::copy(exe_path, &backup_path)
+            copy_preserving_permissions(exe_path, &backup_path)
                 .with_context(|| format!("备份可执行文件失败: {exe_path:?} -> {backup_path:?}"))?;
 
             debug!("备份可执行文件: {:?} -> {:?}", exe_path, backup_path);
         }
 
+        let manifest = serde_json::to_string_pretty(&entries)?;
+        std::fs::write(backup_dir.join(BACKUP_MANIFEST), manifest).context("写入备份清单失败")?;
+
         info!(
             "成功备份 {} 个可执行文件到 {:?}",
             executables.len(),
@@ -615,6 +1348,59 @@ impl ProjectCleaner {
         Ok(())
     }
 
+    /// 将备份的可执行文件复制回 target 中的原始位置，返回恢复的文件数
+    pub fn restore_executables(&self, project: &RustProject) -> PurgerResult<usize> {
+        Ok(self.restore_executables_impl(project)?)
+    }
+
+    fn restore_executables_impl(&self, project: &RustProject) -> Result<usize> {
+        let backup_dir = self.get_backup_directory(project)?;
+        if !backup_dir.is_dir() {
+            anyhow::bail!("没有找到项目 {} 的备份: {:?}", project.name, backup_dir);
+        }
+
+        let mut entries = read_backup_manifest(&backup_dir);
+        // 清单可能被改动过：只接受普通的相对路径，避免写到 target 之外
+        if let Some(entry) = entries
+            .iter()
+            .find(|entry| !is_plain_relative(&entry.backup) || !is_plain_relative(&entry.original))
+        {
+            anyhow::bail!(
+                "备份清单中的路径不安全: {:?} -> {:?}",
+                entry.backup,
+                entry.original
+            );
+        }
+        if entries.is_empty() {
+            // 没有清单的旧备份：默认放回 release 目录
+            entries = std::fs::read_dir(&backup_dir)
+                .context("读取备份目录失败")?
+                .flatten()
+                .filter(|entry| entry.path().is_file() && entry.file_name() != BACKUP_MANIFEST)
+                .map(|entry| BackupEntry {
+                    backup: PathBuf::from(entry.file_name()),
+                    original: Path::new("release").join(entry.file_name()),
+                })
+                .collect();
+        }
+
+        let target_path = project.target_path();
+        for entry in &entries {
+            let source = backup_dir.join(&entry.backup);
+            let destination = target_path.join(&entry.original);
+            copy_preserving_permissions(&source, &destination)
+                .with_context(|| format!("恢复可执行文件失败: {source:?} -> {destination:?}"))?;
+            debug!("恢复可执行文件: {:?} -> {:?}", source, destination);
+        }
+
+        info!(
+            "成功恢复 {} 个可执行文件到项目 {}",
+            entries.len(),
+            project.name
+        );
+        Ok(entries.len())
+    }
+
     fn timeout(&self) -> Option<Duration> {
         if self.config.timeout_seconds == 0 {
             return None;
@@ -623,6 +1409,9 @@ impl ProjectCleaner {
     }
 
     fn check_cancel(&self, cancel_flag: Option<&AtomicBool>) -> Result<()> {
+        if let Some(pause_flag) = &self.pause_flag {
+            wait_while_paused(pause_flag, cancel_flag);
+        }
         if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
             anyhow::bail!(CleanCancelled);
         }
@@ -642,6 +1431,18 @@ impl ProjectCleaner {
             });
         }
 
+        // 通过 CARGO_TARGET_DIR/.cargo/config 重定向的 target 可以在项目之外，
+        // 但必须带有 cargo 生成的标记文件，避免误删配置错误指向的普通目录
+        if project.target_dir.as_deref() == Some(target_path) {
+            if is_cargo_target_dir(target_path) {
+                return Ok(());
+            }
+            anyhow::bail!(UnsafeTargetDirectory {
+                path: target_path.to_path_buf(),
+                reason: "relocated target does not look like a cargo target directory".to_string(),
+            });
+        }
+
         let canonical_target = target_path.canonicalize().ok();
         let canonical_project = project.path.canonicalize().ok();
 
@@ -674,26 +1475,23 @@ impl ProjectCleaner {
         F: Fn(CleanProgress),
     {
         let start = Instant::now();
-        let mut last_report = Instant::now();
+        // 第一批文件删除后立即汇报，之后按间隔节流
+        let mut last_report: Option<Instant> = None;
         let mut processed = 0usize;
         let mut directories: Vec<PathBuf> = Vec::new();
 
-        let mut bytes_freed = if project.target_size > 0 {
-            project.target_size
-        } else {
-            0
-        };
-        let track_bytes = project.target_size == 0;
+        // 按实际删除的文件累计，而不是使用扫描时的 target_size
+        let mut bytes_freed = 0u64;
 
         if self.config.parallel && cancel_flag.is_some() {
             let mut files: Vec<PathBuf> = Vec::new();
 
             for entry in WalkDir::new(target_path).follow_links(false).min_depth(1) {
                 self.check_cancel(cancel_flag)?;
-                if let Some(timeout) = timeout {
-                    if start.elapsed() > timeout {
-                        anyhow::bail!(CleanTimedOut { timeout });
-                    }
+                if let Some(timeout) = timeout
+                    && start.elapsed() > timeout
+                {
+                    anyhow::bail!(CleanTimedOut { timeout });
                 }
 
                 let entry = entry.context("遍历 target 目录失败")?;
@@ -710,10 +1508,10 @@ impl ProjectCleaner {
             let chunk_size = 1024usize;
             for chunk in files.chunks(chunk_size) {
                 self.check_cancel(cancel_flag)?;
-                if let Some(timeout) = timeout {
-                    if start.elapsed() > timeout {
-                        anyhow::bail!(CleanTimedOut { timeout });
-                    }
+                if let Some(timeout) = timeout
+                    && start.elapsed() > timeout
+                {
+                    anyhow::bail!(CleanTimedOut { timeout });
                 }
 
                 let bytes_in_chunk: u64 = chunk
@@ -722,18 +1520,13 @@ impl ProjectCleaner {
                         if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
                             anyhow::bail!(CleanCancelled);
                         }
-                        if let Some(timeout) = timeout {
-                            if start.elapsed() > timeout {
-                                anyhow::bail!(CleanTimedOut { timeout });
-                            }
+                        if let Some(timeout) = timeout
+                            && start.elapsed() > timeout
+                        {
+                            anyhow::bail!(CleanTimedOut { timeout });
                         }
 
-                        let mut bytes = 0u64;
-                        if track_bytes {
-                            if let Ok(metadata) = std::fs::symlink_metadata(path) {
-                                bytes = metadata.len();
-                            }
-                        }
+                        let bytes = std::fs::symlink_metadata(path).map_or(0, |m| m.len());
 
                         Self::remove_path_best_effort(path)
                             .with_context(|| format!("删除失败: {path:?}"))?;
@@ -741,13 +1534,11 @@ impl ProjectCleaner {
                     })
                     .try_reduce(|| 0u64, |a, b| Ok(a.saturating_add(b)))?;
 
-                if track_bytes {
-                    bytes_freed = bytes_freed.saturating_add(bytes_in_chunk);
-                }
+                bytes_freed = bytes_freed.saturating_add(bytes_in_chunk);
                 processed = processed.saturating_add(chunk.len());
 
-                if last_report.elapsed() >= Duration::from_millis(120) {
-                    last_report = Instant::now();
+                if last_report.is_none_or(|at| at.elapsed() >= Duration::from_millis(120)) {
+                    last_report = Some(Instant::now());
                     let current_file = chunk
                         .last()
                         .and_then(|p| p.file_name())
@@ -758,16 +1549,17 @@ impl ProjectCleaner {
                         files_processed: processed,
                         total_files,
                         phase: CleanPhase::Cleaning,
+                        elapsed_ms: 0,
                     });
                 }
             }
         } else {
             for entry in WalkDir::new(target_path).follow_links(false).min_depth(1) {
                 self.check_cancel(cancel_flag)?;
-                if let Some(timeout) = timeout {
-                    if start.elapsed() > timeout {
-                        anyhow::bail!(CleanTimedOut { timeout });
-                    }
+                if let Some(timeout) = timeout
+                    && start.elapsed() > timeout
+                {
+                    anyhow::bail!(CleanTimedOut { timeout });
                 }
 
                 let entry = entry.context("遍历 target 目录失败")?;
@@ -778,18 +1570,16 @@ impl ProjectCleaner {
                     continue;
                 }
 
-                if track_bytes {
-                    if let Ok(metadata) = std::fs::symlink_metadata(&path) {
-                        bytes_freed = bytes_freed.saturating_add(metadata.len());
-                    }
+                if let Ok(metadata) = std::fs::symlink_metadata(&path) {
+                    bytes_freed = bytes_freed.saturating_add(metadata.len());
                 }
 
                 Self::remove_path_best_effort(&path)
                     .with_context(|| format!("删除失败: {path:?}"))?;
                 processed = processed.saturating_add(1);
 
-                if last_report.elapsed() >= Duration::from_millis(120) {
-                    last_report = Instant::now();
+                if last_report.is_none_or(|at| at.elapsed() >= Duration::from_millis(120)) {
+                    last_report = Some(Instant::now());
                     progress_callback(CleanProgress {
                         project_name: project.name.clone(),
                         current_file: path
@@ -799,6 +1589,7 @@ impl ProjectCleaner {
                         files_processed: processed,
                         total_files: None,
                         phase: CleanPhase::Cleaning,
+                        elapsed_ms: 0,
                     });
                 }
             }
@@ -807,19 +1598,19 @@ impl ProjectCleaner {
         directories.sort_by_key(|p| std::cmp::Reverse(p.components().count()));
         for dir in directories {
             self.check_cancel(cancel_flag)?;
-            if let Some(timeout) = timeout {
-                if start.elapsed() > timeout {
-                    anyhow::bail!(CleanTimedOut { timeout });
-                }
+            if let Some(timeout) = timeout
+                && start.elapsed() > timeout
+            {
+                anyhow::bail!(CleanTimedOut { timeout });
             }
             let _ = Self::remove_dir_best_effort(&dir);
         }
 
         self.check_cancel(cancel_flag)?;
-        if let Some(timeout) = timeout {
-            if start.elapsed() > timeout {
-                anyhow::bail!(CleanTimedOut { timeout });
-            }
+        if let Some(timeout) = timeout
+            && start.elapsed() > timeout
+        {
+            anyhow::bail!(CleanTimedOut { timeout });
         }
 
         Self::remove_dir_best_effort(target_path)
@@ -838,7 +1629,13 @@ impl ProjectCleaner {
     where
         T: Fn(Duration),
     {
-        let mut child = cmd.spawn().context("启动命令失败")?;
+        let mut child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && cmd.get_program() == "cargo" => {
+                anyhow::bail!(PurgerError::CargoNotAvailable)
+            }
+            Err(e) => return Err(e).context("启动命令失败"),
+        };
         let start = Instant::now();
 
         let stdout = child.stdout.take();
@@ -869,14 +1666,14 @@ impl ProjectCleaner {
                 anyhow::bail!(CleanCancelled);
             }
 
-            if let Some(timeout) = timeout {
-                if start.elapsed() > timeout {
-                    let _ = child.kill();
-                    let _ = child.wait();
-                    let _ = stdout_handle.take().and_then(|h| h.join().ok());
-                    let _ = stderr_handle.take().and_then(|h| h.join().ok());
-                    anyhow::bail!(CleanTimedOut { timeout });
-                }
+            if let Some(timeout) = timeout
+                && start.elapsed() > timeout
+            {
+                let _ = child.kill();
+                let _ = child.wait();
+                let _ = stdout_handle.take().and_then(|h| h.join().ok());
+                let _ = stderr_handle.take().and_then(|h| h.join().ok());
+                anyhow::bail!(CleanTimedOut { timeout });
             }
 
             if let Some(status) = child.try_wait().context("等待子进程失败")? {
@@ -903,6 +1700,17 @@ impl ProjectCleaner {
         })
     }
 
+    /// 删除整个目录，失败时按 `max_retries` 重试，全部失败时返回最后一次的错误
+    fn remove_dir_all_with_retry(&self, path: &Path) -> std::io::Result<()> {
+        retry_with_backoff(self.config.max_retries, DELETE_RETRY_DELAY, || {
+            match std::fs::remove_dir_all(path) {
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                result => result,
+            }
+        })
+    }
+
+    #[allow(clippy::permissions_set_readonly_false)]
     fn remove_path_best_effort(path: &std::path::Path) -> std::io::Result<()> {
         if std::fs::remove_file(path).is_ok() {
             return Ok(());
@@ -920,6 +1728,7 @@ impl ProjectCleaner {
         std::fs::remove_file(path).or_else(|_| std::fs::remove_dir(path))
     }
 
+    #[allow(clippy::permissions_set_readonly_false)]
     fn remove_dir_best_effort(path: &std::path::Path) -> std::io::Result<()> {
         if std::fs::remove_dir(path).is_ok() {
             return Ok(());
@@ -935,7 +1744,14 @@ impl ProjectCleaner {
     }
 
     /// 查找target目录中的可执行文件
-    fn find_executables(&self, target_path: &std::path::Path) -> Result<Vec<PathBuf>> {
+    fn find_executables(&self, project: &RustProject) -> Result<Vec<PathBuf>> {
+        let target_path = project.target_path();
+        let target_path = target_path.as_path();
+        let binary_names = match self.config.executable_filter {
+            ExecutableFilter::CrateBinaries => Some(binary_target_names(project)),
+            ExecutableFilter::Any => None,
+        };
+        let binary_names = binary_names.as_ref();
         let mut executables = Vec::new();
 
         // 检查常见的可执行文件目录
@@ -943,7 +1759,7 @@ impl ProjectCleaner {
 
         for exe_dir in &exe_dirs {
             if exe_dir.exists() {
-                self.scan_directory_for_executables(exe_dir, &mut executables)?;
+                self.scan_directory_for_executables(exe_dir, binary_names, &mut executables)?;
             }
         }
 
@@ -966,7 +1782,11 @@ impl ProjectCleaner {
                                 && (sub_path.file_name().unwrap_or_default() == "debug"
                                     || sub_path.file_name().unwrap_or_default() == "release")
                             {
-                                self.scan_directory_for_executables(&sub_path, &mut executables)?;
+                                self.scan_directory_for_executables(
+                                    &sub_path,
+                                    binary_names,
+                                    &mut executables,
+                                )?;
                             }
                         }
                     }
@@ -981,12 +1801,16 @@ impl ProjectCleaner {
     fn scan_directory_for_executables(
         &self,
         dir: &std::path::Path,
+        binary_names: Option<&HashSet<String>>,
         executables: &mut Vec<PathBuf>,
     ) -> Result<()> {
         if let Ok(entries) = std::fs::read_dir(dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
-                if path.is_file() && self.is_executable(&path) {
+                if path.is_file()
+                    && self.is_executable(&path)
+                    && binary_names.is_none_or(|names| is_crate_binary(&path, names))
+                {
                     executables.push(path);
                 }
             }
@@ -1023,14 +1847,70 @@ impl ProjectCleaner {
             project.path.join("executables")
         };
 
-        let mut hasher = DefaultHasher::new();
-        project.path.to_string_lossy().hash(&mut hasher);
-        let id = hasher.finish();
+        // 使用固定的 FNV-1a 哈希，保证不同版本生成相同的目录名
+        let path = project
+            .path
+            .canonicalize()
+            .unwrap_or_else(|_| project.path.clone());
+        let id = fnv1a_64(path.to_string_lossy().as_bytes());
 
         Ok(base_dir.join(format!("{}-{:016x}", project.name, id)))
     }
 
+    /// 修复残缺/卡住的 target 目录（强制删除，恢复权限并重试）
+    pub fn repair_project(&self, project: &RustProject) -> PurgerResult<u64> {
+        self.repair_project_impl(project)
+            .map_err(|err| PurgerError::clean_failed(&project.name, err))
+    }
+
+    fn repair_project_impl(&self, project: &RustProject) -> Result<u64> {
+        let target_path = project.target_path();
+        if !target_path.exists() {
+            return Ok(0);
+        }
+
+        crate::safety::ensure_not_dangerous(&target_path, self.config.force)?;
+        self.validate_safe_target_directory(project, &target_path)?;
+
+        let size = project.get_target_size();
+        if self.config.dry_run {
+            info!(
+                "DRY RUN: 将修复项目 {} ({})",
+                project.name,
+                crate::format_bytes(size)
+            );
+            return Ok(size);
+        }
+
+        info!("强制删除残缺的target目录: {:?}", target_path);
+        crate::repair::force_remove_dir(&target_path, REPAIR_RETRIES)
+            .context("强制删除target目录失败")?;
+        Ok(size)
+    }
+
+    /// 批量修复残缺的 target 目录
+    pub fn repair_projects(&self, projects: &[RustProject]) -> CleanResult {
+        let start_time = Instant::now();
+        let mut result = CleanResult::new();
+
+        for project in projects {
+            match self.repair_project_impl(project) {
+                Ok(size_freed) => result.add_success(size_freed),
+                Err(err) => result.add_failure_detail(CleanFailure {
+                    project_name: project.name.clone(),
+                    project_path: project.path.clone(),
+                    error: format!("{err:#}"),
+                }),
+            }
+        }
+
+        result.duration_ms = start_time.elapsed().as_millis() as u64;
+        result
+    }
+
     /// 预览清理操作（dry run）
+    ///
+    /// 只需要汇总可回收空间时，可使用不经过清理器的 [`crate::estimate_reclaimable`]。
     pub fn preview_clean(&self, projects: &[RustProject]) -> CleanResult {
         let mut config = self.config.clone();
         config.dry_run = true;
@@ -1039,6 +1919,89 @@ impl ProjectCleaner {
         cleaner.clean_projects(projects)
     }
 
+    /// 列出各项目清理时将会删除的文件和目录（target 下的完整路径），便于在实际清理前检查
+    ///
+    /// 无法列出的项目（如 `cargo clean --dry-run` 失败）对应 `Err`，不影响其他项目。
+    pub fn dry_run_manifest(
+        &self,
+        projects: &[RustProject],
+    ) -> Vec<(RustProject, PurgerResult<Vec<PathBuf>>)> {
+        projects
+            .iter()
+            .map(|project| {
+                let target_path = project.target_path();
+                let files = self
+                    .preview_removed_files(project)
+                    .map(|files| files.into_iter().map(|f| target_path.join(f)).collect());
+                (project.clone(), files)
+            })
+            .collect()
+    }
+
+    /// 列出清理时将会删除的文件和目录（相对 target 目录的完整路径）
+    ///
+    /// CargoClean 策略使用 `cargo clean --dry-run -v` 的输出；DirectDelete/Trash 会移除整个
+    /// target（DirectDelete 指定 profiles 时只移除对应的 profile 目录），因此直接列出其中的所有条目。
+    pub fn preview_removed_files(&self, project: &RustProject) -> PurgerResult<Vec<PathBuf>> {
+        let target_path = project.target_path();
+        if !target_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        match self.config.strategy {
+            CleanStrategy::CargoClean => Ok(self.cargo_clean_file_list(project)?),
+            CleanStrategy::DirectDelete | CleanStrategy::Trash => {
+                let walkers: Vec<WalkDir> = match self.profile_dirs_to_delete(&target_path) {
+                    Some(dirs) => dirs.into_iter().map(WalkDir::new).collect(),
+                    None => vec![WalkDir::new(&target_path).min_depth(1)],
+                };
+                Ok(walkers
+                    .into_iter()
+                    .flat_map(|walker| walker.follow_links(false))
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| {
+                        entry
+                            .path()
+                            .strip_prefix(&target_path)
+                            .unwrap_or(entry.path())
+                            .to_path_buf()
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// 解析 `cargo clean --dry-run -v` 列出的路径，保留相对 target 的完整路径
+    fn cargo_clean_file_list(&self, project: &RustProject) -> Result<Vec<PathBuf>> {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["clean", "--dry-run", "-v"])
+            .current_dir(&project.path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let output = self.run_command_with_timeout_and_cancel(cmd, self.timeout(), None, |_| {})?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("cargo clean --dry-run失败: {}", stderr.trim());
+        }
+
+        let target_path = project.target_path();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut files: Vec<PathBuf> = stdout
+            .lines()
+            .map(|line| PathBuf::from(line.trim()))
+            .filter(|path| path.is_absolute())
+            .map(|path| {
+                path.strip_prefix(&target_path)
+                    .map(Path::to_path_buf)
+                    .unwrap_or(path)
+            })
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
     /// 检查cargo命令是否可用
     pub fn check_cargo_available() -> bool {
         Command::new("cargo")
@@ -1049,25 +2012,226 @@ impl ProjectCleaner {
     }
 }
 
-impl Default for ProjectCleaner {
-    fn default() -> Self {
-        Self::new(CleanConfig::default())
+/// 目录中是否有 cargo 写入的标记文件（`CACHEDIR.TAG` 或 `.rustc_info.json`）
+pub(crate) fn is_cargo_target_dir(path: &Path) -> bool {
+    path.join("CACHEDIR.TAG").is_file() || path.join(".rustc_info.json").is_file()
+}
+
+/// 暂停标志置位时阻塞，直到复位或取消标志置位
+pub fn wait_while_paused(pause_flag: &AtomicBool, cancel_flag: Option<&AtomicBool>) {
+    while pause_flag.load(Ordering::Relaxed)
+        && !cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed))
+    {
+        std::thread::sleep(Duration::from_millis(50));
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use std::path::Path;
-    use tempfile::TempDir;
+/// 列出 target 中的所有文件及其大小
+fn target_files(path: &std::path::Path) -> Vec<(PathBuf, u64)> {
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| {
+            let len = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            (entry.into_path(), len)
+        })
+        .collect()
+}
 
-    fn create_test_project_with_target(dir: &Path, name: &str) -> Result<RustProject> {
-        let project_dir = dir.join(name);
-        fs::create_dir_all(&project_dir)?;
+/// 统计文件清单中已不存在的文件数和字节数
+fn removed_stats(files: &[(PathBuf, u64)]) -> (usize, u64) {
+    files
+        .iter()
+        .filter(|(path, _)| std::fs::symlink_metadata(path).is_err())
+        .fold((0, 0), |(count, bytes), (_, len)| (count + 1, bytes + len))
+}
 
-        let cargo_toml = format!(
-            r#"
+/// 复制文件（自动创建父目录）并保留权限位，确保可执行位不会丢失
+fn copy_preserving_permissions(source: &Path, destination: &Path) -> Result<()> {
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent).with_context(|| format!("创建目录失败: {parent:?}"))?;
+    }
+    std::fs::copy(source, destination)?;
+    let permissions = std::fs::metadata(source)?.permissions();
+    std::fs::set_permissions(destination, permissions)?;
+    Ok(())
+}
+
+/// 查找 target 中被 cargo 持有的 `.cargo-lock`，返回第一个被锁住的文件
+///
+/// 加锁失败说明有 cargo 进程正在使用该目录。
+pub fn build_in_progress(target_path: &Path) -> Option<PathBuf> {
+    crate::repair::cargo_lock_files(target_path)
+        .ok()?
+        .into_iter()
+        .find(|lock| crate::repair::is_lock_held(lock))
+}
+
+/// 读取备份清单，不存在或无法解析时返回空列表
+/// 只由普通路径组成的非空相对路径（没有根、盘符、`.` 或 `..`）
+fn is_plain_relative(path: &Path) -> bool {
+    path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, std::path::Component::Normal(_)))
+}
+
+/// 64 位 FNV-1a 哈希，结果不随 Rust 版本变化
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn read_backup_manifest(backup_dir: &Path) -> Vec<BackupEntry> {
+    std::fs::read_to_string(backup_dir.join(BACKUP_MANIFEST))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// 执行 `op`，失败后最多重试 `retries` 次，第 n 次重试前等待 `delay * n`
+fn retry_with_backoff<T>(
+    retries: usize,
+    delay: Duration,
+    mut op: impl FnMut() -> std::io::Result<T>,
+) -> std::io::Result<T> {
+    let mut attempt = 0;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt >= retries => return Err(err),
+            Err(err) => {
+                attempt += 1;
+                warn!("删除失败，{} 次重试中的第 {} 次: {}", retries, attempt, err);
+                std::thread::sleep(delay * attempt as u32);
+            }
+        }
+    }
+}
+
+/// 可作为 target 下单级目录名的 profile 名称；`dev` 对应 cargo 的 `debug` 目录
+fn profile_dir_names(profiles: &[String]) -> Vec<&str> {
+    profiles
+        .iter()
+        .map(|profile| if profile == "dev" { "debug" } else { profile })
+        .filter(|name| is_single_component(name))
+        .collect()
+}
+
+/// 名称只含一个普通路径组件（不含路径分隔符、`..` 等）
+fn is_single_component(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(components.next(), Some(std::path::Component::Normal(_)))
+        && components.next().is_none()
+}
+
+/// target 下属于指定 profile 的目录：`<profile>` 以及交叉编译产生的 `<triple>/<profile>`
+///
+/// `dev` 对应 cargo 的 `debug` 目录；含路径分隔符或 `..` 的名称会被忽略。
+fn profile_dirs(target_path: &Path, profiles: &[String]) -> Vec<PathBuf> {
+    let names = profile_dir_names(profiles);
+
+    let Ok(entries) = std::fs::read_dir(target_path) else {
+        return Vec::new();
+    };
+
+    let mut dirs = Vec::new();
+    for entry in entries.flatten() {
+        if !entry.file_type().is_ok_and(|t| t.is_dir()) {
+            continue;
+        }
+        let path = entry.path();
+        if names.iter().any(|name| entry.file_name() == *name) {
+            dirs.push(path);
+        } else if !path.join(".fingerprint").exists() {
+            // profile 目录里有 .fingerprint，triple 目录没有
+            dirs.extend(
+                names
+                    .iter()
+                    .map(|name| path.join(name))
+                    .filter(|dir| std::fs::symlink_metadata(dir).is_ok_and(|m| m.is_dir())),
+            );
+        }
+    }
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+/// target 下属于指定目标三元组的目录：整个 `<triple>`，指定 profiles 时只取其中的 `<triple>/<profile>`
+///
+/// 含 `.fingerprint` 的目录是宿主机 profile 而非三元组，不会被选中；含路径分隔符或 `..` 的名称会被忽略。
+fn triple_dirs(
+    target_path: &Path,
+    triples: &[String],
+    profiles: Option<&[String]>,
+) -> Vec<PathBuf> {
+    let profile_names = profiles.map(profile_dir_names);
+    let is_dir = |dir: &Path| std::fs::symlink_metadata(dir).is_ok_and(|m| m.is_dir());
+
+    let mut dirs = Vec::new();
+    for triple in triples.iter().filter(|name| is_single_component(name)) {
+        let triple_dir = target_path.join(triple);
+        if !is_dir(&triple_dir) || triple_dir.join(".fingerprint").exists() {
+            continue;
+        }
+        match &profile_names {
+            Some(names) => dirs.extend(
+                names
+                    .iter()
+                    .map(|name| triple_dir.join(name))
+                    .filter(|dir| is_dir(dir)),
+            ),
+            None => dirs.push(triple_dir),
+        }
+    }
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+/// 统计目录中的文件数和总字节数（目录不存在时为 0）
+fn target_stats(path: &std::path::Path) -> (usize, u64) {
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .fold((0, 0), |(files, bytes), entry| {
+            let len = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            (files + 1, bytes + len)
+        })
+}
+
+impl Default for ProjectCleaner {
+    fn default() -> Self {
+        Self::new(CleanConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn append_bin_target(project: &RustProject, bin: &str) -> Result<()> {
+        let manifest = project.path.join("Cargo.toml");
+        let content = fs::read_to_string(&manifest)?;
+        fs::write(manifest, format!("{content}\n[[bin]]\nname = \"{bin}\"\n"))?;
+        Ok(())
+    }
+
+    fn create_test_project_with_target(dir: &Path, name: &str) -> Result<RustProject> {
+        let project_dir = dir.join(name);
+        fs::create_dir_all(&project_dir)?;
+
+        let cargo_toml = format!(
+            r#"
 [package]
 name = "{name}"
 version = "0.1.0"
@@ -1075,90 +2239,1250 @@ edition = "2021"
 "#
         );
 
-        fs::write(project_dir.join("Cargo.toml"), cargo_toml)?;
+        fs::write(project_dir.join("Cargo.toml"), cargo_toml)?;
+
+        let target_dir = project_dir.join("target");
+        fs::create_dir_all(&target_dir)?;
+        fs::write(
+            target_dir.join("test.txt"),
+            "test content for size calculation",
+        )?;
+
+        RustProject::from_path(&project_dir)
+    }
+
+    #[test]
+    fn test_cleaner_dry_run() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "test_project")?;
+
+        let config = CleanConfig {
+            dry_run: true,
+            ..Default::default()
+        };
+
+        let cleaner = ProjectCleaner::new(config);
+        let size_freed = cleaner.clean_project(&project)?;
+
+        // 在dry run模式下，应该返回原始大小
+        assert_eq!(size_freed, project.target_size);
+
+        // target目录应该仍然存在
+        assert!(project.target_path().exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cleaner_direct_delete() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "test_project")?;
+
+        let config = CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        };
+
+        let cleaner = ProjectCleaner::new(config);
+        let size_freed = cleaner.clean_project(&project)?;
+
+        // 应该释放了一些空间
+        assert!(size_freed > 0);
+
+        // target目录应该被删除
+        assert!(!project.target_path().exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_changed_policy() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("grown");
+        let target = project_dir.join("target");
+        std::fs::create_dir_all(&target)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"grown\"\n",
+        )?;
+        std::fs::write(target.join("artifact"), vec![0u8; 1000])?;
+
+        let mut project = RustProject::from_path(&project_dir)?;
+        assert_eq!(project.target_size, 1000);
+        // 模拟扫描之后又跑了一次构建
+        std::fs::write(target.join("new-artifact"), vec![0u8; 1000])?;
+
+        let config = |on_changed| CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            on_changed,
+            ..Default::default()
+        };
+
+        for policy in [ChangedPolicy::Skip, ChangedPolicy::Confirm] {
+            let err = ProjectCleaner::new(config(policy))
+                .clean_project(&project)
+                .unwrap_err();
+            let changed = err.downcast_ref::<TargetChanged>().expect("TargetChanged");
+            assert_eq!((changed.before, changed.after), (1000, 2000));
+            assert!(target.exists());
+        }
+
+        // Warn：继续清理，且按最新大小统计释放的空间
+        let freed = ProjectCleaner::new(config(ChangedPolicy::Warn)).clean_project(&project)?;
+        assert_eq!(freed, 2000);
+        assert!(!target.exists());
+
+        // 变化未超过阈值时正常清理
+        std::fs::create_dir_all(&target)?;
+        std::fs::write(target.join("artifact"), vec![0u8; 1050])?;
+        project.target_size = 1000;
+        let freed = ProjectCleaner::new(config(ChangedPolicy::Skip)).clean_project(&project)?;
+        assert_eq!(freed, 1050);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_logs_project_span() -> Result<()> {
+        use std::sync::Mutex;
+
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("traced");
+        let target = project_dir.join("target");
+        std::fs::create_dir_all(&target)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"traced\"\n",
+        )?;
+        std::fs::write(target.join("lib.rlib"), vec![0u8; 100])?;
+        let project = RustProject::from_path(&project_dir)?;
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .with_max_level(tracing::Level::INFO)
+            .finish();
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let freed =
+            tracing::subscriber::with_default(subscriber, || cleaner.clean_project(&project))?;
+        assert_eq!(freed, 100);
+
+        let logs = String::from_utf8(captured.0.lock().unwrap().clone())?;
+        let expected_span = format!("clean{{project=traced path={}}}", project.path.display());
+        assert!(logs.contains(&expected_span), "{logs}");
+        assert!(logs.contains("bytes_freed=100"), "{logs}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cargo_clean_invocations() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path().join("ws");
+        for member in ["app", "core"] {
+            let dir = root.join("crates").join(member);
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{member}\"\n"),
+            )?;
+        }
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )?;
+        let workspace = RustProject::from_path(&root)?;
+        let package = RustProject::from_path(root.join("crates").join("app"))?;
+
+        let invocations = |strategy, packages: Option<&[&str]>, project: &RustProject| {
+            ProjectCleaner::new(CleanConfig {
+                strategy,
+                packages: packages.map(|p| p.iter().map(|s| s.to_string()).collect()),
+                ..Default::default()
+            })
+            .cargo_clean_invocations(project)
+        };
+        let full = vec![vec!["clean".to_string()]];
+
+        // 只清理属于该 workspace 的包
+        assert_eq!(
+            invocations(
+                CleanStrategy::CargoClean,
+                Some(&["core", "missing", "app"]),
+                &workspace
+            ),
+            [["clean", "-p", "core"], ["clean", "-p", "app"]]
+        );
+        assert!(invocations(CleanStrategy::CargoClean, Some(&["missing"]), &workspace).is_empty());
+
+        // 单独的包在列表中时完整清理，否则不清理
+        assert_eq!(
+            invocations(CleanStrategy::CargoClean, Some(&["app"]), &package),
+            full
+        );
+        assert!(invocations(CleanStrategy::CargoClean, Some(&["core"]), &package).is_empty());
+
+        // 没有要清理的包的项目不应被选中
+        let selects = |packages: &[&str], project: &RustProject| {
+            ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::CargoClean,
+                packages: Some(packages.iter().map(|s| s.to_string()).collect()),
+                ..Default::default()
+            })
+            .selects_project(project)
+        };
+        assert!(selects(&["app"], &package));
+        assert!(!selects(&["core"], &package));
+        assert!(selects(&["core"], &workspace));
+        assert!(!selects(&["missing"], &workspace));
+
+        // 未指定包或不是 CargoClean 时完整清理
+        assert_eq!(
+            invocations(CleanStrategy::CargoClean, None, &workspace),
+            full
+        );
+        assert_eq!(
+            invocations(CleanStrategy::DirectDelete, Some(&["app"]), &workspace),
+            full
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_skips_pinned_project() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("pinned");
+        let target = project_dir.join("target");
+        std::fs::create_dir_all(&target)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"pinned\"\n",
+        )?;
+        std::fs::write(project_dir.join(crate::KEEP_MARKER), "")?;
+        std::fs::write(target.join("lib.rlib"), vec![0u8; 100])?;
+        let project = RustProject::from_path(&project_dir)?;
+        assert!(project.pinned);
+
+        let cleaner = |force| {
+            ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::DirectDelete,
+                force,
+                ..Default::default()
+            })
+        };
+
+        let err = cleaner(false).clean_project(&project).unwrap_err();
+        assert!(err.downcast_ref::<ProjectPinned>().is_some());
+        assert!(target.exists());
+
+        // force 时照常清理
+        assert_eq!(cleaner(true).clean_project(&project)?, 100);
+        assert!(!target.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_limits() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("huge");
+        let target = project_dir.join("target");
+        std::fs::create_dir_all(&target)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"huge\"\n",
+        )?;
+        for i in 0..5 {
+            std::fs::write(target.join(format!("f{i}")), vec![0u8; 100])?;
+        }
+        let project = RustProject::from_path(&project_dir)?;
+
+        let limited = |files, bytes, force| {
+            ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::DirectDelete,
+                max_delete_files: files,
+                max_delete_bytes: bytes,
+                force,
+                ..Default::default()
+            })
+        };
+
+        let err = limited(Some(4), None, false)
+            .clean_project(&project)
+            .unwrap_err();
+        assert!(err.downcast_ref::<DeleteLimitExceeded>().is_some());
+        let err = limited(None, Some(499), false)
+            .clean_project(&project)
+            .unwrap_err();
+        assert!(err.downcast_ref::<DeleteLimitExceeded>().is_some());
+        assert!(target.exists());
+
+        // 上限之内正常清理
+        assert_eq!(
+            limited(Some(5), Some(500), false)
+                .preview_clean(std::slice::from_ref(&project))
+                .cleaned_projects,
+            1
+        );
+        // force 时忽略上限
+        assert_eq!(
+            limited(Some(1), Some(1), true).clean_project(&project)?,
+            500
+        );
+        assert!(!target.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preview_removed_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("preview");
+        let nested = project_dir.join("target").join("debug").join("deps");
+        std::fs::create_dir_all(&nested)?;
+        std::fs::create_dir_all(project_dir.join("src"))?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"preview\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )?;
+        std::fs::write(project_dir.join("src").join("lib.rs"), "")?;
+        std::fs::write(nested.join("libfoo.rlib"), "x")?;
+        let project = RustProject::from_path(&project_dir)?;
+        let expected = Path::new("debug").join("deps").join("libfoo.rlib");
+
+        let direct = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        assert!(direct.preview_removed_files(&project)?.contains(&expected));
+
+        if ProjectCleaner::check_cargo_available() {
+            let files = ProjectCleaner::default().preview_removed_files(&project)?;
+            assert!(files.contains(&expected), "{files:?}");
+        }
+        // 预览不会删除任何文件
+        assert!(nested.join("libfoo.rlib").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pause_blocks_until_resumed() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("paused");
+        let target = project_dir.join("target");
+        std::fs::create_dir_all(&target)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"paused\"\n",
+        )?;
+        std::fs::write(target.join("artifact"), "x")?;
+        let project = RustProject::from_path(&project_dir)?;
+
+        let pause = Arc::new(AtomicBool::new(true));
+        let cancel = AtomicBool::new(false);
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        })
+        .with_pause_flag(pause.clone());
+
+        std::thread::scope(|scope| {
+            let handle = scope.spawn(|| {
+                cleaner.clean_project_with_progress_and_cancel(&project, Some(&cancel), |_| {})
+            });
+            std::thread::sleep(Duration::from_millis(200));
+            // 暂停期间不会删除
+            assert!(target.exists());
+            assert!(!handle.is_finished());
+
+            pause.store(false, Ordering::Relaxed);
+            handle.join().unwrap()
+        })?;
+        assert!(!target.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_target_stats() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        std::fs::create_dir_all(target.join("debug").join("deps")).unwrap();
+        std::fs::write(target.join("debug").join("a"), vec![0u8; 100]).unwrap();
+        std::fs::write(target.join("debug").join("deps").join("b"), vec![0u8; 28]).unwrap();
+
+        assert_eq!(target_stats(&target), (2, 128));
+        assert_eq!(target_stats(&temp_dir.path().join("missing")), (0, 0));
+    }
+
+    #[test]
+    fn test_check_cargo_available() {
+        // 这个测试可能在某些环境中失败，如果cargo不可用
+        // 在实际项目中，可能需要mock这个功能
+        let available = ProjectCleaner::check_cargo_available();
+        println!("Cargo available: {available}");
+    }
+
+    #[test]
+    fn test_clean_projects_batch() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let projects = vec![
+            create_test_project_with_target(temp_dir.path(), "project1")?,
+            create_test_project_with_target(temp_dir.path(), "project2")?,
+        ];
+
+        let config = CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            dry_run: false,
+            ..Default::default()
+        };
+
+        let cleaner = ProjectCleaner::new(config);
+        let result = cleaner.clean_projects(&projects);
+
+        assert_eq!(result.cleaned_projects, 2);
+        assert!(result.total_size_freed > 0);
+        assert!(result.failed_projects.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stop_flag_between_projects() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let projects = vec![
+            create_test_project_with_target(temp_dir.path(), "first")?,
+            create_test_project_with_target(temp_dir.path(), "second")?,
+        ];
+
+        // 预先置位：一个项目都不清理
+        let stop = Arc::new(AtomicBool::new(true));
+        for parallel in [false, true] {
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::DirectDelete,
+                parallel,
+                ..Default::default()
+            })
+            .with_stop_flag(stop.clone());
+            let result = cleaner.clean_projects(&projects);
+            assert_eq!(result.cleaned_projects, 0);
+            assert!(result.outcomes.is_empty());
+            assert!(projects.iter().all(|p| p.target_path().exists()));
+        }
+
+        // 第一个项目清理中途置位：该项目照常完成，得到只含它的部分结果
+        stop.store(false, Ordering::Relaxed);
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        })
+        .with_stop_flag(stop.clone());
+        let result = cleaner.clean_projects_with_progress(&projects, None, |event| {
+            if let BatchCleanEvent::Progress(_) = event {
+                stop.store(true, Ordering::Relaxed);
+            }
+        });
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(result.outcomes.len(), 1);
+        assert_eq!(result.outcomes[0].name, "first");
+        assert_eq!(result.total_size_freed, projects[0].target_size);
+        assert!(!projects[0].target_path().exists());
+        assert!(projects[1].target_path().exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_projects_with_progress_events() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut pinned = create_test_project_with_target(temp_dir.path(), "pinned")?;
+        pinned.pinned = true;
+        let projects = vec![
+            create_test_project_with_target(temp_dir.path(), "project1")?,
+            pinned,
+        ];
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let events = std::sync::Mutex::new(Vec::new());
+        let result = cleaner.clean_projects_with_progress(&projects, None, |event| {
+            let event = match event {
+                BatchCleanEvent::ProjectStarted {
+                    index,
+                    total,
+                    project,
+                } => format!("start {index}/{total} {}", project.name),
+                BatchCleanEvent::Progress(progress) => {
+                    format!("progress {}", progress.project_name)
+                }
+                BatchCleanEvent::ProjectCompleted {
+                    index,
+                    project,
+                    bytes_freed,
+                    total_freed,
+                    ..
+                } => {
+                    assert_eq!(bytes_freed, total_freed);
+                    format!("done {index} {}", project.name)
+                }
+                BatchCleanEvent::ProjectFailed {
+                    index,
+                    project,
+                    error,
+                    ..
+                } => {
+                    assert!(error.downcast_ref::<ProjectPinned>().is_some());
+                    format!("failed {index} {}", project.name)
+                }
+            };
+            let mut events = events.lock().unwrap();
+            // 连续的进度事件只保留一条，便于比较
+            if events.last() != Some(&event) {
+                events.push(event);
+            }
+        });
+
+        assert_eq!(
+            events.into_inner().unwrap(),
+            [
+                "start 0/2 project1",
+                "progress project1",
+                "done 0 project1",
+                "start 1/2 pinned",
+                "failed 1 pinned",
+            ]
+        );
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(result.failed_projects.len(), 1);
+        assert_eq!(result.outcomes.len(), 2);
+        assert!(!projects[0].target_path().exists());
+        assert!(projects[1].target_path().exists());
+
+        // 已取消时不再开始任何项目
+        let cancel = AtomicBool::new(true);
+        let result = cleaner.clean_projects_with_progress(&projects, Some(&cancel), |_| {
+            panic!("no events after cancel")
+        });
+        assert!(result.outcomes.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_projects_single_job() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let projects = (0..4)
+            .map(|i| create_test_project_with_target(temp_dir.path(), &format!("project{i}")))
+            .collect::<Result<Vec<_>>>()?;
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            parallel: true,
+            jobs: Some(1),
+            ..Default::default()
+        });
+        let result = cleaner.clean_projects(&projects);
+
+        assert_eq!(result.cleaned_projects, 4);
+        assert!(result.failures.is_empty());
+        assert!(projects.iter().all(|p| !p.target_path().exists()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_projects_parallel_matches_sequential() -> Result<()> {
+        let run = |parallel: bool| -> Result<CleanResult> {
+            let temp_dir = TempDir::new()?;
+            let mut projects = (0..8)
+                .map(|i| create_test_project_with_target(temp_dir.path(), &format!("project{i}")))
+                .collect::<Result<Vec<_>>>()?;
+            projects.push(RustProject {
+                path: temp_dir.path().join("missing"),
+                name: "missing".to_string(),
+                target_size: 0,
+                last_modified: std::time::SystemTime::now(),
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
+                has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
+            });
+
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::DirectDelete,
+                parallel,
+                ..Default::default()
+            });
+            let result = cleaner.clean_projects(&projects);
+            for project in &projects[..8] {
+                assert!(!project.target_path().exists());
+            }
+            // 逐项目结果与输入一一对应
+            assert_eq!(result.outcomes.len(), projects.len());
+            for (outcome, project) in result.outcomes.iter().zip(&projects) {
+                assert_eq!(outcome.path, project.path);
+                assert_eq!(outcome.name, project.name);
+            }
+            assert!(result.outcomes.iter().all(|o| o.error.is_none()));
+            assert_eq!(result.outcomes[8].bytes_freed, 0);
+            Ok(result)
+        };
+
+        let sequential = run(false)?;
+        let parallel = run(true)?;
+        assert_eq!(parallel.cleaned_projects, sequential.cleaned_projects);
+        assert_eq!(parallel.total_size_freed, sequential.total_size_freed);
+        assert_eq!(parallel.failed_projects, sequential.failed_projects);
+        assert_eq!(
+            parallel.cleaned_projects + parallel.failed_projects.len(),
+            9
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_freed_size_reflects_current_target() -> Result<()> {
+        for (parallel, with_cancel) in [(false, false), (false, true), (true, true)] {
+            let temp_dir = TempDir::new()?;
+            let project = create_test_project_with_target(temp_dir.path(), "grown")?;
+            // 扫描之后又有构建写入了新文件
+            fs::write(project.target_path().join("extra.bin"), vec![0u8; 4096])?;
+            let (_, on_disk) = target_stats(&project.target_path());
+            assert!(on_disk > project.target_size);
+
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::DirectDelete,
+                parallel,
+                ..Default::default()
+            });
+            let cancel = AtomicBool::new(false);
+            let freed = cleaner.clean_project_with_progress_and_cancel(
+                &project,
+                with_cancel.then_some(&cancel),
+                |_| {},
+            )?;
+            assert_eq!(freed, on_disk);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cancel_mid_delete() -> Result<()> {
+        for parallel in [false, true] {
+            let temp_dir = TempDir::new()?;
+            let project = create_test_project_with_target(temp_dir.path(), "huge")?;
+            let deps = project.target_path().join("debug").join("deps");
+            fs::create_dir_all(&deps)?;
+            for i in 0..2000 {
+                fs::write(deps.join(format!("file{i}.o")), b"x")?;
+            }
+            let (total_files, _) = target_stats(&project.target_path());
+
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                strategy: CleanStrategy::DirectDelete,
+                parallel,
+                ..Default::default()
+            });
+            let cancel = AtomicBool::new(false);
+            let reported = std::sync::Mutex::new(Vec::new());
+            let err = cleaner
+                .clean_project_with_progress_and_cancel(&project, Some(&cancel), |progress| {
+                    if progress.phase == CleanPhase::Cleaning && progress.files_processed > 0 {
+                        reported.lock().unwrap().push(progress.files_processed);
+                        // 第一次汇报删除进度时按下停止
+                        cancel.store(true, Ordering::Relaxed);
+                    }
+                })
+                .unwrap_err();
+            assert!(matches!(err, PurgerError::Cancelled));
+
+            // 停止后不再删除：只汇报了一次部分进度，剩余文件保留在磁盘上
+            let reported = reported.into_inner().unwrap();
+            assert_eq!(reported.len(), 1, "parallel={parallel}");
+            let (remaining, _) = target_stats(&project.target_path());
+            assert_eq!(remaining, total_files - reported[0], "parallel={parallel}");
+            assert!(remaining > 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cleaner_trash_dry_run() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "trash_project")?;
+
+        let dry_run = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::Trash,
+            dry_run: true,
+            ..Default::default()
+        });
+        assert_eq!(dry_run.clean_project(&project)?, project.target_size);
+        assert!(project.target_path().exists());
+
+        Ok(())
+    }
+
+    // 会真正移动到系统回收站，无头环境/CI 中通常没有可用的回收站：`cargo test -- --ignored` 手动运行
+    #[test]
+    #[ignore = "needs a system recycle bin"]
+    fn test_cleaner_trash() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "trash_project")?;
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::Trash,
+            ..Default::default()
+        });
+        let freed = cleaner.clean_project(&project)?;
+        assert_eq!(freed, project.target_size);
+        assert!(!project.target_path().exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_relocated_target() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("project");
+        fs::create_dir_all(&project_dir)?;
+        fs::write(project_dir.join("Cargo.toml"), "[package]\nname = \"p\"\n")?;
+        let shared = temp_dir.path().join("shared-target");
+        fs::create_dir_all(&shared)?;
+        fs::write(shared.join("artifact"), "12345")?;
+
+        let project = RustProject {
+            path: project_dir,
+            name: "p".to_string(),
+            target_size: 5,
+            last_modified: std::time::SystemTime::now(),
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
+            has_target: true,
+            largest_file: None,
+            target_dir: Some(shared.clone()),
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
+        };
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+
+        // 没有 cargo 标记文件的目录拒绝删除
+        let err = cleaner.clean_project(&project).unwrap_err();
+        assert!(err.downcast_ref::<UnsafeTargetDirectory>().is_some());
+        assert!(shared.exists());
+
+        fs::write(
+            shared.join("CACHEDIR.TAG"),
+            "Signature: 8a477f597d28d172789f06886806bc55",
+        )?;
+        cleaner.clean_project(&project)?;
+        assert!(!shared.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cargo_clean_progress_counts_removed_files() -> Result<()> {
+        if !ProjectCleaner::check_cargo_available() {
+            return Ok(());
+        }
+
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "cargo_progress")?;
+        fs::create_dir_all(project.path.join("src"))?;
+        fs::write(project.path.join("src").join("lib.rs"), "")?;
+        let deps = project.target_path().join("debug").join("deps");
+        fs::create_dir_all(&deps)?;
+        for i in 0..3 {
+            fs::write(deps.join(format!("libdep{i}.rlib")), "rlib")?;
+        }
+
+        let events = std::sync::Mutex::new(Vec::new());
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::CargoClean,
+            ..Default::default()
+        });
+        let freed = cleaner.clean_project_with_progress(&project, |progress| {
+            events.lock().unwrap().push(progress);
+        })?;
+
+        let events = events.into_inner().unwrap();
+        let last = events
+            .iter()
+            .rfind(|p| p.phase == CleanPhase::Finalizing)
+            .unwrap();
+        assert_eq!(last.total_files, Some(4));
+        assert_eq!(last.files_processed, 4);
+        assert_eq!(freed, "test content for size calculation".len() as u64 + 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_removed_stats() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let target = temp_dir.path().join("target");
+        fs::create_dir_all(&target)?;
+        fs::write(target.join("a"), "aa")?;
+        fs::write(target.join("b"), "bbb")?;
 
-        let target_dir = project_dir.join("target");
-        fs::create_dir_all(&target_dir)?;
-        fs::write(
-            target_dir.join("test.txt"),
-            "test content for size calculation",
-        )?;
+        let files = target_files(&target);
+        assert_eq!(files.len(), 2);
+        assert_eq!(removed_stats(&files), (0, 0));
 
-        RustProject::from_path(&project_dir)
+        fs::remove_file(target.join("b"))?;
+        assert_eq!(removed_stats(&files), (1, 3));
+
+        Ok(())
     }
 
     #[test]
-    fn test_cleaner_dry_run() -> Result<()> {
+    fn test_backup_delete_restore_executables() -> Result<()> {
         let temp_dir = TempDir::new()?;
-        let project = create_test_project_with_target(temp_dir.path(), "test_project")?;
+        let project = create_test_project_with_target(temp_dir.path(), "restore_project")?;
+        append_bin_target(&project, "mytool")?;
+        let release = project.target_path().join("release");
+        fs::create_dir_all(&release)?;
+        let exe = release.join(format!("mytool{}", std::env::consts::EXE_SUFFIX));
+        fs::write(&exe, "binary")?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&exe, fs::Permissions::from_mode(0o755))?;
+        }
 
-        let config = CleanConfig {
-            dry_run: true,
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            keep_executable: true,
+            executable_backup_dir: Some(temp_dir.path().join("backup")),
+            ..Default::default()
+        });
+        cleaner.clean_project(&project)?;
+        assert!(!project.target_path().exists());
+
+        assert_eq!(cleaner.restore_executables(&project)?, 1);
+        assert_eq!(fs::read_to_string(&exe)?, "binary");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_restore_rejects_unsafe_manifest_paths() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "tampered")?;
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            executable_backup_dir: Some(temp_dir.path().join("backup")),
+            ..Default::default()
+        });
+        let backup_dir = cleaner.get_backup_directory(&project)?;
+        fs::create_dir_all(&backup_dir)?;
+        fs::write(backup_dir.join("tool"), "binary")?;
+        let escape = temp_dir.path().join("escaped");
+
+        for original in [
+            PathBuf::from("../../escaped"),
+            escape.clone(),
+            PathBuf::from("release/../../escaped"),
+        ] {
+            let manifest = vec![BackupEntry {
+                backup: PathBuf::from("tool"),
+                original,
+            }];
+            fs::write(
+                backup_dir.join(BACKUP_MANIFEST),
+                serde_json::to_string(&manifest)?,
+            )?;
+            assert!(cleaner.restore_executables(&project).is_err());
+        }
+        assert!(!escape.exists());
+
+        // 备份目录名只取决于项目路径，并且是固定的值
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(backup_dir, cleaner.get_backup_directory(&project)?);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_backup_preserves_structure_and_permissions() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "backup_project")?;
+        append_bin_target(&project, "foo")?;
+        for profile in ["debug", "release"] {
+            let dir = project.target_path().join(profile);
+            fs::create_dir_all(&dir)?;
+            fs::write(dir.join("foo"), profile)?;
+            fs::set_permissions(dir.join("foo"), fs::Permissions::from_mode(0o755))?;
+        }
+
+        let backup_root = temp_dir.path().join("backup");
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            keep_executable: true,
+            executable_backup_dir: Some(backup_root.clone()),
             ..Default::default()
+        });
+        cleaner.clean_project(&project)?;
+
+        let backup_dir = cleaner.get_backup_directory(&project)?;
+        for profile in ["debug", "release"] {
+            let backup = backup_dir.join(profile).join("foo");
+            assert_eq!(fs::read_to_string(&backup)?, profile);
+            assert_ne!(fs::metadata(&backup)?.permissions().mode() & 0o111, 0);
+        }
+
+        assert_eq!(cleaner.restore_executables(&project)?, 2);
+        for profile in ["debug", "release"] {
+            let restored = project.target_path().join(profile).join("foo");
+            assert_eq!(fs::read_to_string(&restored)?, profile);
+            assert_ne!(fs::metadata(&restored)?.permissions().mode() & 0o111, 0);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_executable_filter() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "app")?;
+        append_bin_target(&project, "app-cli")?;
+        let debug = project.target_path().join("debug");
+        fs::create_dir_all(&debug)?;
+        for name in [
+            "app",
+            "app-cli",
+            "libapp.so",
+            "build-script-build",
+            "helper",
+        ] {
+            fs::write(debug.join(name), name)?;
+            fs::set_permissions(debug.join(name), fs::Permissions::from_mode(0o755))?;
+        }
+
+        let found = |executable_filter| -> Result<Vec<String>> {
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                keep_executable: true,
+                executable_filter,
+                ..Default::default()
+            });
+            let mut names: Vec<String> = cleaner
+                .find_executables(&project)?
+                .iter()
+                .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            Ok(names)
         };
 
-        let cleaner = ProjectCleaner::new(config);
-        let size_freed = cleaner.clean_project(&project)?;
+        assert_eq!(found(ExecutableFilter::CrateBinaries)?, ["app", "app-cli"]);
+        // 默认备份所有可执行文件
+        assert_eq!(
+            found(ExecutableFilter::default())?,
+            [
+                "app",
+                "app-cli",
+                "build-script-build",
+                "helper",
+                "libapp.so"
+            ]
+        );
 
-        // 在dry run模式下，应该返回原始大小
-        assert_eq!(size_freed, project.target_size);
+        Ok(())
+    }
 
-        // target目录应该仍然存在
-        assert!(project.target_path().exists());
+    #[cfg(unix)]
+    #[test]
+    fn test_executable_filter_workspace_member_bins() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path().join("ws");
+        fs::create_dir_all(root.join("crates/tool/src/bin"))?;
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )?;
+        fs::write(
+            root.join("crates/tool/Cargo.toml"),
+            "[package]\nname = \"tool\"\n\n[[bin]]\nname = \"tool-extra\"\npath = \"src/extra.rs\"\n",
+        )?;
+        fs::write(root.join("crates/tool/src/bin/helper.rs"), "fn main() {}")?;
+        let debug = root.join("target/debug");
+        fs::create_dir_all(&debug)?;
+        for name in ["tool", "tool-extra", "helper", "build-script-build"] {
+            fs::write(debug.join(name), name)?;
+            fs::set_permissions(debug.join(name), fs::Permissions::from_mode(0o755))?;
+        }
+
+        let project = RustProject::from_path(&root)?;
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            keep_executable: true,
+            executable_filter: ExecutableFilter::CrateBinaries,
+            ..Default::default()
+        });
+        let mut names: Vec<String> = cleaner
+            .find_executables(&project)?
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["helper", "tool", "tool-extra"]);
 
         Ok(())
     }
 
     #[test]
-    fn test_cleaner_direct_delete() -> Result<()> {
+    fn test_skip_build_in_progress() -> Result<()> {
         let temp_dir = TempDir::new()?;
-        let project = create_test_project_with_target(temp_dir.path(), "test_project")?;
+        let project = create_test_project_with_target(temp_dir.path(), "building")?;
+        let debug = project.target_path().join("debug");
+        fs::create_dir_all(&debug)?;
+        let lock_path = debug.join(".cargo-lock");
+        let lock = fs::File::create(&lock_path)?;
 
-        let config = CleanConfig {
+        // 锁文件存在但没有被持有（构建已结束）时照常清理
+        assert!(build_in_progress(&project.target_path()).is_none());
+
+        lock.lock()?;
+        assert_eq!(build_in_progress(&project.target_path()), Some(lock_path));
+
+        let config = |force| CleanConfig {
             strategy: CleanStrategy::DirectDelete,
+            force,
             ..Default::default()
         };
+        let result =
+            ProjectCleaner::new(config(false)).clean_projects(std::slice::from_ref(&project));
+        assert_eq!(result.cleaned_projects, 0);
+        assert_eq!(result.failures.len(), 1);
+        assert!(result.failures[0].error.contains("build in progress"));
+        assert!(project.target_path().exists());
 
-        let cleaner = ProjectCleaner::new(config);
-        let size_freed = cleaner.clean_project(&project)?;
+        let result =
+            ProjectCleaner::new(config(true)).clean_projects(std::slice::from_ref(&project));
+        assert_eq!(result.cleaned_projects, 1);
+        assert!(!project.target_path().exists());
 
-        // 应该释放了一些空间
-        assert!(size_freed > 0);
+        Ok(())
+    }
 
-        // target目录应该被删除
+    #[test]
+    fn test_clean_removes_extra_targets() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut project = create_test_project_with_target(temp_dir.path(), "extra")?;
+        let extra = project.path.join("target-wasm");
+        fs::create_dir_all(&extra)?;
+        fs::write(extra.join("app.wasm"), "wasm binary")?;
+        fs::write(
+            extra.join("CACHEDIR.TAG"),
+            "Signature: 8a477f597d28d172789f06886806bc55",
+        )?;
+        // 没有 cargo 标记文件的目录即使被直接加入也不会删除
+        let assets = project.path.join("assets");
+        fs::create_dir_all(&assets)?;
+        fs::write(assets.join("logo.png"), "png")?;
+        project.extra_targets.push(assets.clone());
+        project.add_extra_targets(
+            &["target-wasm".to_string()],
+            false,
+            false,
+            crate::SizeMode::Apparent,
+        );
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let freed = cleaner.clean_project(&project)?;
+        assert_eq!(freed, project.target_size);
         assert!(!project.target_path().exists());
+        assert!(!extra.exists());
+        assert!(assets.join("logo.png").exists());
 
         Ok(())
     }
 
     #[test]
-    fn test_check_cargo_available() {
-        // 这个测试可能在某些环境中失败，如果cargo不可用
-        // 在实际项目中，可能需要mock这个功能
-        let available = ProjectCleaner::check_cargo_available();
-        println!("Cargo available: {available}");
+    fn test_clean_only_debug_profile() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "profiles")?;
+        let target = project.target_path();
+        let triple = target.join("x86_64-unknown-linux-gnu");
+        for dir in [
+            target.join("debug"),
+            target.join("release"),
+            triple.join("debug"),
+            triple.join("release"),
+        ] {
+            fs::create_dir_all(dir.join(".fingerprint"))?;
+            fs::write(dir.join("app"), "binary")?;
+        }
+
+        let config = CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            profiles: Some(vec!["debug".to_string()]),
+            ..Default::default()
+        };
+        let cleaner = ProjectCleaner::new(config.clone());
+        let mut preview = cleaner.preview_removed_files(&project)?;
+        preview.sort();
+        assert!(preview.contains(&PathBuf::from("debug/app")));
+        assert!(!preview.iter().any(|path| path.ends_with("release/app")));
+
+        let freed = cleaner.clean_project(&project)?;
+        assert_eq!(freed, 2 * "binary".len() as u64);
+        assert!(!target.join("debug").exists());
+        assert!(!triple.join("debug").exists());
+        assert!(target.join("release/app").exists());
+        assert!(triple.join("release/app").exists());
+        assert!(target.join("test.txt").exists());
+
+        // 名称中的路径分隔符不会逃出 target
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            profiles: Some(vec!["../profiles".to_string()]),
+            ..config
+        });
+        assert_eq!(cleaner.clean_project(&project)?, 0);
+        assert!(target.join("release/app").exists());
+
+        Ok(())
     }
 
     #[test]
-    fn test_clean_projects_batch() -> Result<()> {
+    fn test_clean_only_selected_triples() -> Result<()> {
         let temp_dir = TempDir::new()?;
-        let projects = vec![
-            create_test_project_with_target(temp_dir.path(), "project1")?,
-            create_test_project_with_target(temp_dir.path(), "project2")?,
-        ];
+        let project = create_test_project_with_target(temp_dir.path(), "triples")?;
+        let target = project.target_path();
+        let x86 = target.join("x86_64-unknown-linux-gnu");
+        let wasm = target.join("wasm32-unknown-unknown");
+        for dir in [
+            target.join("debug"),
+            x86.join("debug"),
+            x86.join("release"),
+            wasm.join("release"),
+        ] {
+            fs::create_dir_all(dir.join(".fingerprint"))?;
+            fs::write(dir.join("app"), "binary")?;
+        }
 
+        // 同时指定 profile 时只删除该三元组下对应的 profile
         let config = CleanConfig {
             strategy: CleanStrategy::DirectDelete,
-            dry_run: false,
+            triples: Some(vec!["x86_64-unknown-linux-gnu".to_string()]),
+            profiles: Some(vec!["dev".to_string()]),
             ..Default::default()
         };
+        let freed = ProjectCleaner::new(config.clone()).clean_project(&project)?;
+        assert_eq!(freed, "binary".len() as u64);
+        assert!(!x86.join("debug").exists());
+        assert!(x86.join("release/app").exists());
+        assert!(target.join("debug/app").exists());
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            triples: Some(vec!["wasm32-unknown-unknown".to_string()]),
+            profiles: None,
+            ..config.clone()
+        });
+        let preview = cleaner.preview_removed_files(&project)?;
+        assert!(preview.contains(&PathBuf::from("wasm32-unknown-unknown/release/app")));
+        assert!(
+            preview
+                .iter()
+                .all(|path| path.starts_with("wasm32-unknown-unknown"))
+        );
 
-        let cleaner = ProjectCleaner::new(config);
-        let result = cleaner.clean_projects(&projects);
+        let freed = cleaner.clean_project(&project)?;
+        assert_eq!(freed, "binary".len() as u64);
+        assert!(!wasm.exists());
+        assert!(x86.join("release/app").exists());
+        assert!(target.join("debug/app").exists());
+        assert!(target.join("test.txt").exists());
+
+        // 宿主机 profile 目录和越界名称都不会被当作三元组
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            triples: Some(vec!["debug".to_string(), "../triples".to_string()]),
+            profiles: None,
+            ..config
+        });
+        assert_eq!(cleaner.clean_project(&project)?, 0);
+        assert!(target.join("debug/app").exists());
 
-        assert_eq!(result.cleaned_projects, 2);
-        assert!(result.total_size_freed > 0);
-        assert!(result.failed_projects.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_error_variants() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "variants")?;
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            ..Default::default()
+        });
+        let cancel = AtomicBool::new(true);
+        let err = cleaner
+            .clean_project_with_progress_and_cancel(&project, Some(&cancel), |_| {})
+            .unwrap_err();
+        assert!(matches!(err, PurgerError::Cancelled));
+
+        let limited = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            max_delete_files: Some(0),
+            ..Default::default()
+        });
+        match limited.clean_project(&project).unwrap_err() {
+            PurgerError::CleanFailed {
+                project: name,
+                source,
+            } => {
+                assert_eq!(name, "variants");
+                assert!(source.is::<DeleteLimitExceeded>());
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(project.target_path().exists());
 
         Ok(())
     }
@@ -1182,6 +3506,7 @@ edition = "2021"
             files_processed: 5,
             total_files: Some(10),
             phase: CleanPhase::Cleaning,
+            elapsed_ms: 2000,
         };
 
         assert_eq!(progress.project_name, "test");
@@ -1189,6 +3514,128 @@ edition = "2021"
         assert_eq!(progress.files_processed, 5);
         assert_eq!(progress.total_files, Some(10));
         assert_eq!(progress.phase, CleanPhase::Cleaning);
+        assert_eq!(progress.eta(), Some(Duration::from_secs(2)));
+
+        let unknown_total = CleanProgress {
+            total_files: None,
+            ..progress
+        };
+        assert_eq!(unknown_total.eta(), None);
+    }
+
+    #[test]
+    fn test_estimate_remaining() {
+        let elapsed = Duration::from_secs(60);
+        // 1/4 用了 1 分钟，剩余 3 分钟
+        assert_eq!(
+            estimate_remaining(25, 100, elapsed),
+            Some(Duration::from_secs(180))
+        );
+        assert_eq!(
+            estimate_remaining(3, 4, elapsed),
+            Some(Duration::from_secs(20))
+        );
+        // 尚无进度时无从估算，完成（或超出）时为 0
+        assert_eq!(estimate_remaining(0, 100, elapsed), None);
+        assert_eq!(estimate_remaining(100, 100, elapsed), Some(Duration::ZERO));
+        assert_eq!(estimate_remaining(5, 0, elapsed), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_clean_emits_finalizing_then_complete() -> Result<()> {
+        let mut strategies = vec![CleanStrategy::DirectDelete];
+        if ProjectCleaner::check_cargo_available() {
+            strategies.push(CleanStrategy::CargoClean);
+        }
+
+        for strategy in strategies {
+            let temp_dir = TempDir::new()?;
+            let project = create_test_project_with_target(temp_dir.path(), "test_project")?;
+            fs::create_dir_all(project.path.join("src"))?;
+            fs::write(project.path.join("src").join("lib.rs"), "")?;
+
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                strategy,
+                ..Default::default()
+            });
+            let phases = std::sync::Mutex::new(Vec::new());
+            cleaner.clean_project_with_progress(&project, |progress| {
+                phases.lock().unwrap().push(progress.phase);
+            })?;
+            assert!(!project.target_path().exists(), "{strategy:?}");
+
+            // 阶段只会前进：Starting 开头，Finalizing 之后紧跟 Complete 结束
+            let phases = phases.into_inner().unwrap();
+            let order = |phase: &CleanPhase| match phase {
+                CleanPhase::Starting => 0,
+                CleanPhase::Analyzing => 1,
+                CleanPhase::Cleaning => 2,
+                CleanPhase::Finalizing => 3,
+                CleanPhase::Complete => 4,
+            };
+            assert!(
+                phases.windows(2).all(|w| order(&w[0]) <= order(&w[1])),
+                "{strategy:?}: {phases:?}"
+            );
+            assert_eq!(phases.first(), Some(&CleanPhase::Starting), "{strategy:?}");
+            assert_eq!(
+                &phases[phases.len() - 2..],
+                &[CleanPhase::Finalizing, CleanPhase::Complete],
+                "{strategy:?}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_clean_refuses_dangerous_target() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("evil_project");
+        fs::create_dir_all(&project_dir)?;
+        fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"evil\"\nversion = \"0.1.0\"\n",
+        )?;
+        // target 指向受保护的系统目录
+        std::os::unix::fs::symlink("/usr", project_dir.join("target"))?;
+        let project = RustProject::from_path_lazy(&project_dir)?;
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            dry_run: true,
+            ..Default::default()
+        });
+        let err = cleaner.clean_project(&project).unwrap_err();
+        assert!(err.downcast_ref::<crate::safety::DangerousPath>().is_some());
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            dry_run: true,
+            force: true,
+            ..Default::default()
+        });
+        assert!(cleaner.clean_project(&project).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repair_projects() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "stuck_project")?;
+        let debug_dir = project.target_path().join("debug");
+        fs::create_dir_all(&debug_dir)?;
+        fs::write(debug_dir.join(".cargo-lock"), "")?;
+        assert!(crate::repair::detect_partial_target(&project.target_path()).is_some());
+
+        let cleaner = ProjectCleaner::default();
+        let result = cleaner.repair_projects(std::slice::from_ref(&project));
+
+        assert_eq!(result.cleaned_projects, 1);
+        assert!(result.total_size_freed > 0);
+        assert!(!project.target_path().exists());
+
+        Ok(())
     }
 
     #[test]
@@ -1241,7 +3688,7 @@ edition = "2021"
 
         // 测试格式化大小
         let formatted = result.format_size();
-        assert_eq!(formatted, "1.00 KB");
+        assert_eq!(formatted, "1.00 KiB");
     }
 
     #[test]
@@ -1252,8 +3699,15 @@ edition = "2021"
             name: "nonexistent".to_string(),
             target_size: 1000,
             last_modified: std::time::SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: true,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         let cleaner = ProjectCleaner::default();
@@ -1268,6 +3722,79 @@ edition = "2021"
         Ok(())
     }
 
+    #[test]
+    fn test_dry_run_manifest() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "manifest")?;
+        let target = project.target_path();
+        fs::create_dir_all(target.join("debug/deps"))?;
+        fs::write(target.join("debug/deps/libfoo.rlib"), "rlib")?;
+
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            dry_run: true,
+            ..Default::default()
+        });
+        let manifest = cleaner.dry_run_manifest(std::slice::from_ref(&project));
+        assert_eq!(manifest.len(), 1);
+        let (listed, files) = &manifest[0];
+        assert_eq!(listed.name, "manifest");
+        let files = files.as_ref().unwrap();
+        assert!(files.contains(&target.join("test.txt")));
+        assert!(files.contains(&target.join("debug/deps/libfoo.rlib")));
+        assert!(files.iter().all(|file| file.starts_with(&target)));
+
+        // 只是预览，不会删除任何内容
+        assert!(target.join("debug/deps/libfoo.rlib").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_with_backoff() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let dir = temp_dir.path().join("target");
+        fs::create_dir_all(dir.join("debug"))?;
+        let locked = dir.join("debug/app.exe");
+        fs::write(&locked, "binary")?;
+        let mut perms = fs::metadata(&locked)?.permissions();
+        perms.set_readonly(true);
+        fs::set_permissions(&locked, perms)?;
+
+        // 第一次尝试时文件仍被"占用"，之后解除只读再删除
+        let mut attempts = 0;
+        retry_with_backoff(2, Duration::ZERO, || {
+            attempts += 1;
+            if attempts == 1 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    format!("{locked:?} is read-only"),
+                ));
+            }
+            #[allow(clippy::permissions_set_readonly_false)]
+            {
+                let mut perms = fs::metadata(&locked)?.permissions();
+                perms.set_readonly(false);
+                fs::set_permissions(&locked, perms)?;
+            }
+            fs::remove_dir_all(&dir)
+        })?;
+        assert_eq!(attempts, 2);
+        assert!(!dir.exists());
+
+        // 重试用尽时返回最后一次的错误
+        let mut attempts = 0;
+        let err = retry_with_backoff(2, Duration::ZERO, || -> std::io::Result<()> {
+            attempts += 1;
+            Err(std::io::Error::other(format!("attempt {attempts}")))
+        })
+        .unwrap_err();
+        assert_eq!(attempts, 3);
+        assert_eq!(err.to_string(), "attempt 3");
+
+        Ok(())
+    }
+
     #[test]
     fn test_clean_readonly_target() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1325,6 +3852,99 @@ edition = "2021"
         Ok(())
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_command_timeout_kills_child() {
+        let cleaner = ProjectCleaner::default();
+        let mut cmd = Command::new("sleep");
+        cmd.arg("5").stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        let start = Instant::now();
+        let err = cleaner
+            .run_command_with_timeout_and_cancel(
+                cmd,
+                Some(Duration::from_millis(100)),
+                None,
+                |_| {},
+            )
+            .unwrap_err();
+        assert!(err.is::<CleanTimedOut>());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_delete_timeout_aborts_walk() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project = create_test_project_with_target(temp_dir.path(), "slow_project")?;
+        fs::create_dir_all(project.target_path().join("debug"))?;
+        fs::write(project.target_path().join("debug").join("a"), "a")?;
+
+        for parallel in [false, true] {
+            let cleaner = ProjectCleaner::new(CleanConfig {
+                parallel,
+                ..Default::default()
+            });
+            let cancel = AtomicBool::new(false);
+            let err = cleaner
+                .delete_directory_tree_with_progress(
+                    &project,
+                    &project.target_path(),
+                    Some(&cancel),
+                    Some(Duration::ZERO),
+                    &|_| {},
+                )
+                .unwrap_err();
+            assert!(err.is::<CleanTimedOut>());
+        }
+        assert!(project.target_path().exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clean_projects_records_timed_out_project() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let slow = create_test_project_with_target(temp_dir.path(), "slow_project")?;
+        for i in 0..10 {
+            fs::write(slow.target_path().join(format!("artifact{i}")), "x")?;
+        }
+        let fast = create_test_project_with_target(temp_dir.path(), "fast_project")?;
+        let projects = vec![slow.clone(), fast.clone()];
+
+        // 第一次汇报进度时卡住超过超时时间，下一个文件前发现已超时
+        let stalled = AtomicBool::new(false);
+        let cleaner = ProjectCleaner::new(CleanConfig {
+            strategy: CleanStrategy::DirectDelete,
+            parallel: false,
+            timeout_seconds: 1,
+            ..Default::default()
+        });
+        let result = cleaner.clean_projects_with_progress(&projects, None, |event| {
+            if let BatchCleanEvent::Progress(progress) = event
+                && progress.phase == CleanPhase::Cleaning
+                && progress.files_processed > 0
+                && !stalled.swap(true, Ordering::Relaxed)
+            {
+                std::thread::sleep(Duration::from_millis(1100));
+            }
+        });
+
+        // 超时的项目出现在失败列表中，附带路径和错误，其余项目照常清理
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(
+            result.failed_projects,
+            vec![slow.path.to_string_lossy().to_string()]
+        );
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].project_name, "slow_project");
+        assert_eq!(result.failures[0].project_path, slow.path);
+        assert!(result.failures[0].error.contains("timed out"));
+        assert!(slow.target_path().exists());
+        assert!(!fast.target_path().exists());
+
+        Ok(())
+    }
+
     #[test]
     fn test_clean_projects_with_mixed_results() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1350,8 +3970,15 @@ edition = "2021"
             name: "bad_project".to_string(),
             target_size: 0, // 没有target目录
             last_modified: std::time::SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: false, // 关键：没有target目录
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         let projects = vec![good_project, bad_project];
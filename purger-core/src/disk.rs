@@ -0,0 +1,95 @@
+use crate::RustProject;
+use std::path::Path;
+use tracing::debug;
+
+/// 获取 `path` 所在文件系统的可用空间（字节）
+///
+/// `path` 不存在时会向上查找最近的已存在祖先目录；无法获取时返回 `None`。
+pub fn available_space(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|p| p.exists())?;
+    match fs2::available_space(existing) {
+        Ok(space) => Some(space),
+        Err(err) => {
+            debug!("无法获取可用空间 {:?}: {}", existing, err);
+            None
+        }
+    }
+}
+
+/// 获取 `path` 所在文件系统的总空间（字节）
+pub fn total_space(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|p| p.exists())?;
+    fs2::total_space(existing).ok()
+}
+
+/// 按 target 所在文件系统的可用空间拆分项目
+///
+/// 返回 `(清理有帮助的项目, 因可用空间充足而跳过的项目及其可用空间)`。
+/// 可用空间超过 `threshold` 的文件系统视为无压力；无法获取可用空间的项目保守地视为需要清理。
+pub fn partition_by_free_space(
+    projects: Vec<RustProject>,
+    threshold: u64,
+) -> (Vec<RustProject>, Vec<(RustProject, u64)>) {
+    let mut helpful = Vec::new();
+    let mut skipped = Vec::new();
+
+    for project in projects {
+        match available_space(&project.target_path()) {
+            Some(free) if free > threshold => skipped.push((project, free)),
+            _ => helpful.push(project),
+        }
+    }
+
+    (helpful, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+    use tempfile::TempDir;
+
+    fn project_at(path: &Path) -> RustProject {
+        RustProject {
+            path: path.to_path_buf(),
+            name: "test".to_string(),
+            target_size: 1024,
+            last_modified: SystemTime::now(),
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
+            has_target: true,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn test_available_space_missing_path_uses_ancestor() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("no").join("such").join("target");
+
+        assert!(available_space(&missing).is_some());
+        assert!(total_space(&missing).is_some());
+    }
+
+    #[test]
+    fn test_partition_by_free_space() {
+        let temp_dir = TempDir::new().unwrap();
+        let projects = vec![project_at(temp_dir.path())];
+
+        // 阈值极大：任何文件系统都处于"压力"下，清理有帮助
+        let (helpful, skipped) = partition_by_free_space(projects.clone(), u64::MAX);
+        assert_eq!(helpful.len(), 1);
+        assert!(skipped.is_empty());
+
+        // 阈值为 0：只要有可用空间就视为充足，跳过
+        let (helpful, skipped) = partition_by_free_space(projects, 0);
+        assert!(helpful.is_empty());
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].1 > 0);
+    }
+}
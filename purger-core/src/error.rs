@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cleaner::{CleanCancelled, CleanTimedOut};
+
+/// 扫描/清理公开接口返回的错误类型，便于库的使用方按失败类型分别处理
+#[derive(Debug, thiserror::Error)]
+pub enum PurgerError {
+    /// 路径不存在
+    #[error("path not found: {0:?}")]
+    PathNotFound(PathBuf),
+    /// 路径不是目录
+    #[error("not a directory: {0:?}")]
+    NotADirectory(PathBuf),
+    /// 目录下没有 `Cargo.toml`
+    #[error("not a Rust project (no Cargo.toml): {0:?}")]
+    NotARustProject(PathBuf),
+    /// 找不到 `cargo` 命令
+    #[error("cargo is not available")]
+    CargoNotAvailable,
+    /// 操作被取消
+    #[error("operation cancelled")]
+    Cancelled,
+    /// 操作超时
+    #[error("operation timed out after {0:?}")]
+    Timeout(Duration),
+    /// 清理单个项目失败
+    #[error("{source}")]
+    CleanFailed {
+        project: String,
+        #[source]
+        source: anyhow::Error,
+    },
+    /// 其他错误
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+/// 以 [`PurgerError`] 作为错误类型的 `Result`
+pub type PurgerResult<T> = std::result::Result<T, PurgerError>;
+
+impl PurgerError {
+    /// 将清理单个项目时的错误归类：取消、超时等保留专门的变体，其余记为 [`PurgerError::CleanFailed`]
+    pub(crate) fn clean_failed(project: &str, err: anyhow::Error) -> Self {
+        match Self::from(err) {
+            Self::Other(source) => Self::CleanFailed {
+                project: project.to_string(),
+                source,
+            },
+            other => other,
+        }
+    }
+
+    /// 在包装的底层错误中查找具体的错误类型（如 [`crate::cleaner::TargetChanged`]）
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+    {
+        match self {
+            Self::CleanFailed { source, .. } | Self::Other(source) => source.downcast_ref::<E>(),
+            _ => None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for PurgerError {
+    fn from(err: anyhow::Error) -> Self {
+        let err = match err.downcast::<PurgerError>() {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
+        if err.is::<CleanCancelled>() {
+            return Self::Cancelled;
+        }
+        if let Some(timed_out) = err.downcast_ref::<CleanTimedOut>() {
+            return Self::Timeout(timed_out.timeout);
+        }
+        Self::Other(err)
+    }
+}
@@ -17,7 +17,7 @@ impl ProjectFilter {
         Self { config }
     }
 
-    /// 过滤项目列表
+    /// 过滤项目列表，返回可以清理的项目（去掉最近编译过而需要保留的项目等）
     pub fn filter_projects(&self, projects: Vec<RustProject>) -> Vec<RustProject> {
         let original_count = projects.len();
 
@@ -53,6 +53,12 @@ impl ProjectFilter {
             return false;
         }
 
+        // 检查最小清理大小
+        if !self.check_min_size_filter(project) {
+            debug!("项目 {} 被最小清理大小过滤器排除", project.name);
+            return false;
+        }
+
         // 检查路径过滤
         if !self.check_path_filter(project) {
             debug!("项目 {} 被路径过滤器排除", project.name);
@@ -62,39 +68,38 @@ impl ProjectFilter {
         true
     }
 
-    /// 检查时间过滤条件
+    /// 检查时间过滤条件：超过保留时长未编译的项目才可以清理
     fn check_time_filter(&self, project: &RustProject) -> bool {
-        if let Some(keep_days) = self.config.keep_days {
+        if let Some(threshold) = self.config.keep_threshold() {
             if !project.has_target {
                 // 没有target目录的项目总是保留（因为没有编译时间）
                 return true;
             }
 
             let now = SystemTime::now();
-            let threshold = Duration::from_secs(keep_days as u64 * 24 * 60 * 60);
 
             match now.duration_since(project.last_modified) {
                 Ok(elapsed) => {
                     if elapsed < threshold {
-                        // 最近编译过，保留
+                        // 最近编译过，保留（不清理）
                         debug!(
-                            "项目 {} 在最近 {} 天内编译过，保留",
-                            project.name, keep_days
+                            "项目 {} 在最近 {:?} 内编译过，保留",
+                            project.name, threshold
                         );
-                        return true;
+                        return false;
                     } else {
                         // 很久没编译，可以清理
                         debug!(
-                            "项目 {} 超过 {} 天未编译，可以清理",
-                            project.name, keep_days
+                            "项目 {} 超过 {:?} 未编译，可以清理",
+                            project.name, threshold
                         );
-                        return false;
+                        return true;
                     }
                 }
                 Err(_) => {
-                    // 时间计算错误，保守起见保留
+                    // 修改时间在未来等时间计算错误，保守起见保留（不清理）
                     debug!("项目 {} 时间计算错误，保留", project.name);
-                    return true;
+                    return false;
                 }
             }
         }
@@ -106,7 +111,7 @@ impl ProjectFilter {
     /// 检查大小过滤条件
     fn check_size_filter(&self, project: &RustProject) -> bool {
         if let Some(keep_size) = self.config.keep_size {
-            let target_size = project.get_target_size();
+            let target_size = project.get_target_size_with(self.config.size_mode);
             if target_size < keep_size {
                 // target目录太小，保留
                 debug!(
@@ -132,25 +137,38 @@ impl ProjectFilter {
         true
     }
 
-    /// 检查路径过滤条件
-    fn check_path_filter(&self, project: &RustProject) -> bool {
-        if self.config.ignore_paths.is_empty() {
-            // 没有忽略路径，保留
-            return true;
+    /// 检查最小清理大小（target 小于下限的项目不清理）
+    fn check_min_size_filter(&self, project: &RustProject) -> bool {
+        if let Some(min_size) = self.config.min_clean_size {
+            let target_size = project.get_target_size_with(self.config.size_mode);
+            if target_size < min_size {
+                debug!(
+                    "项目 {} target目录大小 {} 小于最小清理大小 {}，跳过",
+                    project.name,
+                    crate::format_bytes(target_size),
+                    crate::format_bytes(min_size)
+                );
+                return false;
+            }
         }
 
+        true
+    }
+
+    /// 检查路径过滤条件：位于忽略路径中的项目不清理
+    fn check_path_filter(&self, project: &RustProject) -> bool {
         for ignore_path in &self.config.ignore_paths {
             if self.is_path_ignored(&project.path, ignore_path) {
                 debug!(
-                    "项目 {} 在忽略路径 {:?} 中，保留",
+                    "项目 {} 在忽略路径 {:?} 中，保留（不清理）",
                     project.name, ignore_path
                 );
-                return true;
+                return false;
             }
         }
 
         // 不在任何忽略路径中，可以清理
-        false
+        true
     }
 
     /// 检查路径是否被忽略
@@ -167,18 +185,22 @@ impl ProjectFilter {
         project_canonical.starts_with(&ignore_canonical)
     }
 
-    /// 解析大小字符串（如 "10MB", "1GB", "500KB"）
+    /// 解析大小字符串（如 "10MB", "1.5 GB", "500KB"），数字与单位之间允许空格
     pub fn parse_size_string(size_str: &str) -> Result<u64> {
         let size_str = size_str.trim().to_uppercase();
 
         // 提取数字部分和单位部分
         let (number_part, unit_part) = if let Some(pos) = size_str.find(|c: char| c.is_alphabetic())
         {
-            (&size_str[..pos], &size_str[pos..])
+            (size_str[..pos].trim_end(), &size_str[pos..])
         } else {
             (size_str.as_str(), "")
         };
 
+        // 只接受非负的十进制数（拒绝 "-1MB"、"1.2.3MB"、"+1" 等）
+        if number_part.is_empty() || !number_part.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return Err(anyhow::anyhow!("无效的大小: {}", size_str));
+        }
         let number: f64 = number_part
             .parse()
             .map_err(|_| anyhow::anyhow!("无效的数字: {}", number_part))?;
@@ -198,6 +220,41 @@ impl ProjectFilter {
 
         Ok((number * multiplier as f64) as u64)
     }
+
+    /// 解析时间长度字符串（如 "36h", "90d", "2w", "6mo", "1y"），月按 30 天、年按 365 天计算
+    pub fn parse_duration_string(duration_str: &str) -> Result<Duration> {
+        let duration_str = duration_str.trim().to_lowercase();
+
+        let (number_part, unit_part) = match duration_str.find(|c: char| c.is_alphabetic()) {
+            Some(pos) => (duration_str[..pos].trim_end(), &duration_str[pos..]),
+            None => (duration_str.as_str(), ""),
+        };
+
+        if number_part.is_empty() || !number_part.chars().all(|c| c.is_ascii_digit()) {
+            return Err(anyhow::anyhow!("无效的时间长度: {}", duration_str));
+        }
+        let number: u64 = number_part
+            .parse()
+            .map_err(|_| anyhow::anyhow!("无效的数字: {}", number_part))?;
+
+        const DAY: u64 = 24 * 60 * 60;
+        let multiplier = match unit_part {
+            "s" => 1,
+            "m" | "min" => 60,
+            "h" => 60 * 60,
+            "d" => DAY,
+            "w" => 7 * DAY,
+            "mo" => 30 * DAY,
+            "y" => 365 * DAY,
+            "" => return Err(anyhow::anyhow!("缺少时间单位: {}", duration_str)),
+            _ => return Err(anyhow::anyhow!("不支持的时间单位: {}", unit_part)),
+        };
+
+        number
+            .checked_mul(multiplier)
+            .map(Duration::from_secs)
+            .ok_or_else(|| anyhow::anyhow!("时间长度过大: {}", duration_str))
+    }
 }
 
 #[cfg(test)]
@@ -217,8 +274,15 @@ mod tests {
             name: name.to_string(),
             target_size,
             last_modified,
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: target_size > 0,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         }
     }
 
@@ -236,9 +300,10 @@ mod tests {
             create_test_project("old", 1000, 10),   // 10天前
         ];
 
+        // 7 天内编译过的项目被保留，只选出很久没编译的项目
         let filtered = filter.filter_projects(projects);
         assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "recent");
+        assert_eq!(filtered[0].name, "old");
     }
 
     #[test]
@@ -260,6 +325,52 @@ mod tests {
         assert_eq!(filtered[0].name, "small");
     }
 
+    #[test]
+    fn test_min_size_filter() {
+        let names = |config: ScanConfig| -> Vec<String> {
+            let projects = vec![
+                create_test_project("small", 100, 1),
+                create_test_project("medium", 1000, 1),
+                create_test_project("large", 10_000, 1),
+            ];
+            ProjectFilter::new(config)
+                .filter_projects(projects)
+                .into_iter()
+                .map(|p| p.name)
+                .collect()
+        };
+
+        // 只设置下限
+        let only_min = names(ScanConfig {
+            min_clean_size: Some(1000),
+            ..Default::default()
+        });
+        assert_eq!(only_min, vec!["medium", "large"]);
+
+        // 只设置 keep_size
+        let only_keep = names(ScanConfig {
+            keep_size: Some(5000),
+            ..Default::default()
+        });
+        assert_eq!(only_keep, vec!["small", "medium"]);
+
+        // 同时设置时两者都需满足
+        let both = names(ScanConfig {
+            min_clean_size: Some(500),
+            keep_size: Some(5000),
+            ..Default::default()
+        });
+        assert_eq!(both, vec!["medium"]);
+
+        // 区间为空时不保留任何项目
+        let disjoint = names(ScanConfig {
+            min_clean_size: Some(5000),
+            keep_size: Some(500),
+            ..Default::default()
+        });
+        assert!(disjoint.is_empty());
+    }
+
     #[test]
     fn test_parse_size_string() {
         assert_eq!(ProjectFilter::parse_size_string("100").unwrap(), 100);
@@ -278,6 +389,48 @@ mod tests {
         assert!(ProjectFilter::parse_size_string("10XB").is_err());
     }
 
+    #[test]
+    fn test_parse_duration_string() {
+        const DAY: u64 = 24 * 60 * 60;
+        let parse = ProjectFilter::parse_duration_string;
+
+        assert_eq!(parse("2w").unwrap(), Duration::from_secs(14 * DAY));
+        assert_eq!(parse("6mo").unwrap(), Duration::from_secs(180 * DAY));
+        assert_eq!(parse("90d").unwrap(), Duration::from_secs(90 * DAY));
+        assert_eq!(parse("36h").unwrap(), Duration::from_secs(36 * 60 * 60));
+        assert_eq!(parse(" 1 Y ").unwrap(), Duration::from_secs(365 * DAY));
+
+        assert!(parse("invalid").is_err());
+        assert!(parse("10").is_err());
+        assert!(parse("-3d").is_err());
+        assert!(parse("1.5w").is_err());
+        assert!(parse("3x").is_err());
+    }
+
+    #[test]
+    fn test_time_filter_with_keep_duration() {
+        let mut config = ScanConfig {
+            keep_duration: Some(Duration::from_secs(36 * 60 * 60)),
+            ..Default::default()
+        };
+        let projects = vec![
+            create_test_project("today", 1000, 0),
+            create_test_project("last_week", 1000, 7),
+        ];
+
+        let filtered = ProjectFilter::new(config.clone()).filter_projects(projects.clone());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "last_week");
+
+        // 与 keep_days 同时设置时取较长的窗口，两个项目都在窗口内
+        config.keep_days = Some(10);
+        assert!(
+            ProjectFilter::new(config)
+                .filter_projects(projects)
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_filter_projects_combined() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -300,16 +453,30 @@ mod tests {
                 name: "small_project".to_string(),
                 target_size: 100, // 小于500，应该被保留
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: root.join("large_project"),
                 name: "large_project".to_string(),
                 target_size: 1000, // 大于500，不应该被保留
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
         ];
 
@@ -336,22 +503,37 @@ mod tests {
                 name: "normal_project".to_string(),
                 target_size: 1000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: ignored_project_path,
                 name: "ignored_project".to_string(),
                 target_size: 1000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
         ];
 
         let filtered2 = filter2.filter_projects(projects2);
+        // 忽略路径中的项目不清理
         assert_eq!(filtered2.len(), 1);
-        assert_eq!(filtered2[0].name, "ignored_project");
+        assert_eq!(filtered2[0].name, "normal_project");
 
         Ok(())
     }
@@ -367,16 +549,30 @@ mod tests {
                 name: "project1".to_string(),
                 target_size: 1000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: PathBuf::from("/test/project2"),
                 name: "project2".to_string(),
                 target_size: 2000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
         ];
 
@@ -400,8 +596,15 @@ mod tests {
             name: "test_project".to_string(),
             target_size: 1000,
             last_modified: SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: true,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         let config = ScanConfig {
@@ -437,7 +640,30 @@ mod tests {
         // 测试无效输入
         assert!(ProjectFilter::parse_size_string("").is_err());
         assert!(ProjectFilter::parse_size_string("abc").is_err());
-        // 注意：当前实现可能接受负数，这里先不测试负数
-        // assert!(ProjectFilter::parse_size_string("-1MB").is_err());
+        assert!(ProjectFilter::parse_size_string("-1MB").is_err());
+    }
+
+    #[test]
+    fn test_parse_size_whitespace_and_decimals() {
+        assert_eq!(
+            ProjectFilter::parse_size_string("1.5 GB").unwrap(),
+            1_500_000_000
+        );
+        assert_eq!(ProjectFilter::parse_size_string("500 kb").unwrap(), 500_000);
+        assert_eq!(
+            ProjectFilter::parse_size_string(" 2MiB ").unwrap(),
+            2 * 1024 * 1024
+        );
+        assert_eq!(
+            ProjectFilter::parse_size_string("1.5GB").unwrap(),
+            1_500_000_000
+        );
+
+        assert!(ProjectFilter::parse_size_string("-1MB").is_err());
+        assert!(ProjectFilter::parse_size_string("-0.5 GB").is_err());
+        assert!(ProjectFilter::parse_size_string("1.2.3MB").is_err());
+        assert!(ProjectFilter::parse_size_string("1 2MB").is_err());
+        assert!(ProjectFilter::parse_size_string("MB").is_err());
+        assert!(ProjectFilter::parse_size_string("1.5 G B").is_err());
     }
 }
@@ -1,15 +1,33 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+mod cache;
 pub mod cleaner;
+pub mod disk;
+pub mod error;
 pub mod filter;
 pub mod project;
+pub mod repair;
+pub mod report;
+pub mod safety;
 pub mod scanner;
+pub mod sort;
+pub mod workspace;
 
-pub use cleaner::{CleanPhase, CleanProgress, CleanStrategy, DirectDeleteBackend, ProjectCleaner};
+pub use cleaner::{
+    BatchCleanEvent, ChangedPolicy, CleanPhase, CleanProgress, CleanStrategy, DirectDeleteBackend,
+    ExecutableFilter, ProjectCleaner, estimate_remaining,
+};
+pub use error::{PurgerError, PurgerResult};
 pub use filter::ProjectFilter;
-pub use project::RustProject;
+pub use project::{
+    KEEP_MARKER, LargestFile, RustProject, SizeMode, TargetBreakdownEntry, TimeBasis,
+    resolve_target_dir,
+};
+pub use report::{ReclaimEstimate, estimate_reclaimable};
+pub use safety::is_dangerous_root;
 pub use scanner::ProjectScanner;
+pub use sort::{SortKey, compare_projects, sort_projects};
 
 /// 清理结果统计
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +37,16 @@ pub struct CleanFailure {
     pub error: String,
 }
 
+/// 单个项目的清理结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectCleanOutcome {
+    pub name: String,
+    pub path: PathBuf,
+    pub bytes_freed: u64,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CleanResult {
     pub cleaned_projects: usize,
@@ -26,6 +54,9 @@ pub struct CleanResult {
     pub failed_projects: Vec<String>,
     #[serde(default)]
     pub failures: Vec<CleanFailure>,
+    /// 按输入顺序排列的逐项目结果
+    #[serde(default)]
+    pub outcomes: Vec<ProjectCleanOutcome>,
     pub duration_ms: u64,
 }
 
@@ -42,6 +73,7 @@ impl CleanResult {
             total_size_freed: 0,
             failed_projects: Vec::new(),
             failures: Vec::new(),
+            outcomes: Vec::new(),
             duration_ms: 0,
         }
     }
@@ -61,41 +93,116 @@ impl CleanResult {
         self.failures.push(failure);
     }
 
+    /// 记录单个项目的结果，同时更新汇总字段
+    pub fn add_outcome(&mut self, outcome: ProjectCleanOutcome) {
+        match &outcome.error {
+            None => self.add_success(outcome.bytes_freed),
+            Some(error) => self.add_failure_detail(CleanFailure {
+                project_name: outcome.name.clone(),
+                project_path: outcome.path.clone(),
+                error: error.clone(),
+            }),
+        }
+        self.outcomes.push(outcome);
+    }
+
+    /// 合并另一次运行的结果（多根目录清理、断点续清等场景）
+    pub fn merge(&mut self, other: CleanResult) {
+        self.cleaned_projects += other.cleaned_projects;
+        self.total_size_freed += other.total_size_freed;
+        self.duration_ms += other.duration_ms;
+        self.failed_projects.extend(other.failed_projects);
+        self.failures.extend(other.failures);
+        self.outcomes.extend(other.outcomes);
+    }
+
     pub fn format_size(&self) -> String {
         format_bytes(self.total_size_freed)
     }
 }
 
-/// 格式化字节大小为人类可读格式
-pub fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+/// 在限定线程数的 rayon 线程池中执行（`None` 时使用全局默认线程池）
+pub(crate) fn with_thread_pool<R, F>(jobs: Option<usize>, f: F) -> R
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    let Some(jobs) = jobs else {
+        return f();
+    };
+    match rayon::ThreadPoolBuilder::new().num_threads(jobs).build() {
+        Ok(pool) => pool.install(f),
+        Err(e) => {
+            tracing::warn!("创建线程池失败，使用默认线程池: {}", e);
+            f()
+        }
+    }
+}
+
+/// 大小显示的单位制
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SizeUnits {
+    /// 1024 进制（KiB/MiB/GiB），与 `du -h` 一致
+    #[default]
+    Binary,
+    /// 1000 进制（KB/MB/GB）
+    Decimal,
+}
+
+/// 按指定单位制格式化字节大小
+pub fn format_bytes_with(bytes: u64, units: SizeUnits) -> String {
+    let (base, labels): (f64, &[&str]) = match units {
+        SizeUnits::Binary => (1024.0, &["B", "KiB", "MiB", "GiB", "TiB"]),
+        SizeUnits::Decimal => (1000.0, &["B", "KB", "MB", "GB", "TB"]),
+    };
     let mut size = bytes as f64;
     let mut unit_index = 0;
 
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
+    while size >= base && unit_index < labels.len() - 1 {
+        size /= base;
         unit_index += 1;
     }
 
     if unit_index == 0 {
-        format!("{} {}", bytes, UNITS[unit_index])
+        format!("{} {}", bytes, labels[unit_index])
     } else {
-        format!("{:.2} {}", size, UNITS[unit_index])
+        format!("{:.2} {}", size, labels[unit_index])
     }
 }
 
+/// 格式化字节大小为人类可读格式（1024 进制，显示为 KiB/MiB/GiB）
+pub fn format_bytes(bytes: u64) -> String {
+    format_bytes_with(bytes, SizeUnits::default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_bytes_with_units() {
+        assert_eq!(format_bytes_with(1000, SizeUnits::Binary), "1000 B");
+        assert_eq!(format_bytes_with(1023, SizeUnits::Binary), "1023 B");
+        assert_eq!(format_bytes_with(1024, SizeUnits::Binary), "1.00 KiB");
+        assert_eq!(format_bytes_with(1048576, SizeUnits::Binary), "1.00 MiB");
+        assert_eq!(format_bytes_with(999, SizeUnits::Decimal), "999 B");
+        assert_eq!(format_bytes_with(1000, SizeUnits::Decimal), "1.00 KB");
+        assert_eq!(format_bytes_with(1024, SizeUnits::Decimal), "1.02 KB");
+        assert_eq!(
+            format_bytes_with(1_500_000_000, SizeUnits::Decimal),
+            "1.50 GB"
+        );
+    }
+
     #[test]
     fn test_format_bytes() {
         assert_eq!(format_bytes(0), "0 B");
         assert_eq!(format_bytes(1023), "1023 B");
-        assert_eq!(format_bytes(1024), "1.00 KB");
-        assert_eq!(format_bytes(1536), "1.50 KB");
-        assert_eq!(format_bytes(1048576), "1.00 MB");
-        assert_eq!(format_bytes(1073741824), "1.00 GB");
+        assert_eq!(format_bytes(1000), "1000 B");
+        assert_eq!(format_bytes(1024), "1.00 KiB");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(1048576), "1.00 MiB");
+        assert_eq!(format_bytes(1073741824), "1.00 GiB");
     }
 
     #[test]
@@ -111,4 +218,55 @@ mod tests {
         result.add_failure("test_project".to_string());
         assert_eq!(result.failed_projects.len(), 1);
     }
+
+    #[test]
+    fn test_clean_result_merge() {
+        let mut first = CleanResult::new();
+        first.add_success(1024);
+        first.add_failure("a".to_string());
+        first.duration_ms = 100;
+
+        let mut second = CleanResult::new();
+        second.add_success(2048);
+        second.add_success(10);
+        second.add_failure_detail(CleanFailure {
+            project_name: "b".to_string(),
+            project_path: PathBuf::from("/tmp/b"),
+            error: "boom".to_string(),
+        });
+        second.duration_ms = 50;
+
+        first.merge(second);
+        assert_eq!(first.cleaned_projects, 3);
+        assert_eq!(first.total_size_freed, 1024 + 2048 + 10);
+        assert_eq!(first.duration_ms, 150);
+        assert_eq!(first.failed_projects.len(), 2);
+        assert_eq!(first.failures.len(), 1);
+        assert_eq!(first.failures[0].project_name, "b");
+    }
+
+    #[test]
+    fn test_clean_result_add_outcome() {
+        let mut result = CleanResult::new();
+        result.add_outcome(ProjectCleanOutcome {
+            name: "a".to_string(),
+            path: PathBuf::from("/tmp/a"),
+            bytes_freed: 100,
+            duration_ms: 5,
+            error: None,
+        });
+        result.add_outcome(ProjectCleanOutcome {
+            name: "b".to_string(),
+            path: PathBuf::from("/tmp/b"),
+            bytes_freed: 0,
+            duration_ms: 1,
+            error: Some("boom".to_string()),
+        });
+
+        assert_eq!(result.cleaned_projects, 1);
+        assert_eq!(result.total_size_freed, 100);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.outcomes.len(), 2);
+        assert_eq!(result.outcomes[1].error.as_deref(), Some("boom"));
+    }
 }
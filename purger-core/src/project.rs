@@ -1,11 +1,21 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::time::SystemTime;
 use tracing::debug;
 use walkdir::WalkDir;
 
+/// 从 `Cargo.toml` 中解析出的项目信息
+#[derive(Debug, Default)]
+struct ManifestInfo {
+    name: String,
+    is_workspace_root: bool,
+    is_package: bool,
+    is_workspace_member: bool,
+    members: Vec<String>,
+}
+
 /// Rust project metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RustProject {
@@ -13,8 +23,99 @@ pub struct RustProject {
     pub name: String,
     pub target_size: u64,
     pub last_modified: SystemTime,
-    pub is_workspace: bool,
+    /// 清单中有 `[workspace]`，即 workspace 根（可以同时是一个包）
+    #[serde(alias = "is_workspace")]
+    pub is_workspace_root: bool,
+    /// 清单中有 `[package]`
+    #[serde(default)]
+    pub is_package: bool,
+    /// 是上层目录中某个 workspace 的成员包
+    #[serde(default)]
+    pub is_workspace_member: bool,
     pub has_target: bool,
+    /// target 中最大的单个文件（计算大小时顺带统计）
+    #[serde(default)]
+    pub largest_file: Option<LargestFile>,
+    /// 通过 `CARGO_TARGET_DIR` 或 `.cargo/config.toml` 重定向的 target 目录，`None` 表示 `<path>/target`
+    #[serde(default)]
+    pub target_dir: Option<PathBuf>,
+    /// workspace 成员包名：来自 `[workspace].members`（展开 glob），扫描时共享 target 的子项目也合并进来
+    #[serde(default)]
+    pub members: Vec<String>,
+    /// 额外的构建输出目录（见 `ScanConfig::extra_target_names`），大小计入 `target_size`，清理时一并删除
+    #[serde(default)]
+    pub extra_targets: Vec<PathBuf>,
+    /// 项目目录中有 [`KEEP_MARKER`] 文件，清理时跳过（除非 `force`）
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// 标记文件：项目目录中存在时该项目不会被清理
+pub const KEEP_MARKER: &str = ".purger-keep";
+
+/// `last_modified` 的计算依据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeBasis {
+    /// target 目录本身的修改时间（默认，开销最小）
+    #[default]
+    DirMtime,
+    /// target 中最新文件的修改时间（需要遍历 target，更能反映最近是否用过）
+    NewestFileMtime,
+}
+
+/// target 大小的统计方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SizeMode {
+    /// 文件长度之和（默认，各平台结果一致）
+    #[default]
+    Apparent,
+    /// 实际占用的磁盘块（Unix 上按 `blocks() * 512` 计算，小文件向上取整到块大小、稀疏文件按实际分配计；
+    /// 其他平台等同于 `Apparent`），更接近清理后真正释放的空间
+    OnDisk,
+}
+
+impl SizeMode {
+    /// 按当前方式计算单个文件的大小
+    fn file_size(self, metadata: &fs::Metadata) -> u64 {
+        match self {
+            SizeMode::Apparent => metadata.len(),
+            #[cfg(unix)]
+            SizeMode::OnDisk => {
+                use std::os::unix::fs::MetadataExt;
+                metadata.blocks() * 512
+            }
+            #[cfg(not(unix))]
+            SizeMode::OnDisk => metadata.len(),
+        }
+    }
+}
+
+/// target 目录中最大的单个文件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LargestFile {
+    /// 相对于 target 目录的路径
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// target 中单个 `(triple, profile)` 输出目录的大小
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetBreakdownEntry {
+    /// 交叉编译的目标三元组，宿主机构建（`target/<profile>`）时为 `None`
+    pub triple: Option<String>,
+    pub profile: String,
+    pub size: u64,
+}
+
+impl std::fmt::Display for LargestFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.path.display(),
+            crate::format_bytes(self.size)
+        )
+    }
 }
 
 impl RustProject {
@@ -28,7 +129,41 @@ impl RustProject {
         Self::from_path_impl(path, true)
     }
 
+    /// Create a `RustProject`, counting each hard-linked file only once (Unix only)
+    pub fn from_path_dedup_hardlinks<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_path_with_options(path, false, true, TimeBasis::DirMtime, SizeMode::Apparent)
+    }
+
+    /// Create a `RustProject`, taking `last_modified` according to `time_basis`
+    pub fn from_path_with_time_basis<P: AsRef<Path>>(
+        path: P,
+        time_basis: TimeBasis,
+    ) -> Result<Self> {
+        Self::from_path_with_options(path, false, false, time_basis, SizeMode::Apparent)
+    }
+
+    /// Create a `RustProject`, measuring target size according to `size_mode`
+    pub fn from_path_with_size_mode<P: AsRef<Path>>(path: P, size_mode: SizeMode) -> Result<Self> {
+        Self::from_path_with_options(path, false, false, TimeBasis::DirMtime, size_mode)
+    }
+
     fn from_path_impl<P: AsRef<Path>>(path: P, lazy_size: bool) -> Result<Self> {
+        Self::from_path_with_options(
+            path,
+            lazy_size,
+            false,
+            TimeBasis::DirMtime,
+            SizeMode::Apparent,
+        )
+    }
+
+    pub(crate) fn from_path_with_options<P: AsRef<Path>>(
+        path: P,
+        lazy_size: bool,
+        dedup_hardlinks: bool,
+        time_basis: TimeBasis,
+        size_mode: SizeMode,
+    ) -> Result<Self> {
         let path = path.as_ref().to_path_buf();
         let cargo_toml_path = path.join("Cargo.toml");
 
@@ -37,61 +172,315 @@ impl RustProject {
         }
 
         // 一次性读取和解析 TOML，避免重复 IO
-        let (name, is_workspace) = match Self::parse_cargo_toml(&cargo_toml_path, &path) {
-            Ok(result) => result,
+        let manifest = match Self::parse_cargo_toml(&cargo_toml_path, &path) {
+            Ok(manifest) => manifest,
             Err(err) => {
                 debug!(
                     "Failed to parse Cargo.toml at {:?}: {}",
                     cargo_toml_path, err
                 );
-                (Self::fallback_project_name(&path), false)
+                ManifestInfo {
+                    name: Self::fallback_project_name(&path),
+                    ..Default::default()
+                }
             }
         };
-        let target_path = path.join("target");
+        let target_path = resolve_target_dir(&path);
+        let target_dir = (target_path != path.join("target")).then(|| target_path.clone());
         let has_target = target_path.exists();
+        let pinned = path.join(KEEP_MARKER).is_file();
 
-        let (target_size, last_modified) = if has_target {
+        let (target_size, last_modified, largest_file) = if has_target {
             let modified = fs::metadata(&target_path)
                 .context("Failed to get target directory metadata")?
                 .modified()
                 .context("Failed to get target directory modification time")?;
-            let size = if lazy_size {
-                0
+            let modified = match time_basis {
+                TimeBasis::DirMtime => modified,
+                TimeBasis::NewestFileMtime => Self::newest_mtime(&target_path).max(modified),
+            };
+            let (size, largest_file) = if lazy_size {
+                (0, None)
             } else {
-                Self::calculate_directory_size_fast(&target_path).unwrap_or(0)
+                Self::calculate_directory_stats(&target_path, dedup_hardlinks, size_mode)
             };
-            (size, modified)
+            (size, modified, largest_file)
         } else {
-            (0, SystemTime::UNIX_EPOCH)
+            (0, SystemTime::UNIX_EPOCH, None)
         };
 
         Ok(RustProject {
             path,
-            name,
+            name: manifest.name,
             target_size,
             last_modified,
-            is_workspace,
+            is_workspace_root: manifest.is_workspace_root,
+            is_package: manifest.is_package,
+            is_workspace_member: manifest.is_workspace_member,
             has_target,
+            largest_file,
+            target_dir,
+            members: manifest.members,
+            extra_targets: Vec::new(),
+            pinned,
         })
     }
 
+    /// 把项目目录下名为 `names` 之一的目录记为额外的构建输出，并把它们的大小计入 `target_size`
+    ///
+    /// 名称是相对项目目录的路径（如 `target-wasm`、`dist/target`），含 `..` 的名称和符号链接会被忽略；
+    /// 与孤立 target 一样，只接受带 cargo 标记文件（`CACHEDIR.TAG` 或 `.rustc_info.json`）的目录。
+    pub(crate) fn add_extra_targets(
+        &mut self,
+        names: &[String],
+        lazy_size: bool,
+        dedup_hardlinks: bool,
+        size_mode: SizeMode,
+    ) {
+        let main_target = self.target_path();
+        for name in names {
+            let relative = Path::new(name);
+            if relative.as_os_str().is_empty()
+                || !relative
+                    .components()
+                    .all(|component| matches!(component, Component::Normal(_)))
+            {
+                debug!("忽略无效的额外 target 名称: {:?}", name);
+                continue;
+            }
+
+            let dir = self.path.join(relative);
+            let Ok(metadata) = fs::symlink_metadata(&dir) else {
+                continue;
+            };
+            if !metadata.is_dir() || dir == main_target || self.extra_targets.contains(&dir) {
+                continue;
+            }
+            if !crate::cleaner::is_cargo_target_dir(&dir) {
+                debug!("{:?} 不是 cargo 生成的目录，不作为额外 target", dir);
+                continue;
+            }
+
+            debug!("发现额外的构建输出目录: {:?}", dir);
+            if !lazy_size {
+                self.target_size +=
+                    Self::calculate_directory_stats(&dir, dedup_hardlinks, size_mode).0;
+            }
+            if let Ok(modified) = metadata.modified() {
+                self.last_modified = self.last_modified.max(modified);
+            }
+            self.has_target = true;
+            self.extra_targets.push(dir);
+        }
+    }
+
     /// Parse Cargo.toml once to extract package name and workspace info
-    fn parse_cargo_toml(cargo_toml_path: &Path, project_path: &Path) -> Result<(String, bool)> {
+    fn parse_cargo_toml(cargo_toml_path: &Path, project_path: &Path) -> Result<ManifestInfo> {
         let content = fs::read_to_string(cargo_toml_path).context("Failed to read Cargo.toml")?;
         let parsed: toml::Value = toml::from_str(&content).context("Failed to parse Cargo.toml")?;
 
         // 提取项目名称
-        let name = parsed
-            .get("package")
+        let package = parsed.get("package");
+        let name = package
             .and_then(|p| p.get("name"))
             .and_then(|n| n.as_str())
             .map(|s| s.to_string())
             .unwrap_or_else(|| Self::fallback_project_name(project_path));
 
         // 检查是否为workspace项目
-        let is_workspace = parsed.get("workspace").is_some();
+        let workspace = parsed.get("workspace");
+        let members = workspace
+            .map(|workspace| Self::workspace_members(workspace, project_path))
+            .unwrap_or_default();
+
+        // workspace 根自身的包不算作上层 workspace 的成员
+        let is_workspace_member = package.is_some()
+            && workspace.is_none()
+            && Self::parent_workspace_root(project_path, package).is_some();
+
+        Ok(ManifestInfo {
+            name,
+            is_workspace_root: workspace.is_some(),
+            is_package: package.is_some(),
+            is_workspace_member,
+            members,
+        })
+    }
+
+    /// 查找包所属的上层 workspace 根目录：`package.workspace` 显式指定时使用该目录，
+    /// 否则与 cargo 一样取最近的含 `[workspace]` 的祖先目录，再检查是否在其成员列表中
+    fn parent_workspace_root(
+        project_path: &Path,
+        package: Option<&toml::Value>,
+    ) -> Option<PathBuf> {
+        let explicit_root = package
+            .and_then(|p| p.get("workspace"))
+            .and_then(|w| w.as_str())
+            .map(|root| project_path.join(root));
+
+        let load_workspace = |root: &Path| -> Option<toml::Value> {
+            let content = fs::read_to_string(root.join("Cargo.toml")).ok()?;
+            let parsed: toml::Value = toml::from_str(&content).ok()?;
+            parsed.get("workspace").cloned()
+        };
+        let found = match explicit_root {
+            Some(root) => load_workspace(&root).map(|workspace| (root, workspace)),
+            None => project_path
+                .ancestors()
+                .skip(1)
+                .filter(|dir| dir.join("Cargo.toml").is_file())
+                .find_map(|dir| {
+                    load_workspace(dir).map(|workspace| (dir.to_path_buf(), workspace))
+                }),
+        };
+
+        found
+            .filter(|(root, workspace)| {
+                Self::workspace_member_dirs(workspace, root)
+                    .iter()
+                    .any(|dir| dir == project_path)
+            })
+            .map(|(root, _)| root)
+    }
+
+    /// workspace 成员所在的目录，不是 workspace 根时为空
+    pub(crate) fn member_dirs(&self) -> Vec<PathBuf> {
+        fs::read_to_string(self.path.join("Cargo.toml"))
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+            .and_then(|manifest| {
+                let workspace = manifest.get("workspace")?;
+                Some(Self::workspace_member_dirs(workspace, &self.path))
+            })
+            .unwrap_or_default()
+    }
+
+    /// 展开 `[workspace].members`，返回排序后的成员包名
+    fn workspace_members(workspace: &toml::Value, project_path: &Path) -> Vec<String> {
+        let mut members: Vec<String> = Self::workspace_member_dirs(workspace, project_path)
+            .into_iter()
+            .map(|dir| {
+                Self::extract_project_name(&dir.join("Cargo.toml"))
+                    .unwrap_or_else(|| Self::fallback_project_name(&dir))
+            })
+            .collect();
+        members.sort();
+        members.dedup();
+        members
+    }
+
+    /// 展开 `[workspace].members`（支持 `crates/*` 这样的 glob），跳过 `exclude` 中的目录和没有 `Cargo.toml` 的目录
+    fn workspace_member_dirs(workspace: &toml::Value, project_path: &Path) -> Vec<PathBuf> {
+        let string_list = |key: &str| -> Vec<&str> {
+            workspace
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|items| items.iter().filter_map(|item| item.as_str()).collect())
+                .unwrap_or_default()
+        };
+        let excluded: Vec<PathBuf> = string_list("exclude")
+            .into_iter()
+            .map(|dir| project_path.join(dir))
+            .collect();
 
-        Ok((name, is_workspace))
+        string_list("members")
+            .into_iter()
+            .flat_map(|pattern| Self::expand_member_pattern(project_path, pattern))
+            .filter(|dir| *dir != project_path && !excluded.iter().any(|ex| dir.starts_with(ex)))
+            .filter(|dir| dir.join("Cargo.toml").is_file())
+            .collect()
+    }
+
+    /// 按路径分量逐级匹配成员 glob（与 cargo 一致，`*` 不跨越目录）
+    fn expand_member_pattern(project_path: &Path, pattern: &str) -> Vec<PathBuf> {
+        let mut dirs = vec![project_path.to_path_buf()];
+        for component in Path::new(pattern).components() {
+            let Component::Normal(part) = component else {
+                if component == Component::ParentDir {
+                    dirs = dirs.into_iter().map(|dir| dir.join("..")).collect();
+                }
+                continue;
+            };
+            let part = part.to_string_lossy();
+            if !part.contains(['*', '?', '[']) {
+                dirs = dirs
+                    .into_iter()
+                    .map(|dir| dir.join(part.as_ref()))
+                    .collect();
+                continue;
+            }
+
+            let matcher = match globset::Glob::new(&part) {
+                Ok(glob) => glob.compile_matcher(),
+                Err(err) => {
+                    debug!("无效的 workspace 成员 glob {:?}: {}", pattern, err);
+                    return Vec::new();
+                }
+            };
+            let mut matched: Vec<PathBuf> = dirs
+                .iter()
+                .filter_map(|dir| fs::read_dir(dir).ok())
+                .flatten()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+                .filter(|entry| matcher.is_match(entry.file_name()))
+                .map(|entry| entry.path())
+                .collect();
+            matched.sort();
+            dirs = matched;
+        }
+        dirs
+    }
+
+    /// 是否为孤立的 target：cargo 生成的 `target` 目录，但所在目录没有（或无法解析的）`Cargo.toml`
+    pub(crate) fn is_orphan_target(dir: &Path) -> bool {
+        if dir.file_name() != Some("target".as_ref()) || !crate::cleaner::is_cargo_target_dir(dir) {
+            return false;
+        }
+        let Some(parent) = dir.parent() else {
+            return false;
+        };
+        let manifest = parent.join("Cargo.toml");
+        !manifest.is_file() || Self::parse_cargo_toml(&manifest, parent).is_err()
+    }
+
+    /// 为孤立的 target 构建项目条目：以所在目录为项目路径、目录名为项目名
+    pub(crate) fn from_orphan_target(
+        target: &Path,
+        dedup_hardlinks: bool,
+        time_basis: TimeBasis,
+        size_mode: SizeMode,
+    ) -> Result<Self> {
+        let path = target
+            .parent()
+            .context("target directory has no parent")?
+            .to_path_buf();
+        let modified = fs::metadata(target)
+            .context("Failed to get target directory metadata")?
+            .modified()
+            .context("Failed to get target directory modification time")?;
+        let last_modified = match time_basis {
+            TimeBasis::DirMtime => modified,
+            TimeBasis::NewestFileMtime => Self::newest_mtime(target).max(modified),
+        };
+        let (target_size, largest_file) =
+            Self::calculate_directory_stats(target, dedup_hardlinks, size_mode);
+
+        Ok(RustProject {
+            name: Self::fallback_project_name(&path),
+            pinned: path.join(KEEP_MARKER).is_file(),
+            path,
+            target_size,
+            last_modified,
+            is_workspace_root: false,
+            is_package: false,
+            is_workspace_member: false,
+            has_target: true,
+            largest_file,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+        })
     }
 
     fn fallback_project_name(project_path: &Path) -> String {
@@ -102,15 +491,15 @@ impl RustProject {
             .to_string()
     }
 
-    /// Check whether this is a workspace project (kept for backward compatibility)
-    fn is_workspace_project(cargo_toml_path: &Path) -> Result<bool> {
+    /// Check whether this is a workspace project
+    pub(crate) fn is_workspace_project(cargo_toml_path: &Path) -> Result<bool> {
         let content = fs::read_to_string(cargo_toml_path).context("Failed to read Cargo.toml")?;
         let parsed: toml::Value = toml::from_str(&content).context("Failed to parse Cargo.toml")?;
         Ok(parsed.get("workspace").is_some())
     }
 
-    /// Extract project name from Cargo.toml (kept for backward compatibility)
-    fn extract_project_name(cargo_toml_path: &Path) -> Option<String> {
+    /// Extract project name from Cargo.toml
+    pub(crate) fn extract_project_name(cargo_toml_path: &Path) -> Option<String> {
         let content = fs::read_to_string(cargo_toml_path).ok()?;
         let parsed: toml::Value = toml::from_str(&content).ok()?;
 
@@ -121,42 +510,75 @@ impl RustProject {
             .map(|s| s.to_string())
     }
 
-    /// Calculate directory size
-    fn calculate_directory_size(dir: &Path) -> Result<u64> {
-        let mut total_size = 0u64;
-
-        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
-            if entry.file_type().is_file() {
-                if let Ok(metadata) = entry.metadata() {
-                    total_size += metadata.len();
-                }
-            }
-        }
-
-        Ok(total_size)
+    /// target 中所有文件和目录里最新的修改时间
+    fn newest_mtime(dir: &Path) -> SystemTime {
+        WalkDir::new(dir)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+            .max()
+            .unwrap_or(SystemTime::UNIX_EPOCH)
     }
 
     /// Calculate directory size (parallelized)
-    fn calculate_directory_size_fast(dir: &Path) -> Result<u64> {
+    fn calculate_directory_size_fast(dir: &Path, size_mode: SizeMode) -> Result<u64> {
+        Ok(Self::calculate_directory_stats(dir, false, size_mode).0)
+    }
+
+    /// 并行计算目录总大小，并顺带找出最大的单个文件
+    ///
+    /// 不跟随符号链接，链接本身也不计入，避免重复统计链接目标或在自引用链接上死循环。
+    /// `dedup_hardlinks` 为 true 时，同一 inode 的多个硬链接只计一次大小（仅 Unix 生效，
+    /// 其他平台仍按每个路径累加）；每个文件的大小按 `size_mode` 计算。
+    fn calculate_directory_stats(
+        dir: &Path,
+        dedup_hardlinks: bool,
+        size_mode: SizeMode,
+    ) -> (u64, Option<LargestFile>) {
         use rayon::prelude::*;
-        use std::sync::atomic::{AtomicU64, Ordering};
 
-        // 使用原子计数器避免收集所有条目到 Vec
-        let total_size = AtomicU64::new(0);
+        let seen_inodes = HardlinkTracker::new(dedup_hardlinks);
 
-        // 并行遍历，直接累加大小
-        WalkDir::new(dir)
+        // 每个线程各自累加，最后合并，避免收集所有条目到 Vec
+        let (total, largest) = WalkDir::new(dir)
+            .follow_links(false)
             .into_iter()
             .par_bridge() // 将串行迭代器转换为并行迭代器
             .filter_map(|entry| entry.ok())
+            // 不跟随链接时符号链接条目的 file_type 是 symlink，这里一并跳过
             .filter(|entry| entry.file_type().is_file())
-            .for_each(|entry| {
-                if let Ok(metadata) = entry.metadata() {
-                    total_size.fetch_add(metadata.len(), Ordering::Relaxed);
-                }
-            });
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                let counted = seen_inodes.first_sighting(&metadata);
+                Some((size_mode.file_size(&metadata), counted, entry.into_path()))
+            })
+            .fold(
+                || (0u64, None::<(u64, PathBuf)>),
+                |(total, largest), (len, counted, path)| {
+                    let largest = match largest {
+                        Some((max, _)) if max >= len => largest,
+                        _ => Some((len, path)),
+                    };
+                    (total + if counted { len } else { 0 }, largest)
+                },
+            )
+            .reduce(
+                || (0u64, None),
+                |(total_a, largest_a), (total_b, largest_b)| {
+                    let largest = match (largest_a, largest_b) {
+                        (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+                        (a, b) => a.or(b),
+                    };
+                    (total_a + total_b, largest)
+                },
+            );
 
-        Ok(total_size.into_inner())
+        let largest_file = largest.map(|(size, path)| LargestFile {
+            path: path.strip_prefix(dir).unwrap_or(&path).to_path_buf(),
+            size,
+        });
+        (total, largest_file)
     }
 
     /// Get a human-readable target size string
@@ -165,7 +587,14 @@ impl RustProject {
     }
 
     /// Get target directory size (compute on demand if needed)
+    ///
+    /// 未统计过大小时按 `SizeMode::Apparent` 即时计算，需要其他统计方式时用 [`Self::get_target_size_with`]。
     pub fn get_target_size(&self) -> u64 {
+        self.get_target_size_with(SizeMode::Apparent)
+    }
+
+    /// 同 [`Self::get_target_size`]，未统计过大小时按 `size_mode` 即时计算
+    pub fn get_target_size_with(&self, size_mode: SizeMode) -> u64 {
         if self.target_size > 0 {
             return self.target_size;
         }
@@ -175,8 +604,101 @@ impl RustProject {
         }
 
         // 按需计算大小
+        self.current_target_size(size_mode)
+    }
+
+    /// 按 `size_mode` 重新统计 target（及额外构建输出目录）当前的大小，不使用扫描时记录的 `target_size`
+    ///
+    /// 只累加普通文件，符号链接既不跟随也不计入。
+    pub fn current_target_size(&self, size_mode: SizeMode) -> u64 {
         let target_path = self.target_path();
-        Self::calculate_directory_size_fast(&target_path).unwrap_or(0)
+        std::iter::once(&target_path)
+            .chain(&self.extra_targets)
+            .map(|dir| Self::calculate_directory_size_fast(dir, size_mode).unwrap_or(0))
+            .sum()
+    }
+
+    /// Compute and store the target size (and largest file) for a lazily created project
+    pub fn compute_size(&mut self) -> u64 {
+        self.compute_size_with(false, SizeMode::Apparent)
+    }
+
+    pub(crate) fn compute_size_with(&mut self, dedup_hardlinks: bool, size_mode: SizeMode) -> u64 {
+        if !self.has_target {
+            return 0;
+        }
+
+        let (mut size, largest_file) =
+            Self::calculate_directory_stats(&self.target_path(), dedup_hardlinks, size_mode);
+        for dir in &self.extra_targets {
+            size += Self::calculate_directory_stats(dir, dedup_hardlinks, size_mode).0;
+        }
+        self.target_size = size;
+        self.largest_file = largest_file;
+        size
+    }
+
+    /// 按 `(triple, profile)` 统计 target 中各输出目录的大小（按 `size_mode` 计算）
+    ///
+    /// `target/<profile>` 计为宿主机构建（triple 为 `None`），`target/<triple>/<profile>` 计为交叉编译；
+    /// profile 目录以其中的 `.fingerprint` 识别，`doc`、`tmp` 等其他目录不计入。结果按 triple、profile 排序。
+    pub fn target_breakdown(&self, size_mode: SizeMode) -> Vec<TargetBreakdownEntry> {
+        let is_profile_dir = |dir: &Path| dir.join(".fingerprint").is_dir();
+        let subdirs = |dir: &Path| -> Vec<(String, PathBuf)> {
+            let Ok(entries) = fs::read_dir(dir) else {
+                return Vec::new();
+            };
+            entries
+                .flatten()
+                .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+                .map(|entry| {
+                    (
+                        entry.file_name().to_string_lossy().into_owned(),
+                        entry.path(),
+                    )
+                })
+                .collect()
+        };
+        let entry = |triple: Option<&str>, profile: String, dir: &Path| TargetBreakdownEntry {
+            triple: triple.map(str::to_string),
+            profile,
+            size: Self::calculate_directory_size_fast(dir, size_mode).unwrap_or(0),
+        };
+
+        let mut breakdown = Vec::new();
+        for (name, dir) in subdirs(&self.target_path()) {
+            if is_profile_dir(&dir) {
+                breakdown.push(entry(None, name, &dir));
+                continue;
+            }
+            for (profile, profile_dir) in subdirs(&dir) {
+                if is_profile_dir(&profile_dir) {
+                    breakdown.push(entry(Some(&name), profile, &profile_dir));
+                }
+            }
+        }
+        breakdown.sort_by(|a, b| (&a.triple, &a.profile).cmp(&(&b.triple, &b.profile)));
+        breakdown
+    }
+
+    /// target 下每个直接子目录（如 `debug`、`release`、`doc`）的大小，从大到小排列
+    ///
+    /// 各子目录复用与扫描相同的并行统计（按 `size_mode` 计算）；直接位于 target 下的文件不计入。
+    pub fn target_subdir_sizes(&self, size_mode: SizeMode) -> Vec<(String, u64)> {
+        let Ok(entries) = fs::read_dir(self.target_path()) else {
+            return Vec::new();
+        };
+        let mut sizes: Vec<(String, u64)> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+            .map(|entry| {
+                let size =
+                    Self::calculate_directory_size_fast(&entry.path(), size_mode).unwrap_or(0);
+                (entry.file_name().to_string_lossy().into_owned(), size)
+            })
+            .collect();
+        sizes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        sizes
     }
 
     /// Get relative path from a base directory
@@ -189,12 +711,114 @@ impl RustProject {
 
     /// Check if target directory exists
     pub fn target_exists(&self) -> bool {
-        self.path.join("target").exists()
+        self.target_path().exists()
     }
 
     /// Get target directory path
     pub fn target_path(&self) -> PathBuf {
-        self.path.join("target")
+        self.target_dir
+            .clone()
+            .unwrap_or_else(|| self.path.join("target"))
+    }
+
+    /// cargo 构建时实际写入的 target：没有独立 target 的 workspace 成员使用所属 workspace 根的 target
+    pub(crate) fn shared_target_path(&self) -> PathBuf {
+        if self.is_workspace_member && !self.has_target {
+            let package = fs::read_to_string(self.path.join("Cargo.toml"))
+                .ok()
+                .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+                .and_then(|manifest| manifest.get("package").cloned());
+            if let Some(root) = Self::parent_workspace_root(&self.path, package.as_ref()) {
+                return resolve_target_dir(&root);
+            }
+        }
+        self.target_path()
+    }
+}
+
+/// 解析项目实际使用的 target 目录
+///
+/// 优先级与 cargo 一致：`CARGO_TARGET_DIR` 环境变量，其次是项目目录及其各级父目录中
+/// `.cargo/config.toml`（或旧的 `.cargo/config`）里的 `build.target-dir`（离项目最近的优先），
+/// 最后回退到 `<project>/target`。
+pub fn resolve_target_dir(project_path: &Path) -> PathBuf {
+    resolve_target_dir_with_env(project_path, std::env::var_os("CARGO_TARGET_DIR"))
+}
+
+fn resolve_target_dir_with_env(
+    project_path: &Path,
+    env_target_dir: Option<std::ffi::OsString>,
+) -> PathBuf {
+    // 相对路径的 CARGO_TARGET_DIR 按当前工作目录解析
+    if let Some(dir) = env_target_dir.filter(|dir| !dir.is_empty()) {
+        let dir = PathBuf::from(dir);
+        return std::path::absolute(&dir).unwrap_or(dir);
+    }
+
+    for dir in project_path.ancestors() {
+        for name in ["config.toml", "config"] {
+            let config_path = dir.join(".cargo").join(name);
+            if let Some(target_dir) = read_config_target_dir(&config_path) {
+                // 配置文件中的相对路径相对于 `.cargo` 所在目录
+                return dir.join(target_dir);
+            }
+        }
+    }
+
+    project_path.join("target")
+}
+
+fn read_config_target_dir(config_path: &Path) -> Option<PathBuf> {
+    let content = fs::read_to_string(config_path).ok()?;
+    let parsed: toml::Value = toml::from_str(&content)
+        .inspect_err(|err| debug!("Failed to parse {:?}: {}", config_path, err))
+        .ok()?;
+    parsed
+        .get("build")?
+        .get("target-dir")?
+        .as_str()
+        .map(PathBuf::from)
+}
+
+/// 计算大小时记录已统计过的硬链接 inode
+struct HardlinkTracker {
+    #[cfg(unix)]
+    seen: Option<std::sync::Mutex<std::collections::HashSet<(u64, u64)>>>,
+}
+
+impl HardlinkTracker {
+    #[cfg(unix)]
+    fn new(enabled: bool) -> Self {
+        Self {
+            seen: enabled.then(Default::default),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn new(_enabled: bool) -> Self {
+        Self {}
+    }
+
+    /// 该文件的大小是否应计入总量（同一 inode 只有第一次出现时返回 true）
+    #[cfg(unix)]
+    fn first_sighting(&self, metadata: &fs::Metadata) -> bool {
+        use std::os::unix::fs::MetadataExt;
+
+        // 只有一个链接的文件不可能重复，不必加锁
+        let Some(seen) = &self.seen else {
+            return true;
+        };
+        if metadata.nlink() <= 1 {
+            return true;
+        }
+        seen.lock()
+            .map(|mut seen| seen.insert((metadata.dev(), metadata.ino())))
+            .unwrap_or(true)
+    }
+
+    #[cfg(not(unix))]
+    fn first_sighting(&self, _metadata: &fs::Metadata) -> bool {
+        true
     }
 }
 
@@ -254,6 +878,103 @@ version = "0.1.0"
         assert_eq!(name, Some("my-awesome-project".to_string()));
     }
 
+    #[test]
+    fn test_from_path_manifest_kinds() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        let write = |dir: &Path, manifest: &str| -> Result<()> {
+            fs::create_dir_all(dir)?;
+            fs::write(dir.join("Cargo.toml"), manifest)?;
+            Ok(())
+        };
+
+        // 只有 [package]
+        write(&root.join("solo"), "[package]\nname = \"solo\"\n")?;
+        let solo = RustProject::from_path(root.join("solo"))?;
+        assert!(solo.is_package);
+        assert!(!solo.is_workspace_root);
+        assert!(!solo.is_workspace_member);
+
+        // 只有 [workspace]（虚拟 manifest）
+        write(
+            &root.join("virtual"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )?;
+        write(
+            &root.join("virtual/crates/member"),
+            "[package]\nname = \"member\"\n",
+        )?;
+        let virtual_ws = RustProject::from_path(root.join("virtual"))?;
+        assert!(virtual_ws.is_workspace_root);
+        assert!(!virtual_ws.is_package);
+        assert!(!virtual_ws.is_workspace_member);
+        let member = RustProject::from_path(root.join("virtual/crates/member"))?;
+        assert!(member.is_package);
+        assert!(member.is_workspace_member);
+        assert!(!member.is_workspace_root);
+
+        // 同时有 [package] 和 [workspace]
+        write(
+            &root.join("combined"),
+            "[package]\nname = \"combined\"\n\n[workspace]\nmembers = [\"plugin\"]\nexclude = [\"scratch\"]\n",
+        )?;
+        write(
+            &root.join("combined/plugin"),
+            "[package]\nname = \"plugin\"\n",
+        )?;
+        write(
+            &root.join("combined/scratch"),
+            "[package]\nname = \"scratch\"\n",
+        )?;
+        let combined = RustProject::from_path(root.join("combined"))?;
+        assert_eq!(combined.name, "combined");
+        assert!(combined.is_workspace_root);
+        assert!(combined.is_package);
+        assert!(!combined.is_workspace_member);
+        assert!(RustProject::from_path(root.join("combined/plugin"))?.is_workspace_member);
+        assert!(!RustProject::from_path(root.join("combined/scratch"))?.is_workspace_member);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_path_workspace_members() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["app", "tools/cli", "crates/*"]
+exclude = ["crates/experimental"]
+"#,
+        )?;
+        for (dir, name) in [
+            ("app", "app"),
+            ("tools/cli", "purger-cli-tool"),
+            ("crates/core", "core-lib"),
+            ("crates/utils", "utils"),
+            ("crates/experimental", "experimental"),
+        ] {
+            fs::create_dir_all(root.join(dir))?;
+            fs::write(
+                root.join(dir).join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+        }
+        // glob 匹配到的非 crate 目录被忽略
+        fs::create_dir_all(root.join("crates/docs"))?;
+
+        let project = RustProject::from_path(root)?;
+        assert!(project.is_workspace_root);
+        assert_eq!(
+            project.members,
+            vec!["app", "core-lib", "purger-cli-tool", "utils"]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_path_with_target() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -305,6 +1026,221 @@ edition = "2021"
         Ok(())
     }
 
+    #[test]
+    fn test_from_path_tracks_largest_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_dir = temp_dir.path().join("big_project");
+        let deps = project_dir.join("target").join("debug").join("deps");
+        std::fs::create_dir_all(&deps)?;
+        std::fs::write(
+            project_dir.join("Cargo.toml"),
+            "[package]\nname = \"big_project\"\nversion = \"0.1.0\"\n",
+        )?;
+        std::fs::write(deps.join("small.rmeta"), vec![0u8; 10])?;
+        std::fs::write(deps.join("libfoo.rlib"), vec![0u8; 4096])?;
+        std::fs::write(project_dir.join("target").join("other"), vec![0u8; 100])?;
+
+        let project = RustProject::from_path(&project_dir)?;
+        assert_eq!(project.target_size, 10 + 4096 + 100);
+        let largest = project.largest_file.expect("largest file");
+        assert_eq!(
+            largest.path,
+            Path::new("debug").join("deps").join("libfoo.rlib")
+        );
+        assert_eq!(largest.size, 4096);
+
+        // 延迟计算大小时不统计
+        let lazy = RustProject::from_path_lazy(&project_dir)?;
+        assert!(lazy.largest_file.is_none());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_size_mode_on_disk() -> Result<()> {
+        use std::os::unix::fs::MetadataExt;
+
+        let temp_dir = TempDir::new()?;
+        let project_path = temp_dir.path();
+        fs::write(
+            project_path.join("Cargo.toml"),
+            "[package]\nname = \"blocks\"\n",
+        )?;
+        fs::create_dir_all(project_path.join("target"))?;
+        let small = project_path.join("target").join("small.d");
+        fs::write(&small, "0123456789")?;
+
+        let apparent = RustProject::from_path_with_size_mode(project_path, SizeMode::Apparent)?;
+        assert_eq!(apparent.target_size, 10);
+
+        // 小于一个块的文件按实际分配的块计
+        let on_disk = RustProject::from_path_with_size_mode(project_path, SizeMode::OnDisk)?;
+        assert_eq!(on_disk.target_size, fs::metadata(&small)?.blocks() * 512);
+        assert_ne!(on_disk.target_size, apparent.target_size);
+        assert_eq!(
+            apparent.current_target_size(SizeMode::OnDisk),
+            on_disk.target_size
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_size_skips_symlinks() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_path = temp_dir.path().join("project");
+        let outside = temp_dir.path().join("outside");
+        fs::create_dir_all(&outside)?;
+        fs::write(outside.join("big.bin"), vec![0u8; 5000])?;
+        fs::create_dir_all(project_path.join("target").join("debug"))?;
+        fs::write(
+            project_path.join("Cargo.toml"),
+            "[package]\nname = \"linked\"\n",
+        )?;
+        let target = project_path.join("target");
+        fs::write(target.join("debug").join("app"), vec![0u8; 100])?;
+        std::os::unix::fs::symlink(outside.join("big.bin"), target.join("big.bin"))?;
+        std::os::unix::fs::symlink(&outside, target.join("outside-dir"))?;
+        // 自引用的链接不会导致死循环
+        std::os::unix::fs::symlink(&target, target.join("debug").join("loop"))?;
+
+        let project = RustProject::from_path(&project_path)?;
+        assert_eq!(project.target_size, 100);
+        assert_eq!(project.current_target_size(SizeMode::Apparent), 100);
+        assert_eq!(
+            project.largest_file.map(|f| f.path),
+            Some(Path::new("debug").join("app"))
+        );
+
+        let lazy = RustProject::from_path_lazy(&project_path)?;
+        assert_eq!(lazy.get_target_size(), 100);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_from_path_dedup_hardlinks() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_path = temp_dir.path();
+        fs::write(
+            project_path.join("Cargo.toml"),
+            "[package]\nname = \"linked\"\n",
+        )?;
+        let deps = project_path.join("target").join("debug").join("deps");
+        fs::create_dir_all(&deps)?;
+        fs::write(deps.join("libfoo.rlib"), vec![0u8; 1000])?;
+        fs::hard_link(
+            deps.join("libfoo.rlib"),
+            project_path
+                .join("target")
+                .join("debug")
+                .join("libfoo.rlib"),
+        )?;
+
+        let project = RustProject::from_path(project_path)?;
+        assert_eq!(project.target_size, 2000);
+
+        let project = RustProject::from_path_dedup_hardlinks(project_path)?;
+        assert_eq!(project.target_size, 1000);
+        assert_eq!(project.largest_file.unwrap().size, 1000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_path_newest_file_time_basis() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let project_path = temp_dir.path();
+        fs::write(
+            project_path.join("Cargo.toml"),
+            "[package]\nname = \"recent\"\n",
+        )?;
+        let debug = project_path.join("target").join("debug");
+        fs::create_dir_all(&debug)?;
+        fs::write(debug.join("app"), "bin")?;
+
+        // target 目录本身很久没变，但里面的文件是新的
+        let old = SystemTime::now() - std::time::Duration::from_secs(30 * 24 * 60 * 60);
+        fs::File::open(project_path.join("target"))?.set_modified(old)?;
+
+        let by_dir = RustProject::from_path(project_path)?;
+        let by_file =
+            RustProject::from_path_with_time_basis(project_path, TimeBasis::NewestFileMtime)?;
+        assert!(by_file.last_modified > by_dir.last_modified);
+
+        let filter = crate::ProjectFilter::new(crate::scanner::ScanConfig {
+            keep_days: Some(7),
+            ..Default::default()
+        });
+        // 按目录时间视为很久没编译（可清理），按最新文件时间视为最近编译过（保留）
+        assert_eq!(filter.filter_projects(vec![by_dir]).len(), 1);
+        assert!(filter.filter_projects(vec![by_file]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_target_dir_from_env() {
+        let temp_dir = TempDir::new().unwrap();
+        let shared = temp_dir.path().join("shared-target");
+
+        assert_eq!(
+            resolve_target_dir_with_env(temp_dir.path(), Some(shared.clone().into())),
+            shared
+        );
+        // 空值视为未设置
+        assert_eq!(
+            resolve_target_dir_with_env(temp_dir.path(), Some("".into())),
+            temp_dir.path().join("target")
+        );
+    }
+
+    #[test]
+    fn test_resolve_target_dir_from_cargo_config() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        let project = root.join("nested").join("project");
+        fs::create_dir_all(&project)?;
+        fs::write(
+            project.join("Cargo.toml"),
+            "[package]\nname = \"relocated\"\n",
+        )?;
+
+        // 父目录中的旧式 .cargo/config
+        fs::create_dir_all(root.join(".cargo"))?;
+        fs::write(
+            root.join(".cargo").join("config"),
+            "[build]\ntarget-dir = \"build-out\"\n",
+        )?;
+        assert_eq!(
+            resolve_target_dir_with_env(&project, None),
+            root.join("build-out")
+        );
+
+        // 离项目更近的 config.toml 优先
+        fs::create_dir_all(project.join(".cargo"))?;
+        fs::write(
+            project.join(".cargo").join("config.toml"),
+            "[build]\ntarget-dir = \"out\"\n",
+        )?;
+        let target = project.join("out");
+        assert_eq!(resolve_target_dir_with_env(&project, None), target);
+
+        // 开发环境设置了 CARGO_TARGET_DIR 时 from_path 会优先使用它
+        if std::env::var_os("CARGO_TARGET_DIR").is_some() {
+            return Ok(());
+        }
+        fs::create_dir_all(&target)?;
+        fs::write(target.join("artifact"), "12345")?;
+        let rust_project = RustProject::from_path(&project)?;
+        assert!(rust_project.has_target);
+        assert_eq!(rust_project.target_size, 5);
+        assert_eq!(rust_project.target_path(), target);
+        assert_eq!(rust_project.target_dir, Some(target));
+        Ok(())
+    }
+
     #[test]
     fn test_from_path_invalid() {
         let temp_dir = TempDir::new().unwrap();
@@ -323,12 +1259,19 @@ edition = "2021"
             name: "test".to_string(),
             target_size: 1024,
             last_modified: SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: true,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         let formatted = project.formatted_size();
-        assert_eq!(formatted, "1.00 KB");
+        assert_eq!(formatted, "1.00 KiB");
     }
 
     #[test]
@@ -338,8 +1281,15 @@ edition = "2021"
             name: "my_project".to_string(),
             target_size: 0,
             last_modified: SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: false,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         let base = Path::new("/home/user/projects");
@@ -358,8 +1308,15 @@ edition = "2021"
             name: "test".to_string(),
             target_size: 0,
             last_modified: SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: false,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         // 最初target不存在
@@ -372,6 +1329,84 @@ edition = "2021"
         Ok(())
     }
 
+    #[test]
+    fn test_target_breakdown() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"cross\"\nversion = \"0.1.0\"\n",
+        )?;
+        let target = temp_dir.path().join("target");
+        for (dir, size) in [
+            ("debug", 10),
+            ("x86_64-unknown-linux-gnu/debug", 20),
+            ("wasm32-unknown-unknown/release", 30),
+        ] {
+            let dir = target.join(dir);
+            fs::create_dir_all(dir.join(".fingerprint"))?;
+            fs::write(dir.join("out"), vec![0u8; size])?;
+        }
+        // 非 profile 目录不计入
+        fs::create_dir_all(target.join("doc"))?;
+        fs::write(target.join("doc/index.html"), "doc")?;
+
+        let project = RustProject::from_path(temp_dir.path())?;
+        let breakdown = project.target_breakdown(SizeMode::Apparent);
+        let summary: Vec<_> = breakdown
+            .iter()
+            .map(|e| (e.triple.as_deref(), e.profile.as_str(), e.size))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (None, "debug", 10),
+                (Some("wasm32-unknown-unknown"), "release", 30),
+                (Some("x86_64-unknown-linux-gnu"), "debug", 20),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_target_subdir_sizes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"subdirs\"\nversion = \"0.1.0\"\n",
+        )?;
+        let target = temp_dir.path().join("target");
+        for (file, size) in [
+            ("debug/deps/libfoo.rlib", 300),
+            ("debug/incremental/foo/query", 200),
+            ("release/app", 100),
+            ("doc/index.html", 100),
+        ] {
+            let path = target.join(file);
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, vec![0u8; size])?;
+        }
+        // target 下的文件不属于任何子目录
+        fs::write(target.join(".rustc_info.json"), "{}")?;
+        fs::create_dir_all(target.join("tmp"))?;
+
+        let project = RustProject::from_path(temp_dir.path())?;
+        let sizes = project.target_subdir_sizes(SizeMode::Apparent);
+        assert_eq!(
+            sizes,
+            vec![
+                ("debug".to_string(), 500),
+                ("doc".to_string(), 100),
+                ("release".to_string(), 100),
+                ("tmp".to_string(), 0),
+            ]
+        );
+        let subdir_total: u64 = sizes.iter().map(|(_, size)| size).sum();
+        assert_eq!(subdir_total + 2, project.target_size);
+
+        Ok(())
+    }
+
     #[test]
     fn test_target_path() {
         let project = RustProject {
@@ -379,8 +1414,15 @@ edition = "2021"
             name: "test".to_string(),
             target_size: 0,
             last_modified: SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target: false,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         };
 
         let target_path = project.target_path();
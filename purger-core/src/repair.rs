@@ -0,0 +1,266 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::debug;
+
+/// 残缺 target 的判定原因（上次清理崩溃或构建被强制终止后遗留）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartialTargetIssue {
+    /// 存在无人持有的 `.cargo-lock`，但对应 profile 目录缺少 `.fingerprint`
+    OrphanedLock(PathBuf),
+    /// `deps` 下存在零字节的 `.rlib`/`.rmeta`（写入被中断）
+    TruncatedArtifact(PathBuf),
+    /// 目录无法读取（权限异常）
+    Unreadable(PathBuf),
+}
+
+impl fmt::Display for PartialTargetIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OrphanedLock(path) => write!(f, "orphaned lock: {}", path.display()),
+            Self::TruncatedArtifact(path) => write!(f, "truncated artifact: {}", path.display()),
+            Self::Unreadable(path) => write!(f, "unreadable directory: {}", path.display()),
+        }
+    }
+}
+
+/// 检测 target 目录是否处于残缺/卡住状态
+///
+/// 若发现锁正被其他进程持有（构建进行中），视为健康目录并返回 `None`。
+pub fn detect_partial_target(target_path: &Path) -> Option<PartialTargetIssue> {
+    let lock_files = match cargo_lock_files(target_path) {
+        Ok(lock_files) => lock_files,
+        Err((path, err))
+            if path != target_path || err.kind() == io::ErrorKind::PermissionDenied =>
+        {
+            return Some(PartialTargetIssue::Unreadable(path));
+        }
+        Err(_) => return None,
+    };
+
+    let mut issue = None;
+    for lock_path in lock_files {
+        if is_lock_held(&lock_path) {
+            debug!("锁正在被持有，跳过: {:?}", lock_path);
+            return None;
+        }
+
+        // 只检查 profile 目录中的锁，target 根目录下的锁没有对应的 `.fingerprint`
+        let Some(profile_dir) = lock_path.parent().filter(|dir| *dir != target_path) else {
+            continue;
+        };
+        if issue.is_some() {
+            continue;
+        }
+        if !profile_dir.join(".fingerprint").is_dir() {
+            issue = Some(PartialTargetIssue::OrphanedLock(lock_path.clone()));
+        } else if let Some(artifact) = find_truncated_artifact(&profile_dir.join("deps")) {
+            issue = Some(PartialTargetIssue::TruncatedArtifact(artifact));
+        }
+    }
+
+    issue
+}
+
+/// 列出 target 中存在的 `.cargo-lock`：`target/.cargo-lock`、`target/<profile>/.cargo-lock`
+/// 以及 `target/<triple>/<profile>/.cargo-lock`
+///
+/// target 或其子目录无法读取时返回该目录和对应的错误。
+pub(crate) fn cargo_lock_files(target_path: &Path) -> Result<Vec<PathBuf>, (PathBuf, io::Error)> {
+    let entries = fs::read_dir(target_path).map_err(|err| (target_path.to_path_buf(), err))?;
+
+    let mut dirs = vec![target_path.to_path_buf()];
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !entry.file_type().is_ok_and(|t| t.is_dir()) {
+            continue;
+        }
+        let sub_entries = fs::read_dir(&path).map_err(|err| (path.clone(), err))?;
+        dirs.extend(
+            sub_entries
+                .flatten()
+                .filter(|sub| sub.file_type().is_ok_and(|t| t.is_dir()))
+                .map(|sub| sub.path()),
+        );
+        dirs.push(path);
+    }
+
+    Ok(dirs
+        .into_iter()
+        .map(|dir| dir.join(".cargo-lock"))
+        .filter(|lock| lock.is_file())
+        .collect())
+}
+
+/// 锁文件是否正被其他进程（或同进程的其他句柄）持有
+///
+/// cargo 构建结束后并不会删除 `.cargo-lock`，因此只看文件是否存在不够，需要尝试加锁。
+pub(crate) fn is_lock_held(lock_path: &Path) -> bool {
+    let Ok(file) = fs::File::open(lock_path) else {
+        return false;
+    };
+    matches!(file.try_lock(), Err(fs::TryLockError::WouldBlock))
+}
+
+fn find_truncated_artifact(deps_dir: &Path) -> Option<PathBuf> {
+    fs::read_dir(deps_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            let is_artifact = path
+                .extension()
+                .is_some_and(|ext| ext == "rlib" || ext == "rmeta");
+            is_artifact && fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() == 0)
+        })
+}
+
+/// 强制删除目录：先恢复目录的写权限、清除只读属性，失败时重试
+pub fn force_remove_dir(path: &Path, retries: usize) -> io::Result<()> {
+    let mut attempt = 0;
+    loop {
+        make_tree_writable(path);
+        match fs::remove_dir_all(path) {
+            Ok(()) => return Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) if attempt >= retries => return Err(err),
+            Err(err) => {
+                attempt += 1;
+                debug!("强制删除失败（第 {} 次重试）{:?}: {}", attempt, path, err);
+                std::thread::sleep(Duration::from_millis(100 * attempt as u64));
+            }
+        }
+    }
+}
+
+/// 递归恢复目录树的可写权限（忽略错误）
+fn make_tree_writable(path: &Path) {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return;
+    };
+    if metadata.file_type().is_symlink() {
+        return;
+    }
+
+    make_writable(path, &metadata);
+    if metadata.is_dir()
+        && let Ok(entries) = fs::read_dir(path)
+    {
+        for entry in entries.flatten() {
+            make_tree_writable(&entry.path());
+        }
+    }
+}
+
+#[cfg(unix)]
+fn make_writable(path: &Path, metadata: &fs::Metadata) {
+    use std::os::unix::fs::PermissionsExt;
+
+    // 目录需要 rwx 才能删除其中的条目
+    let mode = metadata.permissions().mode();
+    let wanted = if metadata.is_dir() {
+        mode | 0o700
+    } else {
+        mode | 0o200
+    };
+    if wanted != mode {
+        let _ = fs::set_permissions(path, fs::Permissions::from_mode(wanted));
+    }
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::permissions_set_readonly_false)]
+fn make_writable(path: &Path, metadata: &fs::Metadata) {
+    let mut perms = metadata.permissions();
+    if perms.readonly() {
+        perms.set_readonly(false);
+        let _ = fs::set_permissions(path, perms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_healthy_target(target: &Path) {
+        let debug = target.join("debug");
+        fs::create_dir_all(debug.join(".fingerprint")).unwrap();
+        fs::create_dir_all(debug.join("deps")).unwrap();
+        fs::write(debug.join(".cargo-lock"), "").unwrap();
+        fs::write(debug.join("deps").join("libfoo.rlib"), "rlib").unwrap();
+    }
+
+    #[test]
+    fn test_detect_healthy_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        create_healthy_target(&target);
+
+        assert_eq!(detect_partial_target(&target), None);
+    }
+
+    #[test]
+    fn test_detect_orphaned_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        create_healthy_target(&target);
+        fs::remove_dir_all(target.join("debug").join(".fingerprint")).unwrap();
+
+        assert_eq!(
+            detect_partial_target(&target),
+            Some(PartialTargetIssue::OrphanedLock(
+                target.join("debug").join(".cargo-lock")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_detect_truncated_artifact() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        create_healthy_target(&target);
+        let truncated = target.join("debug").join("deps").join("libbar.rmeta");
+        fs::write(&truncated, "").unwrap();
+
+        assert_eq!(
+            detect_partial_target(&target),
+            Some(PartialTargetIssue::TruncatedArtifact(truncated))
+        );
+    }
+
+    #[test]
+    fn test_detect_skips_held_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        create_healthy_target(&target);
+        fs::remove_dir_all(target.join("debug").join(".fingerprint")).unwrap();
+
+        let lock = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(target.join("debug").join(".cargo-lock"))
+            .unwrap();
+        lock.lock().unwrap();
+
+        assert_eq!(detect_partial_target(&target), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_force_remove_dir_with_readonly_subdir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        let stuck = target.join("debug").join("stuck");
+        fs::create_dir_all(&stuck).unwrap();
+        fs::write(stuck.join("file"), "x").unwrap();
+        fs::set_permissions(&stuck, fs::Permissions::from_mode(0o500)).unwrap();
+
+        force_remove_dir(&target, 2).unwrap();
+        assert!(!target.exists());
+    }
+}
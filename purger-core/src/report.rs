@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use crate::{CleanResult, RustProject};
+
+/// 结构化报告的 schema 版本，字段发生不兼容变化时递增
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// 扫描/清理结果的结构化报告（CLI 与 GUI 共用同一格式）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub schema_version: u32,
+    pub projects: Vec<ProjectEntry>,
+    pub totals: ReportTotals,
+    pub last_clean: Option<CleanResult>,
+}
+
+/// 报告中的单个项目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub has_target: bool,
+    pub is_workspace: bool,
+    pub target_size: u64,
+    /// target 最后修改时间（Unix 秒），没有 target 时为 `None`
+    pub last_modified: Option<u64>,
+}
+
+/// 报告汇总
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportTotals {
+    pub project_count: usize,
+    pub with_target_count: usize,
+    pub total_target_size: u64,
+}
+
+impl Report {
+    pub fn new(projects: &[RustProject], last_clean: Option<&CleanResult>) -> Self {
+        let entries: Vec<ProjectEntry> = projects.iter().map(ProjectEntry::from).collect();
+        let totals = ReportTotals {
+            project_count: entries.len(),
+            with_target_count: entries.iter().filter(|p| p.has_target).count(),
+            total_target_size: entries.iter().map(|p| p.target_size).sum(),
+        };
+
+        Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            projects: entries,
+            totals,
+            last_clean: last_clean.cloned(),
+        }
+    }
+}
+
+impl From<&RustProject> for ProjectEntry {
+    fn from(project: &RustProject) -> Self {
+        let last_modified = project
+            .has_target
+            .then(|| project.last_modified.duration_since(UNIX_EPOCH).ok())
+            .flatten()
+            .map(|d| d.as_secs());
+
+        Self {
+            name: project.name.clone(),
+            path: project.path.clone(),
+            has_target: project.has_target,
+            is_workspace: project.is_workspace_root,
+            target_size: project.target_size,
+            last_modified,
+        }
+    }
+}
+
+/// 可回收空间的估算结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReclaimEstimate {
+    pub total_bytes: u64,
+    /// 有 target 的项目，按输入顺序排列
+    pub projects: Vec<ProjectEstimate>,
+}
+
+/// 单个项目的可回收空间
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectEstimate {
+    pub name: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+/// 估算清理这些项目可回收的空间
+///
+/// 只读取扫描时记录的 `has_target` 与 `target_size`，不访问文件系统、不启动进程、不删除任何内容，
+/// 可以放心在仪表盘等只读场景中周期性调用。延迟计算大小（`lazy_size_calculation`）扫描得到的项目
+/// 在调用 [`RustProject::compute_size`] 之前计为 0。
+pub fn estimate_reclaimable(projects: &[RustProject]) -> ReclaimEstimate {
+    let projects: Vec<ProjectEstimate> = projects
+        .iter()
+        .filter(|project| project.has_target)
+        .map(|project| ProjectEstimate {
+            name: project.name.clone(),
+            path: project.path.clone(),
+            bytes: project.target_size,
+        })
+        .collect();
+
+    ReclaimEstimate {
+        total_bytes: projects.iter().map(|p| p.bytes).sum(),
+        projects,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn project(name: &str, target_size: u64, has_target: bool) -> RustProject {
+        RustProject {
+            path: PathBuf::from(format!("/work/{name}")),
+            name: name.to_string(),
+            target_size,
+            last_modified: UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
+            has_target,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn test_report_totals() {
+        let projects = vec![project("a", 100, true), project("b", 0, false)];
+        let mut clean = CleanResult::new();
+        clean.add_success(100);
+
+        let report = Report::new(&projects, Some(&clean));
+        assert_eq!(report.schema_version, REPORT_SCHEMA_VERSION);
+        assert_eq!(report.totals.project_count, 2);
+        assert_eq!(report.totals.with_target_count, 1);
+        assert_eq!(report.totals.total_target_size, 100);
+        assert_eq!(report.projects[0].last_modified, Some(1_700_000_000));
+        assert_eq!(report.projects[1].last_modified, None);
+        assert_eq!(report.last_clean.unwrap().total_size_freed, 100);
+    }
+
+    #[test]
+    fn test_estimate_reclaimable() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let mut projects = Vec::new();
+        for (name, size) in [("small", 10), ("large", 1000)] {
+            let dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(dir.join("target"))?;
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\n"),
+            )?;
+            std::fs::write(dir.join("target").join("out"), vec![0u8; size])?;
+            projects.push(RustProject::from_path(&dir)?);
+        }
+        projects.push(project("no_target", 0, false));
+
+        let estimate = estimate_reclaimable(&projects);
+        assert_eq!(estimate.total_bytes, 1010);
+        let breakdown: Vec<_> = estimate
+            .projects
+            .iter()
+            .map(|p| (p.name.as_str(), p.bytes))
+            .collect();
+        assert_eq!(breakdown, [("small", 10), ("large", 1000)]);
+        assert!(
+            projects[..2]
+                .iter()
+                .all(|p| p.target_path().join("out").exists())
+        );
+
+        assert_eq!(estimate_reclaimable(&[]), ReclaimEstimate::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_json_roundtrip() {
+        let report = Report::new(&[project("a", 42, true)], None);
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"schema_version\":1"));
+
+        let parsed: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.projects[0].name, "a");
+        assert!(parsed.last_clean.is_none());
+    }
+}
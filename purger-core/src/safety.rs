@@ -0,0 +1,165 @@
+use std::path::{Component, Path, PathBuf};
+
+/// 拒绝扫描/清理危险路径（未指定 force 时）
+#[derive(Debug, thiserror::Error)]
+#[error("refusing to operate on protected system path: {path:?} (use --force to override)")]
+pub struct DangerousPath {
+    pub path: PathBuf,
+}
+
+/// 内置的危险路径列表（文件系统根之外的系统目录）
+#[cfg(unix)]
+const DANGEROUS_ROOTS: &[&str] = &[
+    "/bin",
+    "/boot",
+    "/dev",
+    "/etc",
+    "/home",
+    "/lib",
+    "/lib64",
+    "/opt",
+    "/proc",
+    "/sbin",
+    "/sys",
+    "/usr",
+    "/usr/bin",
+    "/usr/lib",
+    "/usr/sbin",
+    "/var",
+    #[cfg(target_os = "macos")]
+    "/System",
+    #[cfg(target_os = "macos")]
+    "/Library",
+    #[cfg(target_os = "macos")]
+    "/Applications",
+    #[cfg(target_os = "macos")]
+    "/Users",
+];
+
+#[cfg(windows)]
+const DANGEROUS_ROOTS: &[&str] = &[
+    r"C:\Windows",
+    r"C:\Program Files",
+    r"C:\Program Files (x86)",
+    r"C:\ProgramData",
+    r"C:\Users",
+];
+
+#[cfg(not(any(unix, windows)))]
+const DANGEROUS_ROOTS: &[&str] = &[];
+
+/// 判断路径是否为不应扫描或清理的危险路径
+///
+/// 文件系统根（`/`、`C:\` 等）以及内置系统目录都视为危险；只比较路径本身，其子目录不受影响。
+pub fn is_dangerous_root(path: &Path) -> bool {
+    // 同时检查原路径和解析后的路径（例如 `/bin` 可能是指向 `/usr/bin` 的符号链接）
+    [strip_verbatim_prefix(path.to_path_buf()), resolve(path)]
+        .iter()
+        .any(|candidate| matches_dangerous_root(candidate))
+}
+
+fn matches_dangerous_root(path: &Path) -> bool {
+    // 没有任何普通路径组件，即文件系统/盘符根
+    if !path.components().any(|c| matches!(c, Component::Normal(_))) {
+        return path.has_root();
+    }
+
+    DANGEROUS_ROOTS
+        .iter()
+        .any(|root| paths_equal(path, Path::new(root)))
+}
+
+/// 未指定 force 时，若路径危险则返回 [`DangerousPath`] 错误
+pub fn ensure_not_dangerous(path: &Path, force: bool) -> anyhow::Result<()> {
+    if !force && is_dangerous_root(path) {
+        anyhow::bail!(DangerousPath {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(())
+}
+
+/// 尽量解析为绝对路径（解析 `..`、符号链接），失败时保留原路径
+fn resolve(path: &Path) -> PathBuf {
+    let resolved = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    strip_verbatim_prefix(resolved)
+}
+
+#[cfg(windows)]
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    match path.to_str().and_then(|s| s.strip_prefix(r"\\?\")) {
+        Some(stripped) if !stripped.starts_with("UNC") => PathBuf::from(stripped),
+        _ => path,
+    }
+}
+
+#[cfg(not(windows))]
+fn strip_verbatim_prefix(path: PathBuf) -> PathBuf {
+    path
+}
+
+#[cfg(windows)]
+fn paths_equal(a: &Path, b: &Path) -> bool {
+    let a = a.to_string_lossy().trim_end_matches('\\').to_lowercase();
+    let b = b.to_string_lossy().trim_end_matches('\\').to_lowercase();
+    a == b
+}
+
+#[cfg(not(windows))]
+fn paths_equal(a: &Path, b: &Path) -> bool {
+    a == b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_temp_dir_is_not_dangerous() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(!is_dangerous_root(temp_dir.path()));
+        assert!(ensure_not_dangerous(temp_dir.path(), false).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_dangerous_roots() {
+        assert!(is_dangerous_root(Path::new("/")));
+        assert!(is_dangerous_root(Path::new("/usr")));
+        assert!(is_dangerous_root(Path::new("/usr/")));
+        assert!(is_dangerous_root(Path::new("/bin")));
+        assert!(is_dangerous_root(Path::new("/home")));
+        assert!(is_dangerous_root(Path::new("/usr/../usr")));
+
+        // 子目录不受影响
+        assert!(!is_dangerous_root(Path::new("/usr/local/src/my-project")));
+        assert!(!is_dangerous_root(Path::new("/home/user/projects")));
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn test_macos_dangerous_roots() {
+        assert!(is_dangerous_root(Path::new("/System")));
+        assert!(is_dangerous_root(Path::new("/Users")));
+        assert!(!is_dangerous_root(Path::new("/Users/someone/code")));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_windows_dangerous_roots() {
+        assert!(is_dangerous_root(Path::new(r"C:\")));
+        assert!(is_dangerous_root(Path::new(r"C:\Windows")));
+        assert!(is_dangerous_root(Path::new(r"c:\windows\")));
+        assert!(is_dangerous_root(Path::new(r"C:\Program Files")));
+        assert!(!is_dangerous_root(Path::new(r"C:\Users\someone\code")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_ensure_not_dangerous_force() {
+        let err = ensure_not_dangerous(Path::new("/"), false).unwrap_err();
+        assert!(err.downcast_ref::<DangerousPath>().is_some());
+        assert!(ensure_not_dangerous(Path::new("/"), true).is_ok());
+    }
+}
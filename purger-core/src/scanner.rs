@@ -1,21 +1,32 @@
 use anyhow::{Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ignore::{DirEntry, WalkBuilder};
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 use tracing::{debug, info, warn};
 
+use crate::cache::SizeCache;
+use crate::error::{PurgerError, PurgerResult};
 use crate::filter::ProjectFilter;
-use crate::project::RustProject;
+use crate::project::{RustProject, SizeMode, TimeBasis};
+
+/// purger 专用忽略文件名（gitignore 语法）
+pub const PURGERIGNORE_FILENAME: &str = ".purgerignore";
 
 /// 项目扫描器配置
 #[derive(Debug, Clone)]
 pub struct ScanConfig {
     pub max_depth: Option<usize>,
+    /// 只收集项目目录相对扫描根至少这么深的项目（扫描根本身深度为 0，`None` 时不限制）
+    pub min_depth: Option<usize>,
     pub follow_links: bool,
     pub respect_gitignore: bool,
+    /// 遵循 `.purgerignore`（gitignore 语法，只对 purger 生效，不要求在 git 仓库中）
+    pub purgerignore: bool,
     pub ignore_hidden: bool,
     pub parallel: bool,
 
@@ -26,18 +37,53 @@ pub struct ScanConfig {
     // 过滤选项
     /// 保留最近N天编译的项目（基于target目录的最后修改时间）
     pub keep_days: Option<u32>,
+    /// 保留最近这段时间内编译的项目，比 `keep_days` 更精细；两者都设置时取较长的时间
+    pub keep_duration: Option<Duration>,
     /// 保留target目录小于指定大小的项目（字节）
     pub keep_size: Option<u64>,
+    /// 只清理target目录不小于指定大小的项目（字节），与 `keep_size` 同时设置时两者都需满足
+    pub min_clean_size: Option<u64>,
     /// 忽略的路径列表（绝对路径或相对路径）
     pub ignore_paths: Vec<PathBuf>,
+    /// 只把路径匹配任一 glob 的 `Cargo.toml` 视为项目（相对扫描根匹配，为空时不限制）
+    pub manifest_globs: Vec<String>,
+    /// 跳过路径匹配任一 glob 的目录子树（相对扫描根或绝对路径匹配，如 `**/vendor/**`、`*-generated`）
+    pub exclude_patterns: Vec<String>,
+    /// 遍历时跳过修改时间早于该时刻的目录子树（用于加速对稳定目录树的重复扫描）
+    ///
+    /// 注意：目录的 mtime 只在其直接子项增删改名时更新，且部分文件系统（网络盘、某些
+    /// FUSE 实现）上的目录 mtime 并不可靠，因此开启后可能漏掉深层目录中的变化。
+    pub prune_unmodified_since: Option<SystemTime>,
+    /// 除 target 外也视为构建输出的目录名（相对项目目录，如 `target-wasm`、`dist/target`），
+    /// 其大小计入项目并在清理时一并删除；非空时不使用磁盘缓存
+    pub extra_target_names: Vec<String>,
+    /// 计算 target 大小时，同一 inode 的硬链接只计一次（仅 Unix，其他平台忽略此选项）
+    pub dedup_hardlinks: bool,
+    /// 项目最后修改时间（`keep_days` 过滤依据）的计算方式
+    pub time_basis: TimeBasis,
+    /// target 大小按文件长度还是实际占用的磁盘块统计
+    pub size_mode: SizeMode,
+
+    /// 并行扫描使用的线程数（`None` 时使用默认值）
+    pub jobs: Option<usize>,
+
+    /// 使用磁盘缓存：target 修改时间未变的项目复用上次扫描的大小（延迟计算大小时不生效）
+    pub use_cache: bool,
+    /// 缓存目录，`None` 时使用系统缓存目录下的 `purger`
+    pub cache_dir: Option<PathBuf>,
+
+    /// 允许扫描受保护的系统路径（见 [`crate::safety::is_dangerous_root`]）
+    pub force: bool,
 }
 
 impl Default for ScanConfig {
     fn default() -> Self {
         Self {
             max_depth: Some(10), // 默认最大深度10层
+            min_depth: None,
             follow_links: false,
             respect_gitignore: true,
+            purgerignore: true,
             ignore_hidden: true,
             parallel: true,
 
@@ -46,8 +92,22 @@ impl Default for ScanConfig {
 
             // 过滤选项默认值
             keep_days: None,
+            keep_duration: None,
             keep_size: None,
+            min_clean_size: None,
             ignore_paths: Vec::new(),
+            manifest_globs: Vec::new(),
+            exclude_patterns: Vec::new(),
+            prune_unmodified_since: None,
+            extra_target_names: Vec::new(),
+            dedup_hardlinks: false,
+            time_basis: TimeBasis::DirMtime,
+            size_mode: SizeMode::Apparent,
+
+            jobs: None,
+            use_cache: false,
+            cache_dir: None,
+            force: false,
         }
     }
 }
@@ -59,6 +119,16 @@ pub struct ProjectScanner {
     cache: Arc<Mutex<HashMap<PathBuf, RustProject>>>,
 }
 
+impl ScanConfig {
+    /// 时间过滤的保留窗口：`keep_days` 与 `keep_duration` 中较长的一个，都未设置时为 None
+    pub fn keep_threshold(&self) -> Option<Duration> {
+        let days = self
+            .keep_days
+            .map(|days| Duration::from_secs(days as u64 * 24 * 60 * 60));
+        days.max(self.keep_duration)
+    }
+}
+
 impl ProjectScanner {
     /// 创建新的扫描器
     pub fn new(config: ScanConfig) -> Self {
@@ -69,8 +139,136 @@ impl ProjectScanner {
     }
 
     /// 扫描指定路径下的所有Rust项目
-    pub fn scan<P: AsRef<Path>>(&self, root_path: P) -> Result<Vec<RustProject>> {
-        self.scan_with_cancel_and_progress(root_path, None, None)
+    pub fn scan<P: AsRef<Path>>(&self, root_path: P) -> PurgerResult<Vec<RustProject>> {
+        self.scan_with_progress(root_path, |_| {})
+    }
+
+    /// 扫描并在每发现一个 `Cargo.toml` 时以当前已发现的数量调用回调
+    ///
+    /// 并行遍历时回调可能来自多个线程。
+    pub fn scan_with_progress<P, F>(
+        &self,
+        root_path: P,
+        on_found: F,
+    ) -> PurgerResult<Vec<RustProject>>
+    where
+        P: AsRef<Path>,
+        F: Fn(usize) + Sync,
+    {
+        self.scan_with_cancel_and_progress(root_path, None, Some(&on_found))
+    }
+
+    /// 依次扫描多个根路径并合并结果，出现在多个根下的项目（按规范化路径判断）只保留一次
+    pub fn scan_many(&self, roots: &[PathBuf]) -> PurgerResult<Vec<RustProject>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut projects = Vec::new();
+        for root in roots {
+            for project in self.scan(root)? {
+                let key = project
+                    .path
+                    .canonicalize()
+                    .unwrap_or_else(|_| project.path.clone());
+                if seen.insert(key) {
+                    projects.push(project);
+                } else {
+                    debug!("跳过重复项目: {:?}", project.path);
+                }
+            }
+        }
+        // 不同扫描根下的项目也可能共用同一个 target（如全局 CARGO_TARGET_DIR）
+        Ok(Self::collapse_shared_targets(projects))
+    }
+
+    /// 查找孤立的 target 目录：cargo 生成的 `target`，但所在目录没有（或无法解析的）`Cargo.toml`
+    ///
+    /// 不依赖 `Cargo.toml` 发现项目，因此单独遍历目录树，但与普通扫描一样遵守 exclude glob、深度限制、
+    /// `.gitignore` 和 `.purgerignore`。只遍历目录且不进入任何 `target`：孤立 target 通过其所在目录发现，
+    /// 因此 `target/` 本身被 `.gitignore` 忽略时也能找到。结果同样经过时间/大小/路径过滤。
+    pub fn scan_orphans<P: AsRef<Path>>(&self, root_path: P) -> PurgerResult<Vec<RustProject>> {
+        let root_path = root_path.as_ref();
+        if !root_path.exists() {
+            return Err(PurgerError::PathNotFound(root_path.to_path_buf()));
+        }
+        if !root_path.is_dir() {
+            return Err(PurgerError::NotADirectory(root_path.to_path_buf()));
+        }
+        crate::safety::ensure_not_dangerous(root_path, self.config.force)?;
+
+        let exclude_globs = Self::build_globset(&self.config.exclude_patterns, "exclude")?;
+        let mut builder = WalkBuilder::new(root_path);
+        builder
+            .follow_links(self.config.follow_links)
+            .git_ignore(self.config.respect_gitignore)
+            .hidden(self.config.ignore_hidden)
+            .max_depth(self.config.max_depth);
+        if self.config.purgerignore {
+            builder.add_custom_ignore_filename(PURGERIGNORE_FILENAME);
+        }
+        let root = root_path.to_path_buf();
+        let globs = exclude_globs.clone();
+        builder.filter_entry(move |entry| {
+            entry.file_type().is_some_and(|t| t.is_dir())
+                && (entry.depth() == 0 || entry.file_name() != "target")
+                && globs
+                    .as_ref()
+                    .is_none_or(|globs| !Self::is_excluded(entry, &root, globs))
+        });
+
+        let mut orphans = Vec::new();
+        for entry in builder.build() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    warn!("扫描错误: {}", e);
+                    continue;
+                }
+            };
+            // 与普通扫描一致：项目目录不浅于 min_depth，target 本身不超过 max_depth
+            if self.config.min_depth.is_some_and(|min| entry.depth() < min)
+                || self
+                    .config
+                    .max_depth
+                    .is_some_and(|max| entry.depth() >= max)
+            {
+                continue;
+            }
+
+            let target = entry.path().join("target");
+            if !target.is_dir() || !RustProject::is_orphan_target(&target) {
+                continue;
+            }
+            if let Some(globs) = &exclude_globs {
+                let relative = target.strip_prefix(root_path).unwrap_or(&target);
+                if globs.is_match(relative) || globs.is_match(&target) {
+                    debug!("匹配 exclude glob，跳过: {:?}", target);
+                    continue;
+                }
+            }
+            match RustProject::from_orphan_target(
+                &target,
+                self.config.dedup_hardlinks,
+                self.config.time_basis,
+                self.config.size_mode,
+            ) {
+                Ok(project) => {
+                    debug!("发现孤立的 target: {:?}", target);
+                    orphans.push(project);
+                }
+                Err(e) => warn!("读取孤立 target 失败 {:?}: {}", target, e),
+            }
+        }
+
+        info!("找到 {} 个孤立的 target 目录", orphans.len());
+        Ok(self.apply_filters(orphans))
+    }
+
+    /// 可取消的扫描：遍历和解析过程中检查 `cancel_flag`，置位后尽快返回 [`PurgerError::Cancelled`]
+    pub fn scan_cancellable<P: AsRef<Path>>(
+        &self,
+        root_path: P,
+        cancel_flag: &AtomicBool,
+    ) -> PurgerResult<Vec<RustProject>> {
+        self.scan_with_cancel_and_progress(root_path, Some(cancel_flag), None)
     }
 
     pub fn scan_with_cancel_and_progress<P: AsRef<Path>>(
@@ -78,17 +276,47 @@ impl ProjectScanner {
         root_path: P,
         cancel_flag: Option<&AtomicBool>,
         on_cargo_toml_found: Option<&(dyn Fn(usize) + Sync)>,
-    ) -> Result<Vec<RustProject>> {
+    ) -> PurgerResult<Vec<RustProject>> {
         let root_path = root_path.as_ref();
         let start_time = std::time::Instant::now();
         info!("开始扫描路径: {:?}", root_path);
 
         if !root_path.exists() {
-            anyhow::bail!("路径不存在: {:?}", root_path);
+            return Err(PurgerError::PathNotFound(root_path.to_path_buf()));
+        }
+
+        crate::safety::ensure_not_dangerous(root_path, self.config.force)?;
+
+        let cache_file = self.cache_file(root_path);
+        let size_cache = cache_file.as_ref().map(|file| {
+            SizeCache::load(
+                file,
+                root_path,
+                self.config.dedup_hardlinks,
+                self.config.size_mode,
+            )
+        });
+
+        // 扫描根直接指向单个项目时，无需遍历目录树（设置了 min_depth 时扫描根本身不计入）
+        if self.config.min_depth.unwrap_or(0) == 0
+            && let Some(project_dir) = Self::single_project_root(root_path)
+        {
+            info!("扫描路径指向单个项目: {:?}", project_dir);
+            let project = Self::load_project(&project_dir, &self.config, size_cache.as_ref())
+                .context("解析Rust项目失败")?;
+            self.save_cache(
+                cache_file.as_deref(),
+                root_path,
+                std::slice::from_ref(&project),
+            );
+            if let Some(callback) = on_cargo_toml_found {
+                callback(1);
+            }
+            return Ok(self.apply_filters(vec![project]));
         }
 
         if !root_path.is_dir() {
-            anyhow::bail!("路径不是目录: {:?}", root_path);
+            return Err(PurgerError::NotADirectory(root_path.to_path_buf()));
         }
 
         // 优化的文件遍历
@@ -100,17 +328,24 @@ impl ProjectScanner {
             find_time
         );
 
-        if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
-            anyhow::bail!("扫描已取消");
+        let is_cancelled = || cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed));
+        if is_cancelled() {
+            return Err(PurgerError::Cancelled);
         }
 
         // 并行或串行处理项目
         let parse_start = std::time::Instant::now();
         let projects = if self.config.parallel {
-            self.process_projects_parallel(cargo_dirs)?
+            crate::with_thread_pool(self.config.jobs, || {
+                self.process_projects_parallel(cargo_dirs, cancel_flag, size_cache.as_ref())
+            })?
         } else {
-            self.process_projects_sequential(cargo_dirs)?
+            self.process_projects_sequential(cargo_dirs, cancel_flag, size_cache.as_ref())?
         };
+        if is_cancelled() {
+            return Err(PurgerError::Cancelled);
+        }
+        self.save_cache(cache_file.as_deref(), root_path, &projects);
         let parse_time = parse_start.elapsed();
 
         info!(
@@ -120,12 +355,158 @@ impl ProjectScanner {
         );
         info!("总扫描时间: {:?}", start_time.elapsed());
 
+        let projects = Self::collapse_workspace_members(projects);
+        let projects = Self::collapse_shared_targets(projects);
+
         // 应用过滤器
         let filtered_projects = self.apply_filters(projects);
 
         Ok(filtered_projects)
     }
 
+    /// 将共享 workspace target 的成员合并到 workspace 根项目，避免重复清理同一个 target
+    ///
+    /// 按 cargo 实际使用的 target 分组：同一个 target 下有 target 的 workspace 根（多个时取最外层的）
+    /// 作为归属，其余项目记为它的成员。使用独立 target 的子项目（如被 `exclude` 的 crate）保持不变。
+    fn collapse_workspace_members(projects: Vec<RustProject>) -> Vec<RustProject> {
+        let targets: Vec<PathBuf> = projects.iter().map(|p| p.shared_target_path()).collect();
+
+        let mut owners: HashMap<&PathBuf, usize> = HashMap::new();
+        for (index, project) in projects.iter().enumerate() {
+            if !(project.is_workspace_root && project.has_target) {
+                continue;
+            }
+            let depth = project.path.components().count();
+            owners
+                .entry(&targets[index])
+                .and_modify(|owner| {
+                    if projects[*owner].path.components().count() > depth {
+                        *owner = index;
+                    }
+                })
+                .or_insert(index);
+        }
+        if owners.is_empty() {
+            return projects;
+        }
+
+        let owner_of: Vec<Option<PathBuf>> = targets
+            .iter()
+            .enumerate()
+            .map(|(index, target)| match owners.get(target) {
+                Some(&owner) if owner != index => Some(projects[owner].path.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let mut members: HashMap<PathBuf, Vec<String>> = HashMap::new();
+        let mut kept = Vec::with_capacity(projects.len());
+        for (project, owner) in projects.into_iter().zip(owner_of) {
+            match owner {
+                Some(root) => {
+                    debug!("合并 workspace 成员 {} 到 {:?}", project.name, root);
+                    members.entry(root).or_default().push(project.name);
+                }
+                None => kept.push(project),
+            }
+        }
+
+        for project in &mut kept {
+            if let Some(names) = members.remove(&project.path) {
+                project.members.extend(names);
+                project.members.sort();
+                project.members.dedup();
+            }
+        }
+        kept
+    }
+
+    /// 将实际使用同一个 target 目录的项目合并为一项（如全局 `CARGO_TARGET_DIR` 或共同的 `build.target-dir`）
+    ///
+    /// 否则总大小会按项目数重复计算，清理其中一个项目也会清空其他项目的 target。
+    /// 路径最浅的项目作为归属，其余项目记为它的成员。
+    fn collapse_shared_targets(projects: Vec<RustProject>) -> Vec<RustProject> {
+        let keys: Vec<PathBuf> = projects
+            .iter()
+            .map(|p| {
+                let target = p.target_path();
+                target.canonicalize().unwrap_or(target)
+            })
+            .collect();
+
+        let mut owners: HashMap<&PathBuf, usize> = HashMap::new();
+        for (index, key) in keys.iter().enumerate() {
+            let rank = |i: usize| (projects[i].path.components().count(), &projects[i].path);
+            owners
+                .entry(key)
+                .and_modify(|owner| {
+                    if rank(index) < rank(*owner) {
+                        *owner = index;
+                    }
+                })
+                .or_insert(index);
+        }
+        if owners.len() == projects.len() {
+            return projects;
+        }
+
+        let owner_of: Vec<Option<PathBuf>> = keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| match owners.get(key) {
+                Some(&owner) if owner != index => Some(projects[owner].path.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let mut members: HashMap<PathBuf, Vec<String>> = HashMap::new();
+        let mut kept = Vec::with_capacity(owners.len());
+        for (project, owner) in projects.into_iter().zip(owner_of) {
+            match owner {
+                Some(owner) => {
+                    debug!("{} 与 {:?} 共用 target，合并为一项", project.name, owner);
+                    let entry = members.entry(owner).or_default();
+                    entry.push(project.name);
+                    entry.extend(project.members);
+                }
+                None => kept.push(project),
+            }
+        }
+
+        for project in &mut kept {
+            if let Some(names) = members.remove(&project.path) {
+                project.members.extend(names);
+                project.members.sort();
+                project.members.dedup();
+            }
+        }
+        kept
+    }
+
+    /// 判断扫描根是否为单个项目：`Cargo.toml` 文件本身，或顶层含 `Cargo.toml` 的非 workspace 目录。
+    ///
+    /// workspace 根仍然走完整遍历，以便发现各个成员。
+    fn single_project_root(root_path: &Path) -> Option<PathBuf> {
+        if root_path.is_file() {
+            if root_path.file_name()? != "Cargo.toml" {
+                return None;
+            }
+            let parent = root_path
+                .parent()
+                .filter(|p| !p.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            return Some(parent.to_path_buf());
+        }
+
+        let cargo_toml = root_path.join("Cargo.toml");
+        if cargo_toml.is_file() && !RustProject::is_workspace_project(&cargo_toml).unwrap_or(false)
+        {
+            return Some(root_path.to_path_buf());
+        }
+
+        None
+    }
+
     /// 优化的Cargo项目查找方法
     fn find_cargo_projects(
         &self,
@@ -133,25 +514,61 @@ impl ProjectScanner {
         cancel_flag: Option<&AtomicBool>,
         on_cargo_toml_found: Option<&(dyn Fn(usize) + Sync)>,
     ) -> Result<Vec<PathBuf>> {
+        let manifest_globs = Self::build_globset(&self.config.manifest_globs, "manifest")?;
+        let exclude_globs = Self::build_globset(&self.config.exclude_patterns, "exclude")?;
         let mut builder = WalkBuilder::new(root_path);
         builder
             .follow_links(self.config.follow_links)
             .git_ignore(self.config.respect_gitignore)
             .hidden(self.config.ignore_hidden);
+        if self.config.purgerignore {
+            builder.add_custom_ignore_filename(PURGERIGNORE_FILENAME);
+        }
 
         if let Some(depth) = self.config.max_depth {
             builder.max_depth(Some(depth));
         }
 
+        // 跟随符号链接时记录已访问目录的真实路径，避免链接成环或同一目录被重复遍历
+        let visited = self.config.follow_links.then(|| {
+            let root = root_path
+                .canonicalize()
+                .unwrap_or_else(|_| root_path.to_path_buf());
+            Arc::new(Mutex::new(HashSet::from([root])))
+        });
+
+        // WalkBuilder 只支持一个 filter_entry：同时处理 exclude glob、按 mtime 剪枝和环检测
+        let prune_threshold = self.config.prune_unmodified_since;
+        if exclude_globs.is_some() || prune_threshold.is_some() || visited.is_some() {
+            let root = root_path.to_path_buf();
+            builder.filter_entry(move |entry| {
+                if let Some(globs) = &exclude_globs
+                    && Self::is_excluded(entry, &root, globs)
+                {
+                    return false;
+                }
+                if prune_threshold
+                    .is_some_and(|threshold| Self::is_unmodified_dir(entry, threshold))
+                {
+                    return false;
+                }
+                visited
+                    .as_ref()
+                    .is_none_or(|visited| Self::is_first_visit(entry, visited))
+            });
+        }
+
         // 启用并行遍历以提升性能
         if self.config.parallel {
-            // 使用系统CPU核心数，但限制最大线程数避免过度并发
-            let thread_count = std::cmp::min(
-                std::thread::available_parallelism()
-                    .map(|n| n.get())
-                    .unwrap_or(4),
-                8,
-            );
+            // 默认使用系统CPU核心数，但限制最大线程数避免过度并发
+            let thread_count = self.config.jobs.unwrap_or_else(|| {
+                std::cmp::min(
+                    std::thread::available_parallelism()
+                        .map(|n| n.get())
+                        .unwrap_or(4),
+                    8,
+                )
+            });
             builder.threads(thread_count);
             debug!("启用并行文件遍历，线程数: {}", thread_count);
         }
@@ -163,6 +580,7 @@ impl ProjectScanner {
         walker.run(|| {
             let cargo_dirs = &cargo_dirs;
             let found_count = &found_count;
+            let manifest_globs = manifest_globs.as_ref();
             Box::new(move |entry| {
                 if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
                     return ignore::WalkState::Quit;
@@ -170,12 +588,15 @@ impl ProjectScanner {
 
                 match entry {
                     Ok(entry) => {
-                        if let Some(project_dir) =
-                            self.process_entry(entry, found_count, on_cargo_toml_found)
+                        if let Some(project_dir) = self.process_entry(
+                            entry,
+                            root_path,
+                            manifest_globs,
+                            found_count,
+                            on_cargo_toml_found,
+                        ) && let Ok(mut dirs) = cargo_dirs.lock()
                         {
-                            if let Ok(mut dirs) = cargo_dirs.lock() {
-                                dirs.push(project_dir);
-                            }
+                            dirs.push(project_dir);
                         }
                     }
                     Err(e) => {
@@ -192,10 +613,85 @@ impl ProjectScanner {
         Ok(cargo_dirs)
     }
 
+    /// 记录目录的真实路径，已经访问过（符号链接成环或指向已扫描的目录）时返回 false
+    fn is_first_visit(entry: &DirEntry, visited: &Mutex<HashSet<PathBuf>>) -> bool {
+        if entry.depth() == 0 || !entry.file_type().is_some_and(|t| t.is_dir()) {
+            return true;
+        }
+        let Ok(canonical) = entry.path().canonicalize() else {
+            return true;
+        };
+
+        let first = visited
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+            .insert(canonical);
+        if !first {
+            warn!(
+                "目录已扫描过（符号链接成环或重复），跳过: {:?}",
+                entry.path()
+            );
+        }
+        first
+    }
+
+    /// 判断目录条目的修改时间是否早于阈值（扫描根本身永远不会被剪枝）
+    fn is_unmodified_dir(entry: &DirEntry, threshold: SystemTime) -> bool {
+        if entry.depth() == 0 || !entry.file_type().is_some_and(|t| t.is_dir()) {
+            return false;
+        }
+
+        let unmodified = entry
+            .metadata()
+            .ok()
+            .and_then(|m| m.modified().ok())
+            .is_some_and(|modified| modified < threshold);
+        if unmodified {
+            debug!("目录未修改，跳过子树: {:?}", entry.path());
+        }
+        unmodified
+    }
+
+    /// 判断条目是否匹配 exclude glob（扫描根本身永远不会被排除）
+    fn is_excluded(entry: &DirEntry, root_path: &Path, globs: &GlobSet) -> bool {
+        if entry.depth() == 0 {
+            return false;
+        }
+
+        let path = entry.path();
+        let relative = path.strip_prefix(root_path).unwrap_or(path);
+        let excluded = globs.is_match(relative) || globs.is_match(path);
+        if excluded {
+            debug!("匹配 exclude glob，跳过: {:?}", path);
+        }
+        excluded
+    }
+
+    /// 编译 glob 列表（`what` 用于错误信息），为空时返回 `None`
+    fn build_globset(patterns: &[String], what: &str) -> Result<Option<GlobSet>> {
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob =
+                Glob::new(pattern).with_context(|| format!("无效的 {what} glob: {pattern}"))?;
+            builder.add(glob);
+        }
+        Ok(Some(
+            builder
+                .build()
+                .with_context(|| format!("构建 {what} glob 失败"))?,
+        ))
+    }
+
     /// 处理单个目录条目
     fn process_entry(
         &self,
         entry: DirEntry,
+        root_path: &Path,
+        manifest_globs: Option<&GlobSet>,
         found_count: &AtomicUsize,
         on_cargo_toml_found: Option<&(dyn Fn(usize) + Sync)>,
     ) -> Option<PathBuf> {
@@ -203,12 +699,26 @@ impl ProjectScanner {
 
         // 检查是否为Cargo.toml文件
         if path.file_name()? == "Cargo.toml" && path.is_file() {
+            // Cargo.toml 比所在的项目目录深一层
+            if let Some(min_depth) = self.config.min_depth
+                && entry.depth().saturating_sub(1) < min_depth
+            {
+                debug!("项目深度小于 min_depth，跳过: {:?}", path);
+                return None;
+            }
+
+            if let Some(globs) = manifest_globs {
+                let relative = path.strip_prefix(root_path).unwrap_or(path);
+                if !globs.is_match(relative) && !globs.is_match(path) {
+                    debug!("Cargo.toml 不匹配 manifest glob，跳过: {:?}", path);
+                    return None;
+                }
+            }
+
             debug!("发现Cargo.toml: {:?}", path);
             let count = found_count.fetch_add(1, Ordering::Relaxed) + 1;
-            if count % 50 == 0 {
-                if let Some(callback) = on_cargo_toml_found {
-                    callback(count);
-                }
+            if let Some(callback) = on_cargo_toml_found {
+                callback(count);
             }
             return path.parent().map(|p| p.to_path_buf());
         }
@@ -217,29 +727,33 @@ impl ProjectScanner {
     }
 
     /// 并行处理项目（带缓存优化）
-    fn process_projects_parallel(&self, cargo_dirs: Vec<PathBuf>) -> Result<Vec<RustProject>> {
+    fn process_projects_parallel(
+        &self,
+        cargo_dirs: Vec<PathBuf>,
+        cancel_flag: Option<&AtomicBool>,
+        size_cache: Option<&SizeCache>,
+    ) -> Result<Vec<RustProject>> {
         let cache = Arc::clone(&self.cache);
-        let lazy_size_calculation = self.config.lazy_size_calculation;
+        let config = &self.config;
 
         let projects: Vec<_> = cargo_dirs
             .into_par_iter()
             .filter_map(|dir| {
+                // 取消后跳过剩余项目（计算大小可能很慢）
+                if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                    return None;
+                }
+
                 // 先检查缓存
-                if let Ok(cache_guard) = cache.lock() {
-                    if let Some(cached_project) = cache_guard.get(&dir) {
-                        debug!("从缓存获取项目: {}", cached_project.name);
-                        return Some(cached_project.clone());
-                    }
+                if let Ok(cache_guard) = cache.lock()
+                    && let Some(cached_project) = cache_guard.get(&dir)
+                {
+                    debug!("从缓存获取项目: {}", cached_project.name);
+                    return Some(cached_project.clone());
                 }
 
                 // 缓存未命中，解析项目
-                let project_result = if lazy_size_calculation {
-                    RustProject::from_path_lazy(&dir)
-                } else {
-                    RustProject::from_path(&dir)
-                };
-
-                match project_result {
+                match Self::load_project(&dir, config, size_cache) {
                     Ok(project) => {
                         debug!("成功解析项目: {}", project.name);
 
@@ -262,17 +776,20 @@ impl ProjectScanner {
     }
 
     /// 串行处理项目
-    fn process_projects_sequential(&self, cargo_dirs: Vec<PathBuf>) -> Result<Vec<RustProject>> {
+    fn process_projects_sequential(
+        &self,
+        cargo_dirs: Vec<PathBuf>,
+        cancel_flag: Option<&AtomicBool>,
+        size_cache: Option<&SizeCache>,
+    ) -> Result<Vec<RustProject>> {
         let mut projects = Vec::new();
 
         for dir in cargo_dirs {
-            let project_result = if self.config.lazy_size_calculation {
-                RustProject::from_path_lazy(&dir)
-            } else {
-                RustProject::from_path(&dir)
-            };
+            if cancel_flag.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                break;
+            }
 
-            match project_result {
+            match Self::load_project(&dir, &self.config, size_cache) {
                 Ok(project) => {
                     debug!("成功解析项目: {}", project.name);
                     projects.push(project);
@@ -288,20 +805,112 @@ impl ProjectScanner {
     }
 
     /// 扫描单个项目（用于验证特定路径）
-    pub fn scan_single<P: AsRef<Path>>(&self, project_path: P) -> Result<RustProject> {
+    pub fn scan_single<P: AsRef<Path>>(&self, project_path: P) -> PurgerResult<RustProject> {
         let project_path = project_path.as_ref();
 
         if !project_path.join("Cargo.toml").exists() {
-            anyhow::bail!("路径不是Rust项目: {:?}", project_path);
+            return Err(PurgerError::NotARustProject(project_path.to_path_buf()));
         }
 
-        let project_result = if self.config.lazy_size_calculation {
-            RustProject::from_path_lazy(project_path)
-        } else {
-            RustProject::from_path(project_path)
+        Ok(Self::load_project(project_path, &self.config, None).context("解析Rust项目失败")?)
+    }
+
+    /// 直接从给定的项目目录构建项目列表（不遍历目录树）
+    ///
+    /// 并行解析每个路径，返回成功解析的项目以及解析失败的路径和错误，结果保持输入顺序。
+    pub fn projects_from_paths(
+        &self,
+        paths: &[PathBuf],
+    ) -> (Vec<RustProject>, Vec<(PathBuf, anyhow::Error)>) {
+        let config = &self.config;
+        let results: Vec<_> = crate::with_thread_pool(config.jobs, || {
+            paths
+                .par_iter()
+                .map(|path| (path, Self::load_project(path, config, None)))
+                .collect()
+        });
+
+        let mut projects = Vec::new();
+        let mut errors = Vec::new();
+        for (path, result) in results {
+            match result {
+                Ok(project) => projects.push(project),
+                Err(e) => {
+                    warn!("解析项目失败 {:?}: {}", path, e);
+                    errors.push((path.clone(), e));
+                }
+            }
+        }
+        (projects, errors)
+    }
+
+    /// 解析单个项目目录，按配置决定是否计算 target 大小
+    fn load_project(
+        dir: &Path,
+        config: &ScanConfig,
+        size_cache: Option<&SizeCache>,
+    ) -> Result<RustProject> {
+        let Some(size_cache) = size_cache else {
+            let mut project = RustProject::from_path_with_options(
+                dir,
+                config.lazy_size_calculation,
+                config.dedup_hardlinks,
+                config.time_basis,
+                config.size_mode,
+            )?;
+            project.add_extra_targets(
+                &config.extra_target_names,
+                config.lazy_size_calculation,
+                config.dedup_hardlinks,
+                config.size_mode,
+            );
+            return Ok(project);
         };
 
-        project_result.context("解析Rust项目失败")
+        // 先不计算大小，命中缓存时直接复用，否则再计算
+        let mut project = RustProject::from_path_with_options(
+            dir,
+            true,
+            config.dedup_hardlinks,
+            config.time_basis,
+            config.size_mode,
+        )?;
+        if let Some(cached) = size_cache.lookup(&project) {
+            debug!("从磁盘缓存获取项目大小: {}", project.name);
+            project.target_size = cached.target_size;
+            project.largest_file = cached.largest_file.clone();
+        } else {
+            project.compute_size_with(config.dedup_hardlinks, config.size_mode);
+        }
+        Ok(project)
+    }
+
+    /// 当前扫描使用的磁盘缓存文件（未启用缓存或延迟计算大小时为 `None`）
+    fn cache_file(&self, root_path: &Path) -> Option<PathBuf> {
+        // 缓存只按主 target 的修改时间判断是否失效，无法覆盖额外的构建输出目录
+        if !self.config.use_cache
+            || self.config.lazy_size_calculation
+            || !self.config.extra_target_names.is_empty()
+        {
+            return None;
+        }
+        SizeCache::file_for(self.config.cache_dir.as_deref(), root_path)
+    }
+
+    /// 写回磁盘缓存，失败时只记录警告
+    fn save_cache(&self, cache_file: Option<&Path>, root_path: &Path, projects: &[RustProject]) {
+        let Some(cache_file) = cache_file else {
+            return;
+        };
+        let cache = SizeCache::from_projects(
+            root_path,
+            self.config.dedup_hardlinks,
+            self.config.size_mode,
+            projects,
+        );
+        if let Err(e) = cache.save(cache_file) {
+            warn!("保存扫描缓存失败 {:?}: {}", cache_file, e);
+        }
     }
 
     /// 过滤有target目录的项目
@@ -309,17 +918,23 @@ impl ProjectScanner {
         projects.into_iter().filter(|p| p.has_target).collect()
     }
 
+    /// 拆分出带有 `.purger-keep` 标记的项目，返回 (可清理, 已固定)
+    pub fn partition_pinned(projects: Vec<RustProject>) -> (Vec<RustProject>, Vec<RustProject>) {
+        projects.into_iter().partition(|p| !p.pinned)
+    }
+
     /// 按大小排序项目（从大到小）
     pub fn sort_by_size(mut projects: Vec<RustProject>) -> Vec<RustProject> {
-        projects.sort_by(|a, b| b.target_size.cmp(&a.target_size));
+        projects.sort_by_key(|p| std::cmp::Reverse(p.target_size));
         projects
     }
 
     /// 应用过滤器
     fn apply_filters(&self, projects: Vec<RustProject>) -> Vec<RustProject> {
         // 如果没有配置任何过滤条件，直接返回
-        if self.config.keep_days.is_none()
+        if self.config.keep_threshold().is_none()
             && self.config.keep_size.is_none()
+            && self.config.min_clean_size.is_none()
             && self.config.ignore_paths.is_empty()
         {
             return projects;
@@ -388,6 +1003,174 @@ edition = "2021"
         Ok(())
     }
 
+    #[test]
+    fn test_scan_lazy_size_calculation() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_project(root, "lazy_project", true)?;
+
+        let scanner = ProjectScanner::new(ScanConfig {
+            lazy_size_calculation: true,
+            ..Default::default()
+        });
+        let mut projects = scanner.scan(root)?;
+        assert_eq!(projects.len(), 1);
+
+        let project = &mut projects[0];
+        assert!(project.has_target);
+        assert_ne!(project.last_modified, SystemTime::UNIX_EPOCH);
+        assert_eq!(project.target_size, 0);
+        assert!(project.largest_file.is_none());
+
+        assert_eq!(project.compute_size(), "test content".len() as u64);
+        assert_eq!(project.target_size, "test content".len() as u64);
+        assert!(project.largest_file.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_many_dedups_overlapping_roots() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let work = temp_dir.path().join("work");
+        let oss = temp_dir.path().join("oss");
+        create_test_project(&work, "app", true)?;
+        create_test_project(&work, "lib", false)?;
+        create_test_project(&oss, "tool", true)?;
+
+        let scanner = ProjectScanner::default();
+        // work/app 同时出现在 work 和 work/app 两个根下
+        let projects = scanner.scan_many(&[work.clone(), oss, work.join("app")])?;
+
+        let mut names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["app", "lib", "tool"]);
+
+        assert!(
+            scanner
+                .scan_many(&[temp_dir.path().join("missing")])
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_with_progress() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        for i in 0..5 {
+            create_test_project(root, &format!("project{i}"), i % 2 == 0)?;
+        }
+
+        let calls = AtomicUsize::new(0);
+        let max_seen = AtomicUsize::new(0);
+        let projects = ProjectScanner::default().scan_with_progress(root, |found| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            max_seen.fetch_max(found, Ordering::Relaxed);
+        })?;
+
+        assert_eq!(projects.len(), 5);
+        assert_eq!(calls.load(Ordering::Relaxed), 5);
+        assert_eq!(max_seen.load(Ordering::Relaxed), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_cancellable() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        for i in 0..20 {
+            create_test_project(&root.join(format!("group{i}")), "project", true)?;
+        }
+
+        let scanner = ProjectScanner::default();
+        let cancel = AtomicBool::new(true);
+        let start = std::time::Instant::now();
+        let err = scanner.scan_cancellable(root, &cancel).unwrap_err();
+        assert!(matches!(err, PurgerError::Cancelled));
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+
+        cancel.store(false, Ordering::Relaxed);
+        assert_eq!(scanner.scan_cancellable(root, &cancel)?.len(), 20);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_uses_disk_cache() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path().join("projects");
+        create_test_project(&root, "cached", true)?;
+        let config = ScanConfig {
+            use_cache: true,
+            cache_dir: Some(temp_dir.path().join("cache")),
+            ..Default::default()
+        };
+        let scan = || -> Result<u64> {
+            let projects = ProjectScanner::new(config.clone()).scan(&root)?;
+            Ok(projects[0].target_size)
+        };
+
+        let real_size = scan()?;
+        assert!(real_size > 0);
+
+        // 篡改缓存中的大小：target 未变化时应直接返回缓存值
+        let cache_file = SizeCache::file_for(config.cache_dir.as_deref(), &root).unwrap();
+        let mut cache: serde_json::Value = serde_json::from_str(&fs::read_to_string(&cache_file)?)?;
+        cache["projects"][0]["target_size"] = 424242.into();
+        fs::write(&cache_file, cache.to_string())?;
+        assert_eq!(scan()?, 424242);
+
+        // target 修改时间变化后重新计算
+        let target = root.join("cached").join("target");
+        fs::File::open(&target)?
+            .set_modified(SystemTime::now() - std::time::Duration::from_secs(3600))?;
+        assert_eq!(scan()?, real_size);
+
+        // 不启用缓存时总是重新计算
+        fs::write(&cache_file, cache.to_string())?;
+        let uncached = ProjectScanner::new(ScanConfig {
+            use_cache: false,
+            ..config.clone()
+        });
+        assert_eq!(uncached.scan(&root)?[0].target_size, real_size);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_cache_detects_rebuild() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path().join("projects");
+        create_test_project(&root, "rebuilt", true)?;
+        let target = root.join("rebuilt").join("target");
+        let deps = target.join("debug").join("deps");
+        fs::create_dir_all(&deps)?;
+        fs::write(deps.join("librebuilt.rlib"), "old")?;
+        let old = SystemTime::now() - std::time::Duration::from_secs(3600);
+        for dir in [&deps, &target.join("debug"), &target] {
+            fs::File::open(dir)?.set_modified(old)?;
+        }
+        let config = ScanConfig {
+            use_cache: true,
+            cache_dir: Some(temp_dir.path().join("cache")),
+            ..Default::default()
+        };
+        let scan = || -> Result<u64> {
+            let projects = ProjectScanner::new(config.clone()).scan(&root)?;
+            Ok(projects[0].target_size)
+        };
+        let before = scan()?;
+
+        // 重新编译只改动 target/debug/deps，target 本身的修改时间不变
+        fs::write(deps.join("librebuilt-new.rlib"), "rebuilt artifact")?;
+        fs::File::open(&target)?.set_modified(old)?;
+        assert_eq!(scan()?, before + "rebuilt artifact".len() as u64);
+
+        Ok(())
+    }
+
     #[test]
     fn test_scan_single() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -404,6 +1187,78 @@ edition = "2021"
         Ok(())
     }
 
+    #[test]
+    fn test_scan_with_min_depth() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "active", true)?;
+        let archive = root.join("archive").join("2023");
+        std::fs::create_dir_all(&archive)?;
+        create_test_project(&archive, "experiment", true)?;
+
+        let config = ScanConfig {
+            min_depth: Some(2),
+            ..Default::default()
+        };
+        let projects = ProjectScanner::new(config.clone()).scan(root)?;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["experiment"]);
+
+        // 扫描根本身是项目时也遵守 min_depth
+        let projects = ProjectScanner::new(config).scan(root.join("active"))?;
+        assert!(projects.is_empty());
+
+        let projects = ProjectScanner::new(ScanConfig {
+            min_depth: Some(1),
+            ..Default::default()
+        })
+        .scan(root)?;
+        assert_eq!(projects.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_with_extra_target_names() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "wasm_app", false)?;
+        let extra = root.join("wasm_app").join("target-wasm");
+        fs::create_dir_all(&extra)?;
+        fs::write(extra.join("app.wasm"), "wasm binary")?;
+        fs::write(extra.join(".rustc_info.json"), "{}")?;
+        // 没有 cargo 标记文件的目录不算构建输出
+        let assets = root.join("wasm_app").join("assets");
+        fs::create_dir_all(&assets)?;
+        fs::write(assets.join("logo.png"), "png")?;
+
+        // 默认只认 target
+        let projects = ProjectScanner::default().scan(root)?;
+        assert_eq!(projects.len(), 1);
+        assert!(!projects[0].has_target);
+        assert!(projects[0].extra_targets.is_empty());
+
+        let projects = ProjectScanner::new(ScanConfig {
+            extra_target_names: vec![
+                "target-wasm".to_string(),
+                "assets".to_string(),
+                "../escape".to_string(),
+            ],
+            ..Default::default()
+        })
+        .scan(root)?;
+        assert_eq!(projects.len(), 1);
+        assert!(projects[0].has_target);
+        assert_eq!(projects[0].extra_targets, vec![extra]);
+        let size = ("wasm binary".len() + "{}".len()) as u64;
+        assert_eq!(projects[0].target_size, size);
+        assert_eq!(projects[0].get_target_size(), size);
+
+        Ok(())
+    }
+
     #[test]
     fn test_scan_with_max_depth() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -499,16 +1354,30 @@ edition = "2021"
                 name: "test1".to_string(),
                 target_size: 1000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: PathBuf::from("/test2"),
                 name: "test2".to_string(),
                 target_size: 0,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: false,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
         ];
 
@@ -525,24 +1394,45 @@ edition = "2021"
                 name: "small".to_string(),
                 target_size: 100,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: PathBuf::from("/large"),
                 name: "large".to_string(),
                 target_size: 1000,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
             RustProject {
                 path: PathBuf::from("/medium"),
                 name: "medium".to_string(),
                 target_size: 500,
                 last_modified: SystemTime::now(),
-                is_workspace: false,
+                is_workspace_root: false,
+                is_package: true,
+                is_workspace_member: false,
                 has_target: true,
+                largest_file: None,
+                target_dir: None,
+                members: Vec::new(),
+                extra_targets: Vec::new(),
+                pinned: false,
             },
         ];
 
@@ -559,6 +1449,342 @@ edition = "2021"
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_scan_cargo_toml_file_as_root() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "manifest_project", true)?;
+        create_test_project(root, "other_project", true)?;
+
+        let scanner = ProjectScanner::default();
+        let projects = scanner.scan(root.join("manifest_project").join("Cargo.toml"))?;
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "manifest_project");
+        assert!(projects[0].has_target);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_project_dir_as_root() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "single_project", true)?;
+        // 项目内部的嵌套 crate 不应被当作独立项目
+        create_test_project(&root.join("single_project"), "nested", false)?;
+
+        let scanner = ProjectScanner::default();
+        let projects = scanner.scan(root.join("single_project"))?;
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "single_project");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_directory_tree_and_workspace_root() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "project1", true)?;
+        create_test_project(&root.join("group"), "project2", false)?;
+
+        let scanner = ProjectScanner::default();
+        assert_eq!(scanner.scan(root)?.len(), 2);
+
+        // workspace 根目录仍然遍历以发现成员
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"project1\"]\n",
+        )?;
+        let projects = scanner.scan(root)?;
+        assert_eq!(projects.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_collapses_workspace_members() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path().join("ws");
+        create_test_project(&root.join("crates"), "member_a", false)?;
+        create_test_project(&root.join("crates"), "member_b", false)?;
+        // 有独立 target 的子项目不属于共享 target
+        create_test_project(&root, "standalone", true)?;
+        // 被排除、还没编译过的子项目不使用 workspace 的 target，也不合并
+        create_test_project(&root, "scratch", false)?;
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"scratch\"]\n",
+        )?;
+        fs::create_dir_all(root.join("target"))?;
+        fs::write(root.join("target").join("shared.rlib"), "shared")?;
+
+        let scanner = ProjectScanner::default();
+        let projects = scanner.scan(temp_dir.path())?;
+        assert_eq!(projects.len(), 3);
+        assert!(projects.iter().any(|p| p.name == "scratch"));
+
+        let cleanable = ProjectScanner::filter_with_target(projects);
+        let workspace = cleanable.iter().find(|p| p.is_workspace_root).unwrap();
+        assert_eq!(workspace.path, root);
+        assert_eq!(workspace.members, vec!["member_a", "member_b"]);
+        assert!(cleanable.iter().any(|p| p.name == "standalone"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_collapses_shared_target_dir() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_project(root, "app_a", false)?;
+        create_test_project(root, "app_b", false)?;
+        create_test_project(root, "own", true)?;
+        // 两个项目通过共同的 build.target-dir 使用同一个 target，与全局 CARGO_TARGET_DIR 相同
+        for name in ["app_a", "app_b"] {
+            fs::create_dir_all(root.join(name).join(".cargo"))?;
+            fs::write(
+                root.join(name).join(".cargo").join("config.toml"),
+                "[build]\ntarget-dir = \"../shared-target\"\n",
+            )?;
+        }
+        fs::create_dir_all(root.join("shared-target").join("debug"))?;
+        fs::write(
+            root.join("shared-target").join("debug").join("app"),
+            "0123456789",
+        )?;
+
+        let scanner = ProjectScanner::default();
+        let check = |projects: Vec<RustProject>| {
+            let mut projects = ProjectScanner::filter_with_target(projects);
+            projects.sort_by(|a, b| a.name.cmp(&b.name));
+            let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+            assert_eq!(names, ["app_a", "own"]);
+            assert_eq!(projects[0].members, vec!["app_b"]);
+            assert_eq!(projects[0].target_size, 10);
+        };
+        if std::env::var_os("CARGO_TARGET_DIR").is_none() {
+            check(scanner.scan(root)?);
+            // 分别作为扫描根时同样只计算一次
+            check(scanner.scan_many(&[
+                root.join("app_a"),
+                root.join("app_b"),
+                root.join("own"),
+            ])?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_pinned_project() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        create_test_project(temp_dir.path(), "keep_me", true)?;
+        create_test_project(temp_dir.path(), "clean_me", true)?;
+        fs::write(temp_dir.path().join("keep_me").join(crate::KEEP_MARKER), "")?;
+
+        let projects = ProjectScanner::default().scan(temp_dir.path())?;
+        let (cleanable, pinned) =
+            ProjectScanner::partition_pinned(ProjectScanner::filter_with_target(projects));
+        assert_eq!(cleanable.len(), 1);
+        assert_eq!(cleanable[0].name, "clean_me");
+        assert!(!cleanable[0].pinned);
+        assert_eq!(pinned.len(), 1);
+        assert_eq!(pinned[0].name, "keep_me");
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_follow_links_cycle() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_project(root, "app", true)?;
+        create_test_project(&root.join("libs"), "util", false)?;
+        // 指向祖先目录的链接会成环，指向已扫描目录的链接会重复
+        std::os::unix::fs::symlink(root, root.join("app").join("loop"))?;
+        std::os::unix::fs::symlink(root.join("libs"), root.join("libs-alias"))?;
+
+        let scanner = ProjectScanner::new(ScanConfig {
+            follow_links: true,
+            max_depth: None,
+            ..Default::default()
+        });
+        let mut names: Vec<String> = scanner
+            .scan(root)?
+            .into_iter()
+            .map(|project| project.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["app", "util"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_non_manifest_file_as_root() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let file = temp_dir.path().join("README.md");
+        fs::write(&file, "not a manifest")?;
+
+        let scanner = ProjectScanner::default();
+        assert!(scanner.scan(&file).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_error_variants() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let scanner = ProjectScanner::default();
+
+        let missing = temp_dir.path().join("missing");
+        assert!(matches!(
+            scanner.scan(&missing),
+            Err(PurgerError::PathNotFound(path)) if path == missing
+        ));
+
+        let file = temp_dir.path().join("file.txt");
+        fs::write(&file, "not a directory")?;
+        assert!(matches!(
+            scanner.scan(&file),
+            Err(PurgerError::NotADirectory(_))
+        ));
+
+        assert!(matches!(
+            scanner.scan_single(temp_dir.path()),
+            Err(PurgerError::NotARustProject(_))
+        ));
+
+        // 无法解析的 Cargo.toml 不算错误，按目录名回退（见 test_scan_corrupted_cargo_toml）
+        let corrupted = temp_dir.path().join("corrupted");
+        fs::create_dir_all(&corrupted)?;
+        fs::write(corrupted.join("Cargo.toml"), "[[[[")?;
+        assert_eq!(scanner.scan_single(&corrupted)?.name, "corrupted");
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_refuses_dangerous_root() {
+        let scanner = ProjectScanner::default();
+        let err = scanner.scan("/").unwrap_err();
+        assert!(err.downcast_ref::<crate::safety::DangerousPath>().is_some());
+    }
+
+    #[test]
+    fn test_scan_with_manifest_globs() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        fs::create_dir_all(root.join("crates"))?;
+        fs::create_dir_all(root.join("vendor"))?;
+        create_test_project(&root.join("crates"), "mine", true)?;
+        create_test_project(&root.join("vendor"), "third_party", true)?;
+
+        let config = ScanConfig {
+            manifest_globs: vec!["crates/**/Cargo.toml".to_string()],
+            ..Default::default()
+        };
+        let projects = ProjectScanner::new(config).scan(root)?;
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "mine");
+
+        // 无效 glob 应返回错误
+        let config = ScanConfig {
+            manifest_globs: vec!["crates/[".to_string()],
+            ..Default::default()
+        };
+        assert!(ProjectScanner::new(config).scan(root).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_with_exclude_patterns() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_project(&root.join("third_party").join("vendor"), "vendored", true)?;
+        create_test_project(root, "bindings-generated", true)?;
+        create_test_project(&root.join("third_party"), "kept", true)?;
+
+        let scanner = ProjectScanner::new(ScanConfig {
+            exclude_patterns: vec!["**/vendor/**".to_string(), "*-generated".to_string()],
+            ..Default::default()
+        });
+        let projects = scanner.scan(root)?;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["kept"]);
+
+        let invalid = ProjectScanner::new(ScanConfig {
+            exclude_patterns: vec!["a{".to_string()],
+            ..Default::default()
+        });
+        assert!(invalid.scan(root).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_prunes_unmodified_dirs() -> Result<()> {
+        use std::time::Duration;
+
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_project(root, "fresh", true)?;
+        create_test_project(root, "stale", true)?;
+
+        let old = SystemTime::now() - Duration::from_secs(30 * 24 * 3600);
+        fs::File::open(root.join("stale"))?.set_modified(old)?;
+
+        let config = ScanConfig {
+            prune_unmodified_since: Some(SystemTime::now() - Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        let projects = ProjectScanner::new(config).scan(root)?;
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["fresh"]);
+
+        // 不设置阈值时两个项目都能找到
+        let projects = ProjectScanner::default().scan(root)?;
+        assert_eq!(projects.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_projects_from_paths() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "alpha", true)?;
+        create_test_project(root, "beta", false)?;
+        fs::create_dir_all(root.join("not-a-project"))?;
+
+        let scanner = ProjectScanner::default();
+        let paths = vec![
+            root.join("beta"),
+            root.join("not-a-project"),
+            root.join("alpha"),
+        ];
+        let (projects, errors) = scanner.projects_from_paths(&paths);
+
+        let names: Vec<_> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["beta", "alpha"]);
+        assert!(projects[1].target_size > 0);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, root.join("not-a-project"));
+        Ok(())
+    }
+
     #[test]
     fn test_scan_single_invalid_project() {
         let temp_dir = TempDir::new().unwrap();
@@ -613,7 +1839,7 @@ edition = "2021"
         let scanner = ProjectScanner::default();
         let project = scanner.scan_single(&project_path)?;
         assert_eq!(project.name, "corrupted_project");
-        assert!(!project.is_workspace);
+        assert!(!project.is_workspace_root);
         assert!(!project.has_target);
 
         Ok(())
@@ -643,6 +1869,154 @@ edition = "2021"
         Ok(())
     }
 
+    #[test]
+    fn test_scan_corrupted_manifest_parallel_and_sequential() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        create_test_project(root, "good_project", true)?;
+        let corrupted_path = root.join("corrupted_project");
+        std::fs::create_dir_all(&corrupted_path)?;
+        std::fs::write(corrupted_path.join("Cargo.toml"), "[package\nname = ")?;
+
+        // 损坏的清单不会中断并行扫描，两种模式结果一致
+        for parallel in [true, false] {
+            let scanner = ProjectScanner::new(ScanConfig {
+                parallel,
+                ..Default::default()
+            });
+            let mut names: Vec<_> = scanner.scan(root)?.into_iter().map(|p| p.name).collect();
+            names.sort();
+            assert_eq!(
+                names,
+                ["corrupted_project", "good_project"],
+                "parallel={parallel}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_purgerignore() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_project(root, "kept", true)?;
+        fs::create_dir_all(root.join("vendored"))?;
+        create_test_project(&root.join("vendored"), "third_party", true)?;
+        // 不在 git 仓库中也生效
+        fs::write(root.join(PURGERIGNORE_FILENAME), "vendored/\n")?;
+
+        let names = |config: ScanConfig| -> Result<Vec<String>> {
+            let mut names: Vec<_> = ProjectScanner::new(config)
+                .scan(root)?
+                .into_iter()
+                .map(|p| p.name)
+                .collect();
+            names.sort();
+            Ok(names)
+        };
+
+        assert_eq!(names(ScanConfig::default())?, ["kept"]);
+        assert_eq!(
+            names(ScanConfig {
+                parallel: false,
+                ..Default::default()
+            })?,
+            ["kept"]
+        );
+        assert_eq!(
+            names(ScanConfig {
+                purgerignore: false,
+                ..Default::default()
+            })?,
+            ["kept", "third_party"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_orphans() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        // 源码已删除，只剩 cargo 生成的 target
+        let orphan_target = root.join("deleted_project").join("target");
+        fs::create_dir_all(orphan_target.join("debug"))?;
+        fs::write(
+            orphan_target.join("CACHEDIR.TAG"),
+            "Signature: 8a477f597d28d172789f06886806bc55",
+        )?;
+        fs::write(orphan_target.join("debug").join("app"), vec![0u8; 1000])?;
+
+        // Cargo.toml 无法解析的项目同样视为孤立
+        let broken = root.join("broken");
+        fs::create_dir_all(broken.join("target"))?;
+        fs::write(broken.join("Cargo.toml"), "[[[[")?;
+        fs::write(broken.join("target").join(".rustc_info.json"), "{}")?;
+
+        // 正常项目的 target 和非 cargo 生成的 target（如 maven）不算
+        create_test_project(root, "alive", true)?;
+        fs::write(root.join("alive").join("target").join("CACHEDIR.TAG"), "")?;
+        fs::create_dir_all(root.join("java").join("target").join("classes"))?;
+
+        let scanner = ProjectScanner::default();
+        let mut orphans = scanner.scan_orphans(root)?;
+        orphans.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let names: Vec<_> = orphans.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["broken", "deleted_project"]);
+        let orphan = &orphans[1];
+        assert!(orphan.has_target);
+        assert_eq!(orphan.target_path(), orphan_target);
+        assert!(orphan.target_size >= 1000);
+
+        // 普通扫描不会报告没有 Cargo.toml 的目录
+        let projects = scanner.scan(root)?;
+        assert!(!projects.iter().any(|p| p.name == "deleted_project"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_orphans_respects_scan_rules() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        for dir in [
+            "shallow",
+            "a/kept",
+            "vendor/old",
+            "skipped/old",
+            "git/ignored",
+        ] {
+            let target = root.join(dir).join("target");
+            fs::create_dir_all(&target)?;
+            fs::write(
+                target.join("CACHEDIR.TAG"),
+                "Signature: 8a477f597d28d172789f06886806bc55",
+            )?;
+        }
+        fs::write(root.join(PURGERIGNORE_FILENAME), "skipped/\n")?;
+        fs::create_dir_all(root.join(".git"))?;
+        fs::write(root.join(".gitignore"), "git/\ntarget/\n")?;
+
+        let scanner = ProjectScanner::new(ScanConfig {
+            min_depth: Some(2),
+            exclude_patterns: vec!["**/vendor/**".to_string()],
+            ..Default::default()
+        });
+        let names: Vec<_> = scanner
+            .scan_orphans(root)?
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        // 被 .gitignore 忽略的 target/ 本身仍能找到
+        assert_eq!(names, ["kept"]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_scan_empty_directory() -> Result<()> {
         let temp_dir = TempDir::new()?;
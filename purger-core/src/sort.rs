@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::time::SystemTime;
+
+use crate::project::RustProject;
+
+/// 项目列表的排序依据（CLI 的 `--sort` 与 GUI 的排序共用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortKey {
+    Name,
+    /// target 大小；没有 target 或大小未知的项目无论正序倒序都排在最后
+    #[default]
+    Size,
+    /// 最后修改时间（精确到秒）
+    Modified,
+    Path,
+}
+
+/// 按 `key` 比较两个项目，`reverse` 为 false 时从小到大；大小相同时按名称、路径决出顺序
+pub fn compare_projects(a: &RustProject, b: &RustProject, key: SortKey, reverse: bool) -> Ordering {
+    let directed = |ordering: Ordering| {
+        if reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+
+    match key {
+        SortKey::Name => directed(a.name.cmp(&b.name)),
+        SortKey::Size => compare_optional(size_key(a), size_key(b), reverse)
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.path.cmp(&b.path)),
+        SortKey::Modified => {
+            directed(system_time_key(a.last_modified).cmp(&system_time_key(b.last_modified)))
+        }
+        SortKey::Path => directed(a.path.cmp(&b.path)),
+    }
+}
+
+/// 稳定排序：比较结果相同的项目保持原有（扫描）顺序
+pub fn sort_projects(projects: &mut [RustProject], key: SortKey, reverse: bool) {
+    projects.sort_by(|a, b| compare_projects(a, b, key, reverse));
+}
+
+fn system_time_key(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn size_key(project: &RustProject) -> Option<u64> {
+    if !project.has_target || project.target_size == 0 {
+        return None;
+    }
+    Some(project.target_size)
+}
+
+fn compare_optional(a: Option<u64>, b: Option<u64>, reverse: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if reverse => b.cmp(&a),
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn project(name: &str, path: &str, target_size: u64, modified_secs: u64) -> RustProject {
+        RustProject {
+            path: PathBuf::from(path),
+            name: name.to_string(),
+            target_size,
+            last_modified: SystemTime::UNIX_EPOCH + Duration::from_secs(modified_secs),
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
+            has_target: target_size > 0,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
+        }
+    }
+
+    fn sorted_names(key: SortKey, reverse: bool) -> Vec<String> {
+        let mut projects = vec![
+            project("beta", "/b", 300, 20),
+            project("alpha", "/c", 100, 30),
+            project("gamma", "/a", 0, 10),
+            project("delta", "/d", 200, 40),
+        ];
+        sort_projects(&mut projects, key, reverse);
+        projects.into_iter().map(|p| p.name).collect()
+    }
+
+    #[test]
+    fn test_sort_by_name() {
+        assert_eq!(
+            sorted_names(SortKey::Name, false),
+            ["alpha", "beta", "delta", "gamma"]
+        );
+        assert_eq!(
+            sorted_names(SortKey::Name, true),
+            ["gamma", "delta", "beta", "alpha"]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_size() {
+        // 没有 target 的项目总在最后
+        assert_eq!(
+            sorted_names(SortKey::Size, false),
+            ["alpha", "delta", "beta", "gamma"]
+        );
+        assert_eq!(
+            sorted_names(SortKey::Size, true),
+            ["beta", "delta", "alpha", "gamma"]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_modified() {
+        assert_eq!(
+            sorted_names(SortKey::Modified, false),
+            ["gamma", "beta", "alpha", "delta"]
+        );
+        assert_eq!(
+            sorted_names(SortKey::Modified, true),
+            ["delta", "alpha", "beta", "gamma"]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_path() {
+        assert_eq!(
+            sorted_names(SortKey::Path, false),
+            ["gamma", "beta", "alpha", "delta"]
+        );
+        assert_eq!(
+            sorted_names(SortKey::Path, true),
+            ["delta", "alpha", "beta", "gamma"]
+        );
+    }
+
+    #[test]
+    fn test_sort_is_stable_for_ties() {
+        let mut projects = vec![
+            project("same", "/first", 100, 10),
+            project("same", "/second", 100, 10),
+        ];
+        sort_projects(&mut projects, SortKey::Modified, true);
+        assert_eq!(projects[0].path, PathBuf::from("/first"));
+        assert_eq!(projects[1].path, PathBuf::from("/second"));
+    }
+}
@@ -0,0 +1,202 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Component, Path};
+use std::process::{Command, Stdio};
+use walkdir::WalkDir;
+
+/// 工作区 target 占用空间按成员的归属（启发式）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemberAttribution {
+    /// 成员名及其归属的字节数，按字节数降序
+    pub members: Vec<(String, u64)>,
+    /// 无法判断归属的字节数（依赖、共享产物等）
+    pub unattributed: u64,
+}
+
+impl MemberAttribution {
+    pub fn total(&self) -> u64 {
+        self.members.iter().map(|(_, size)| size).sum::<u64>() + self.unattributed
+    }
+}
+
+/// 通过 `cargo metadata --no-deps` 获取工作区成员的包名
+pub fn workspace_member_names(workspace_root: &Path) -> Result<Vec<String>> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .current_dir(workspace_root)
+        .stdin(Stdio::null())
+        .output()
+        .context("执行cargo metadata失败")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("cargo metadata失败: {}", stderr.trim());
+    }
+
+    parse_member_names(&output.stdout)
+}
+
+/// 从 `cargo metadata` 的 JSON 输出中提取工作区成员包名
+fn parse_member_names(metadata: &[u8]) -> Result<Vec<String>> {
+    let value: serde_json::Value =
+        serde_json::from_slice(metadata).context("解析cargo metadata输出失败")?;
+    let members: Vec<&str> = value["workspace_members"]
+        .as_array()
+        .map(|ids| ids.iter().filter_map(|id| id.as_str()).collect())
+        .unwrap_or_default();
+
+    let names = value["packages"]
+        .as_array()
+        .map(|packages| {
+            packages
+                .iter()
+                .filter(|package| {
+                    package["id"]
+                        .as_str()
+                        .is_some_and(|id| members.contains(&id))
+                })
+                .filter_map(|package| package["name"].as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(names)
+}
+
+/// 按产物命名规则把 target 中的文件归属到工作区成员
+///
+/// 依次检查相对路径的每一级（如 `debug/deps/libfoo-1a2b.rlib`、`debug/.fingerprint/foo-1a2b/...`、
+/// `debug/build/foo-1a2b/...`、`debug/incremental/foo-1a2b/...`），去掉 `lib` 前缀、扩展名和
+/// 哈希后缀后与成员名比较，第一个命中的成员获得该文件的大小；都不命中则计为未归属。
+pub fn attribute_target(target_path: &Path, members: &[String]) -> MemberAttribution {
+    let by_crate_name: HashMap<String, &str> = members
+        .iter()
+        .map(|name| (name.replace('-', "_"), name.as_str()))
+        .collect();
+
+    let mut sizes: HashMap<&str, u64> = HashMap::new();
+    let mut unattributed = 0u64;
+    for entry in WalkDir::new(target_path).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let relative = entry
+            .path()
+            .strip_prefix(target_path)
+            .unwrap_or(entry.path());
+        let member = relative.components().find_map(|component| match component {
+            Component::Normal(name) => by_crate_name
+                .get(&artifact_stem(&name.to_string_lossy()))
+                .copied(),
+            _ => None,
+        });
+        match member {
+            Some(member) => *sizes.entry(member).or_default() += metadata.len(),
+            None => unattributed += metadata.len(),
+        }
+    }
+
+    let mut members: Vec<(String, u64)> = sizes
+        .into_iter()
+        .map(|(name, size)| (name.to_string(), size))
+        .collect();
+    members.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    MemberAttribution {
+        members,
+        unattributed,
+    }
+}
+
+/// 从产物文件/目录名中提取 crate 名（`libfoo_bar-1a2b3c.rlib` -> `foo_bar`）
+fn artifact_stem(name: &str) -> String {
+    let name = name.split('.').next().unwrap_or(name);
+    let name = match name.rsplit_once('-') {
+        // cargo 的哈希后缀为 16 位十六进制，incremental 目录则是 base36
+        Some((stem, hash))
+            if hash.len() >= 8 && hash.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            stem
+        }
+        _ => name,
+    };
+    name.strip_prefix("lib")
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or(name)
+        .replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_artifact_stem() {
+        assert_eq!(artifact_stem("libfoo_bar-1a2b3c4d5e6f7a8b.rlib"), "foo_bar");
+        assert_eq!(artifact_stem("foo-bar-0b5z7ifqfv5kc"), "foo_bar");
+        assert_eq!(artifact_stem("foo-cli"), "foo_cli");
+        assert_eq!(artifact_stem("my-cli.d"), "my_cli");
+        assert_eq!(artifact_stem("lib"), "lib");
+    }
+
+    #[test]
+    fn test_parse_member_names() {
+        let metadata = br#"{
+            "packages": [
+                {"name": "app", "id": "path+file:///ws/app#0.1.0"},
+                {"name": "core-lib", "id": "path+file:///ws/core#0.1.0"}
+            ],
+            "workspace_members": ["path+file:///ws/app#0.1.0", "path+file:///ws/core#0.1.0"]
+        }"#;
+        assert_eq!(
+            parse_member_names(metadata).unwrap(),
+            vec!["app", "core-lib"]
+        );
+    }
+
+    #[test]
+    fn test_attribute_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        let debug = target.join("debug");
+        for dir in [
+            "deps",
+            ".fingerprint/core-lib-00ff00ff00ff00ff",
+            "incremental/app-0b5z7ifqfv5kc/s-1",
+        ] {
+            fs::create_dir_all(debug.join(dir)).unwrap();
+        }
+        fs::write(
+            debug.join("deps/libcore_lib-00ff00ff00ff00ff.rlib"),
+            vec![0u8; 300],
+        )
+        .unwrap();
+        fs::write(
+            debug.join(".fingerprint/core-lib-00ff00ff00ff00ff/lib-core_lib"),
+            vec![0u8; 10],
+        )
+        .unwrap();
+        fs::write(debug.join("deps/app-0b5z7ifqfv5kc"), vec![0u8; 100]).unwrap();
+        fs::write(
+            debug.join("incremental/app-0b5z7ifqfv5kc/s-1/query-cache.bin"),
+            vec![0u8; 50],
+        )
+        .unwrap();
+        fs::write(
+            debug.join("deps/libserde-9f9f9f9f9f9f9f9f.rlib"),
+            vec![0u8; 40],
+        )
+        .unwrap();
+
+        let members = vec!["app".to_string(), "core-lib".to_string()];
+        let attribution = attribute_target(&target, &members);
+        assert_eq!(
+            attribution.members,
+            vec![("core-lib".to_string(), 310), ("app".to_string(), 150)]
+        );
+        assert_eq!(attribution.unattributed, 40);
+        assert_eq!(attribution.total(), 500);
+    }
+}
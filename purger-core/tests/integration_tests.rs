@@ -256,8 +256,15 @@ fn test_error_handling() -> Result<()> {
         name: "nonexistent".to_string(),
         target_size: 0,
         last_modified: std::time::SystemTime::now(),
-        is_workspace: false,
+        is_workspace_root: false,
+        is_package: true,
+        is_workspace_member: false,
         has_target: true,
+        largest_file: None,
+        target_dir: None,
+        members: Vec::new(),
+        extra_targets: Vec::new(),
+        pinned: false,
     };
 
     let cleaner = ProjectCleaner::default();
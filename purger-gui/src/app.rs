@@ -1,16 +1,18 @@
 use eframe::egui;
-use purger_core::{CleanPhase, RustProject, cleaner::CleanConfig};
-use std::path::PathBuf;
+use purger_core::{ChangedPolicy, CleanPhase, RustProject, cleaner::CleanConfig};
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
-use std::time::SystemTime;
 
+use crate::disk_info::DiskInfo;
 use crate::handlers::{CleanHandler, ScanHandler, SizeHandler};
+use crate::shortcuts::ShortcutAction;
 use crate::simple_i18n::{Language, detect_system_language, set_language};
+use crate::size_format::set_size_units;
 use crate::state::{AppData, AppMessage, AppSettings, AppState};
 use crate::tr;
 use crate::ui::{
-    ActionBar, Dialogs, FiltersPanel, MenuBar, ProgressBar, ProjectDetails, ProjectList,
-    ProjectSort, ScanPanel,
+    ActionBar, Dialogs, DisplayFilters, FiltersPanel, MenuBar, ProgressBar, ProjectDetails,
+    ProjectList, ProjectRowAction, ProjectSort, ScanPanel, filter_projects_for_display,
 };
 
 /// Main application
@@ -24,12 +26,18 @@ pub struct PurgerApp {
     show_settings: bool,
     show_about: bool,
     show_clean_confirm: bool,
+    // 右键菜单触发的单项目清理；为 None 时清理所有选中项目
+    clean_target: Option<PathBuf>,
+    // 清理前大小发生变化、等待用户重新确认的项目 (project, scanned_size, current_size)
+    changed_projects: Vec<(RustProject, u64, u64)>,
 
     // 列表视图状态
     search_query: String,
     sort: ProjectSort,
     show_selected_only: bool,
     show_workspace_only: bool,
+    // 扫描路径所在卷的空间，扫描和清理完成后刷新
+    disk_info: Option<DiskInfo>,
 
     // 应用状态和数据
     state: AppState,
@@ -40,6 +48,7 @@ pub struct PurgerApp {
     sender: mpsc::Sender<AppMessage>,
     scan_cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
     clean_cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    clean_pause: std::sync::Arc<std::sync::atomic::AtomicBool>,
     size_cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
@@ -59,10 +68,12 @@ impl PurgerApp {
             settings.language = detected_lang;
         }
 
-        // 设置当前语言
+        // 设置当前语言和大小单位
         set_language(settings.language);
+        set_size_units(settings.size_units);
 
         let scan_path = settings.last_scan_path.clone();
+        let disk_info = DiskInfo::query(Path::new(&scan_path));
 
         Self {
             settings,
@@ -71,11 +82,14 @@ impl PurgerApp {
             show_settings: false,
             show_about: false,
             show_clean_confirm: false,
+            clean_target: None,
+            changed_projects: Vec::new(),
 
             search_query: String::new(),
             sort: ProjectSort::ModifiedDesc,
             show_selected_only: false,
             show_workspace_only: false,
+            disk_info,
 
             state: AppState::Idle,
             data: AppData::new(),
@@ -84,6 +98,7 @@ impl PurgerApp {
             sender,
             scan_cancel: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
             clean_cancel: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            clean_pause: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
             size_cancel: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
         }
     }
@@ -100,12 +115,14 @@ impl PurgerApp {
                     self.data.scan_progress = None;
                     self.data.size_progress = None;
                     self.data.set_projects(projects);
+                    self.load_project_notes();
                     self.start_size_calculation();
 
                     // 保存扫描路径到设置
                     self.settings.last_scan_path = self.scan_path.clone();
                     self.settings.add_recent_path(self.scan_path.clone());
                     self.save_settings();
+                    self.refresh_disk_info();
                 }
                 AppMessage::ScanError(error) => {
                     self.state = AppState::Idle;
@@ -114,16 +131,10 @@ impl PurgerApp {
                     self.data.error_message = Some(format!("扫描失败: {error}"));
                 }
                 AppMessage::SizeProgress(current, total) => {
-                    if total == 0 || current >= total {
-                        self.data.size_progress = None;
-                    } else {
-                        self.data.size_progress = Some((current, total));
-                    }
+                    self.data.set_size_progress(current, total);
                 }
-                AppMessage::ProjectSizeUpdate(path, size) => {
-                    if let Some(project) = self.data.projects.iter_mut().find(|p| p.path == path) {
-                        project.target_size = size;
-                    }
+                AppMessage::ProjectSizeUpdate(path, size, largest_file) => {
+                    self.data.update_project_size(&path, size, largest_file);
                 }
                 AppMessage::CleanProgress(current, total, size_freed) => {
                     self.data.clean_progress = Some((current, total, size_freed));
@@ -140,11 +151,11 @@ impl PurgerApp {
                         "{} - {} ({}/{total})",
                         progress.project_name,
                         match progress.phase {
-                            CleanPhase::Starting => "开始",
-                            CleanPhase::Analyzing => "分析",
-                            CleanPhase::Cleaning => "清理中",
-                            CleanPhase::Finalizing => "完成",
-                            CleanPhase::Complete => "完成",
+                            CleanPhase::Starting => tr!("clean.phase_starting"),
+                            CleanPhase::Analyzing => tr!("clean.phase_analyzing"),
+                            CleanPhase::Cleaning => tr!("clean.phase_cleaning"),
+                            CleanPhase::Finalizing => tr!("clean.phase_finalizing"),
+                            CleanPhase::Complete => tr!("clean.phase_complete"),
                         },
                         progress.files_processed,
                     ));
@@ -159,18 +170,30 @@ impl PurgerApp {
                     self.data.error_message =
                         Some(format!("项目 {project_name} 清理失败: {error}"));
                 }
+                AppMessage::CleanNeedsConfirm(projects) => {
+                    self.changed_projects = projects;
+                }
                 AppMessage::CleanComplete(result) => {
                     self.state = AppState::Idle;
+                    self.data.clean_paused = false;
                     self.data.clean_progress = None;
                     self.data.current_cleaning_project = None;
                     self.data.last_clean_result = Some(result);
                     self.data.error_message = None;
-                    self.start_scan();
+                    self.refresh_disk_info();
+                    // 预览模式没有删除任何文件，无需重新扫描
+                    if !self.data.last_clean_dry_run {
+                        self.start_scan();
+                    }
                 }
             }
         }
     }
 
+    fn refresh_disk_info(&mut self) {
+        self.disk_info = DiskInfo::query(Path::new(self.scan_path.trim()));
+    }
+
     fn start_size_calculation(&mut self) {
         self.size_cancel
             .store(true, std::sync::atomic::Ordering::Relaxed);
@@ -180,7 +203,7 @@ impl PurgerApp {
             .data
             .projects
             .iter()
-            .map(|p| (p.path.clone(), p.has_target))
+            .map(|p| (p.path.clone(), p.target_path(), p.has_target))
             .collect();
         SizeHandler::start_size_calculation(
             projects,
@@ -219,15 +242,38 @@ impl PurgerApp {
         );
     }
 
+    /// Projects to clean: the single context-menu target, or all selected projects
+    fn projects_to_clean(&self) -> Vec<RustProject> {
+        match &self.clean_target {
+            Some(path) => self
+                .data
+                .projects
+                .iter()
+                .filter(|p| p.has_target && &p.path == path)
+                .cloned()
+                .collect(),
+            None => self
+                .data
+                .get_selected_projects()
+                .into_iter()
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Start cleaning
     fn start_clean(&mut self) {
-        let selected_projects: Vec<_> = self
-            .data
-            .get_selected_projects()
-            .into_iter()
-            .cloned()
-            .collect();
+        let selected_projects = self.projects_to_clean();
+        self.clean_target = None;
+        self.start_clean_projects(selected_projects, self.settings.on_target_changed);
+    }
 
+    /// Start cleaning the given projects
+    fn start_clean_projects(
+        &mut self,
+        selected_projects: Vec<RustProject>,
+        on_changed: ChangedPolicy,
+    ) {
         if selected_projects.is_empty() {
             return;
         }
@@ -236,10 +282,14 @@ impl PurgerApp {
         self.data.error_message = None;
         self.data.clean_errors.clear();
         self.data.clean_progress = Some((0, selected_projects.len(), 0));
+        self.data.clean_started = Some(std::time::Instant::now());
+        self.data.last_clean_dry_run = self.settings.dry_run_by_default;
 
         self.clean_cancel
             .store(true, std::sync::atomic::Ordering::Relaxed);
         self.clean_cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        self.clean_pause = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        self.data.clean_paused = false;
 
         let config = CleanConfig {
             strategy: self.settings.clean_strategy,
@@ -251,6 +301,9 @@ impl PurgerApp {
                 .executable_backup_dir
                 .as_ref()
                 .map(std::path::PathBuf::from),
+            dry_run: self.settings.dry_run_by_default,
+            on_changed,
+            changed_threshold_percent: self.settings.changed_threshold_percent,
             ..Default::default()
         };
 
@@ -259,6 +312,7 @@ impl PurgerApp {
             config,
             self.sender.clone(),
             self.clean_cancel.clone(),
+            self.clean_pause.clone(),
         );
     }
 
@@ -272,6 +326,9 @@ impl PurgerApp {
             AppState::Cleaning => {
                 self.clean_cancel
                     .store(true, std::sync::atomic::Ordering::Relaxed);
+                self.clean_pause
+                    .store(false, std::sync::atomic::Ordering::Relaxed);
+                self.data.clean_paused = false;
             }
             AppState::Idle => {
                 if self.data.size_progress.is_some() {
@@ -287,6 +344,16 @@ impl PurgerApp {
         self.data.current_cleaning_project = None;
     }
 
+    /// Pause or resume the current clean
+    fn toggle_clean_pause(&mut self) {
+        if self.state != AppState::Cleaning {
+            return;
+        }
+        self.data.clean_paused = !self.data.clean_paused;
+        self.clean_pause
+            .store(self.data.clean_paused, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Select a folder
     fn select_folder(&mut self) {
         if let Some(path) = ScanHandler::select_folder() {
@@ -294,73 +361,100 @@ impl PurgerApp {
         }
     }
 
-    /// Save settings
-    fn save_settings(&self) {
-        if self.settings.auto_save_settings {
-            if let Err(e) = self.settings.save_to_file() {
-                tracing::error!("保存设置失败: {}", e);
-            }
+    /// Shortcut/menu actions available in the current state (mirrors the enabled buttons)
+    fn enabled_shortcut_actions(&self) -> Vec<ShortcutAction> {
+        let idle = self.state == AppState::Idle;
+        let mut actions = vec![ShortcutAction::SelectFolder];
+        if idle && !self.scan_path.trim().is_empty() {
+            actions.push(ShortcutAction::Scan);
         }
+        if idle {
+            actions.push(ShortcutAction::SelectAll);
+        }
+        if idle && self.data.get_selected_count() > 0 {
+            actions.push(ShortcutAction::Clean);
+        }
+        if !idle || self.data.size_progress.is_some() {
+            actions.push(ShortcutAction::Stop);
+        }
+        actions
     }
 
-    fn visible_project_indices(&self) -> Vec<usize> {
-        let query = self.search_query.trim().to_lowercase();
-        let mut indices: Vec<usize> = (0..self.data.projects.len()).collect();
+    /// 有对话框打开时不响应快捷键（Escape/Delete 等留给对话框）
+    fn has_open_dialog(&self) -> bool {
+        self.show_settings
+            || self.show_about
+            || self.show_clean_confirm
+            || !self.changed_projects.is_empty()
+    }
 
-        indices.retain(|&i| {
-            let project = &self.data.projects[i];
+    /// Ask for confirmation before cleaning, or clean right away when confirmation is disabled
+    fn request_clean(&mut self, on_confirm_clean: &mut bool) {
+        if self.settings.confirm_before_clean {
+            self.show_clean_confirm = true;
+        } else {
+            *on_confirm_clean = true;
+        }
+    }
 
-            if self.show_selected_only {
-                if !self.data.is_selected(project) {
-                    return false;
-                }
-            } else {
-                if self.settings.target_only && !project.has_target {
-                    return false;
-                }
-                if self.show_workspace_only && !project.is_workspace {
-                    return false;
-                }
-                if let Some(size_mb) = self.settings.keep_size_mb {
-                    if project.has_target && project.target_size != 0 {
-                        let keep_bytes = (size_mb * 1_000_000.0) as u64;
-                        if project.target_size > keep_bytes {
-                            return false;
-                        }
-                    }
-                }
-            }
+    /// Track the current window size/position so it can be restored on the next launch
+    fn remember_window_geometry(&mut self, ctx: &egui::Context) {
+        let (inner, outer) = ctx.input(|i| (i.viewport().inner_rect, i.viewport().outer_rect));
+        if let Some(inner) = inner {
+            let pos = outer.map(|outer| [outer.min.x, outer.min.y]);
+            self.settings
+                .remember_window([inner.width(), inner.height()], pos);
+        }
+    }
 
-            if query.is_empty() {
-                return true;
-            }
+    /// Save settings
+    fn save_settings(&self) {
+        if self.settings.auto_save_settings
+            && let Err(e) = self.settings.save_to_file()
+        {
+            tracing::error!("保存设置失败: {}", e);
+        }
+    }
 
-            project.name.to_lowercase().contains(&query)
-                || project
-                    .path
-                    .display()
-                    .to_string()
-                    .to_lowercase()
-                    .contains(&query)
-        });
+    /// Resolve persisted notes for the current project list
+    fn load_project_notes(&mut self) {
+        self.data.notes = self
+            .data
+            .projects
+            .iter()
+            .filter_map(|project| {
+                self.settings
+                    .note_for(&project.path)
+                    .map(|note| (project.path.clone(), note.to_string()))
+            })
+            .collect();
+    }
+
+    /// Add a project to the ignore list and drop it from the current list
+    fn ignore_project(&mut self, path: &Path) {
+        let path_text = path.display().to_string();
+        if !self.settings.ignore_paths.contains(&path_text) {
+            self.settings.ignore_paths.push(path_text);
+        }
+        self.data.remove_project(path);
+        self.save_settings();
+    }
+
+    fn visible_project_indices(&self) -> Vec<usize> {
+        let filters = DisplayFilters {
+            selected: self
+                .show_selected_only
+                .then_some(&self.data.selected_projects),
+            workspace_only: self.show_workspace_only,
+            target_only: self.settings.target_only,
+            keep_size_mb: self.settings.keep_size_mb,
+        };
+        let mut indices =
+            filter_projects_for_display(&self.data.projects, &self.search_query, filters);
 
         indices.sort_by(|&a, &b| {
-            let pa = &self.data.projects[a];
-            let pb = &self.data.projects[b];
-            match self.sort {
-                ProjectSort::SizeDesc => compare_size_desc(pa, pb),
-                ProjectSort::SizeAsc => compare_size_asc(pa, pb),
-                ProjectSort::ModifiedDesc => {
-                    system_time_key(pb.last_modified).cmp(&system_time_key(pa.last_modified))
-                }
-                ProjectSort::ModifiedAsc => {
-                    system_time_key(pa.last_modified).cmp(&system_time_key(pb.last_modified))
-                }
-                ProjectSort::NameAsc => pa.name.cmp(&pb.name),
-                ProjectSort::NameDesc => pb.name.cmp(&pa.name),
-                ProjectSort::PathAsc => pa.path.cmp(&pb.path),
-                ProjectSort::PathDesc => pb.path.cmp(&pa.path),
-            }
+            self.sort
+                .compare(&self.data.projects[a], &self.data.projects[b])
         });
 
         indices
@@ -370,20 +464,53 @@ impl PurgerApp {
 impl eframe::App for PurgerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.handle_messages();
+        self.remember_window_geometry(ctx);
+        if ctx.input(|i| i.viewport().close_requested()) {
+            self.save_settings();
+        }
 
         let mut on_select_folder = false;
         let mut on_start_scan = false;
         let mut on_stop = false;
         let mut on_request_clean = false;
+        let mut on_select_all = false;
+        let mut on_toggle_pause = false;
         let mut on_confirm_clean = false;
+        let mut row_action = None;
+        let mut note_changed = None;
 
-        // 菜单栏
+        // 菜单栏 + 键盘快捷键
+        let enabled_actions = self.enabled_shortcut_actions();
+        let mut menu_action = None;
         MenuBar::show(
             ctx,
             &mut self.show_settings,
             &mut self.show_about,
-            &mut on_select_folder,
+            &enabled_actions,
+            &mut menu_action,
         );
+        let mut actions = if self.has_open_dialog() {
+            Vec::new()
+        } else {
+            ShortcutAction::pressed(ctx)
+        };
+        actions.extend(menu_action);
+        for action in actions {
+            if !enabled_actions.contains(&action) {
+                continue;
+            }
+            match action {
+                ShortcutAction::SelectFolder => on_select_folder = true,
+                ShortcutAction::Scan => on_start_scan = true,
+                ShortcutAction::SelectAll => on_select_all = true,
+                // 快捷键误触代价大：无论设置如何都先弹出确认框
+                ShortcutAction::Clean => {
+                    self.clean_target = None;
+                    self.show_clean_confirm = true;
+                }
+                ShortcutAction::Stop => on_stop = true,
+            }
+        }
 
         // 顶部扫描工具栏
         egui::TopBottomPanel::top("scan_toolbar").show(ctx, |ui| {
@@ -394,6 +521,7 @@ impl eframe::App for PurgerApp {
                 &mut self.settings,
                 &self.state,
                 can_stop_extra,
+                self.disk_info.as_ref(),
                 &mut on_select_folder,
                 &mut on_start_scan,
                 &mut on_stop,
@@ -420,14 +548,25 @@ impl eframe::App for PurgerApp {
             .default_width(280.0)
             .resizable(true)
             .show(ctx, |ui| {
-                ProjectDetails::show(ui, &mut self.data);
+                ProjectDetails::show(ui, &mut self.data, &mut note_changed);
             });
 
         // 底部操作栏 + 进度
         egui::TopBottomPanel::bottom("actions_panel").show(ctx, |ui| {
             ProgressBar::show_all_progress(ui, &self.state, &self.data);
             ui.separator();
-            ActionBar::show(ui, &mut self.data, &self.state, &mut on_request_clean);
+            let dry_run_before = self.settings.dry_run_by_default;
+            ActionBar::show(
+                ui,
+                &mut self.data,
+                &self.state,
+                &mut self.settings.dry_run_by_default,
+                &mut on_request_clean,
+                &mut on_toggle_pause,
+            );
+            if self.settings.dry_run_by_default != dry_run_before {
+                self.save_settings();
+            }
         });
 
         // 中间主列表
@@ -442,6 +581,7 @@ impl eframe::App for PurgerApp {
                 &mut self.sort,
                 &mut sort_changed,
                 self.settings.keep_size_mb.is_some(),
+                &mut row_action,
             );
             if sort_changed {
                 ctx.request_repaint();
@@ -458,39 +598,59 @@ impl eframe::App for PurgerApp {
         Dialogs::show_about(ctx, &mut self.show_about);
 
         if self.show_clean_confirm {
-            let selected_count = self.data.get_selected_count();
-            let total_selected_size = self.data.get_total_cleanable_size();
-            let strategy_text = match self.settings.clean_strategy {
-                purger_core::CleanStrategy::CargoClean => tr!("strategy.cargo_clean"),
-                purger_core::CleanStrategy::DirectDelete => tr!("strategy.direct_delete"),
+            let (selected_count, total_selected_size) = if self.clean_target.is_some() {
+                let projects = self.projects_to_clean();
+                (
+                    projects.len(),
+                    projects.iter().map(|p| p.target_size).sum::<u64>(),
+                )
+            } else {
+                (
+                    self.data.get_selected_count(),
+                    self.data.get_total_cleanable_size(),
+                )
             };
+            on_confirm_clean = Dialogs::show_clean_confirm(
+                ctx,
+                &mut self.show_clean_confirm,
+                selected_count,
+                total_selected_size,
+                self.settings.clean_strategy,
+                self.settings.dry_run_by_default,
+                selected_count > 0 && self.state == AppState::Idle,
+            );
+            if !self.show_clean_confirm && !on_confirm_clean {
+                self.clean_target = None;
+            }
+        }
 
-            egui::Window::new(tr!("clean.confirm_title"))
+        let mut on_confirm_changed = false;
+        if !self.changed_projects.is_empty() && self.state == AppState::Idle {
+            egui::Window::new(tr!("clean.changed_title"))
                 .collapsible(false)
                 .resizable(false)
                 .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
                 .show(ctx, |ui| {
                     ui.label(tr!(
-                        "clean.confirm_message",
-                        count = selected_count,
-                        size = purger_core::format_bytes(total_selected_size)
+                        "clean.changed_message",
+                        count = self.changed_projects.len()
                     ));
-                    ui.label(tr!("clean.confirm_strategy", strategy = strategy_text));
+                    ui.add_space(4.0);
+                    for (project, before, after) in &self.changed_projects {
+                        ui.label(format!(
+                            "{}: {} → {}",
+                            project.name,
+                            crate::size_format::format_size(*before),
+                            crate::size_format::format_size(*after)
+                        ));
+                    }
                     ui.add_space(8.0);
                     ui.horizontal(|ui| {
                         if ui.button(tr!("dialog.cancel")).clicked() {
-                            self.show_clean_confirm = false;
+                            self.changed_projects.clear();
                         }
-                        let can_confirm = selected_count > 0 && self.state == AppState::Idle;
-                        if ui
-                            .add_enabled(
-                                can_confirm,
-                                egui::Button::new(tr!("clean.confirm_button")),
-                            )
-                            .clicked()
-                        {
-                            on_confirm_clean = true;
-                            self.show_clean_confirm = false;
+                        if ui.button(tr!("clean.changed_confirm_button")).clicked() {
+                            on_confirm_changed = true;
                         }
                     });
                 });
@@ -506,12 +666,38 @@ impl eframe::App for PurgerApp {
         if on_stop {
             self.stop_operation();
         }
+        if on_toggle_pause {
+            self.toggle_clean_pause();
+        }
+        if on_select_all {
+            self.data.select_all();
+        }
         if on_request_clean {
-            self.show_clean_confirm = true;
+            self.clean_target = None;
+            self.request_clean(&mut on_confirm_clean);
+        }
+        match row_action {
+            Some(ProjectRowAction::Clean(path)) => {
+                self.clean_target = Some(path);
+                self.request_clean(&mut on_confirm_clean);
+            }
+            Some(ProjectRowAction::Ignore(path)) => self.ignore_project(&path),
+            None => {}
+        }
+        if let Some((path, note)) = note_changed {
+            self.settings.set_note(&path, &note);
+            self.save_settings();
         }
         if on_confirm_clean {
             self.start_clean();
         }
+        if on_confirm_changed {
+            let projects = std::mem::take(&mut self.changed_projects)
+                .into_iter()
+                .map(|(project, _, _)| project)
+                .collect();
+            self.start_clean_projects(projects, ChangedPolicy::Ignore);
+        }
 
         let has_background_work = self.state != AppState::Idle
             || self.data.scan_progress.is_some()
@@ -527,49 +713,6 @@ impl eframe::App for PurgerApp {
     }
 }
 
-fn system_time_key(time: SystemTime) -> u64 {
-    time.duration_since(SystemTime::UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0)
-}
-
-fn compare_size_desc(a: &RustProject, b: &RustProject) -> std::cmp::Ordering {
-    compare_optional_desc(size_key(a), size_key(b))
-        .then_with(|| a.name.cmp(&b.name))
-        .then_with(|| a.path.cmp(&b.path))
-}
-
-fn compare_size_asc(a: &RustProject, b: &RustProject) -> std::cmp::Ordering {
-    compare_optional_asc(size_key(a), size_key(b))
-        .then_with(|| a.name.cmp(&b.name))
-        .then_with(|| a.path.cmp(&b.path))
-}
-
-fn size_key(project: &RustProject) -> Option<u64> {
-    if !project.has_target || project.target_size == 0 {
-        return None;
-    }
-    Some(project.target_size)
-}
-
-fn compare_optional_desc(a: Option<u64>, b: Option<u64>) -> std::cmp::Ordering {
-    match (a, b) {
-        (Some(a), Some(b)) => b.cmp(&a),
-        (Some(_), None) => std::cmp::Ordering::Less,
-        (None, Some(_)) => std::cmp::Ordering::Greater,
-        (None, None) => std::cmp::Ordering::Equal,
-    }
-}
-
-fn compare_optional_asc(a: Option<u64>, b: Option<u64>) -> std::cmp::Ordering {
-    match (a, b) {
-        (Some(a), Some(b)) => a.cmp(&b),
-        (Some(_), None) => std::cmp::Ordering::Less,
-        (None, Some(_)) => std::cmp::Ordering::Greater,
-        (None, None) => std::cmp::Ordering::Equal,
-    }
-}
-
 fn apply_compact_style(ctx: &egui::Context) {
     let mut style = (*ctx.style()).clone();
 
@@ -0,0 +1,72 @@
+use purger_core::SizeUnits;
+use std::path::Path;
+
+/// Total and free space of the volume containing the scan path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskInfo {
+    pub total: u64,
+    pub free: u64,
+}
+
+impl DiskInfo {
+    /// 查询 `path` 所在卷的空间；路径不存在时使用最近的已存在祖先目录，都不存在或查询失败时返回 None
+    pub fn query(path: &Path) -> Option<Self> {
+        Some(Self {
+            total: purger_core::disk::total_space(path)?,
+            free: purger_core::disk::available_space(path)?,
+        })
+    }
+
+    /// Fraction of the volume in use, 0.0 for an empty/unknown volume
+    pub fn used_fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.total.saturating_sub(self.free) as f32 / self.total as f32
+    }
+
+    /// Formatted (free, total) figures
+    pub fn figures(&self, units: SizeUnits) -> (String, String) {
+        (
+            purger_core::format_bytes_with(self.free, units),
+            purger_core::format_bytes_with(self.total, units),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_disk_info_figures() {
+        let info = DiskInfo {
+            total: 500 * 1024 * 1024 * 1024,
+            free: 125 * 1024 * 1024 * 1024,
+        };
+        assert_eq!(
+            info.figures(SizeUnits::Binary),
+            ("125.00 GiB".to_string(), "500.00 GiB".to_string())
+        );
+        assert_eq!(info.figures(SizeUnits::Decimal).1, "536.87 GB");
+        assert!((info.used_fraction() - 0.75).abs() < f32::EPSILON);
+
+        let empty = DiskInfo { total: 0, free: 0 };
+        assert_eq!(empty.used_fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_disk_info_missing_path() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // 不存在的路径回退到所在卷
+        let missing = temp_dir.path().join("not").join("created");
+        let info = DiskInfo::query(&missing).expect("ancestor volume");
+        assert!(info.free <= info.total);
+
+        // 没有任何已存在的祖先
+        assert_eq!(DiskInfo::query(Path::new("")), None);
+        assert_eq!(DiskInfo::query(Path::new("no/such/relative/dir")), None);
+    }
+}
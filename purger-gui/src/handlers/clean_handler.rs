@@ -1,8 +1,9 @@
 use crate::state::AppMessage;
 use purger_core::{
-    CleanFailure, CleanResult, ProjectCleaner, RustProject,
-    cleaner::{CleanCancelled, CleanConfig},
+    BatchCleanEvent, ChangedPolicy, CleanResult, ProjectCleaner, RustProject,
+    cleaner::{CleanConfig, TargetChanged},
 };
+use std::cell::RefCell;
 use std::sync::mpsc;
 use std::thread;
 
@@ -16,69 +17,91 @@ impl CleanHandler {
         config: CleanConfig,
         sender: mpsc::Sender<AppMessage>,
         stop_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        pause_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
     ) {
         thread::spawn(move || {
-            let start_time = std::time::Instant::now();
-            let cleaner = ProjectCleaner::new(config);
+            let on_changed = config.on_changed;
+            let cleaner = ProjectCleaner::new(config).with_pause_flag(pause_flag);
             let total = selected_projects.len();
-            let mut total_freed = 0u64;
-            let mut result = CleanResult::new();
+            let needs_confirm = RefCell::new(Vec::new());
 
             let _ = sender.send(AppMessage::CleanProgress(0, total, 0));
 
-            for (i, project) in selected_projects.iter().enumerate() {
-                if stop_flag.load(std::sync::atomic::Ordering::Relaxed) {
-                    return;
-                }
-
-                // 发送开始清理项目的消息
-                let _ = sender.send(AppMessage::CleanProjectStart(project.name.clone()));
-
-                // 使用带进度回调的清理方法
-                let sender_clone = sender.clone();
-                match cleaner.clean_project_with_progress_and_cancel(
-                    project,
-                    Some(stop_flag.as_ref()),
-                    |progress| {
-                        let _ = sender_clone.send(AppMessage::CleanProjectProgress(progress));
-                    },
-                ) {
-                    Ok(size_freed) => {
-                        total_freed += size_freed;
-                        result.add_success(size_freed);
+            let mut result = cleaner.clean_projects_with_progress(
+                &selected_projects,
+                Some(stop_flag.as_ref()),
+                |event| match event {
+                    BatchCleanEvent::ProjectStarted { project, .. } => {
+                        let _ = sender.send(AppMessage::CleanProjectStart(project.name.clone()));
+                    }
+                    BatchCleanEvent::Progress(progress) => {
+                        let _ = sender.send(AppMessage::CleanProjectProgress(progress));
+                    }
+                    BatchCleanEvent::ProjectCompleted {
+                        index,
+                        project,
+                        bytes_freed,
+                        total_freed,
+                        ..
+                    } => {
                         let _ = sender.send(AppMessage::CleanProjectComplete(
                             project.name.clone(),
-                            size_freed,
+                            bytes_freed,
                         ));
-                        let _ = sender.send(AppMessage::CleanProgress(i + 1, total, total_freed));
+                        let _ =
+                            sender.send(AppMessage::CleanProgress(index + 1, total, total_freed));
                     }
-                    Err(e) => {
-                        if e.is::<CleanCancelled>()
-                            || stop_flag.load(std::sync::atomic::Ordering::Relaxed)
+                    BatchCleanEvent::ProjectFailed {
+                        index,
+                        project,
+                        error,
+                        total_freed,
+                        ..
+                    } => {
+                        // 大小变化的项目不算失败，结束后交给用户重新确认
+                        if on_changed == ChangedPolicy::Confirm
+                            && let Some(changed) = error.downcast_ref::<TargetChanged>()
                         {
-                            return;
+                            needs_confirm.borrow_mut().push((
+                                project.clone(),
+                                changed.before,
+                                changed.after,
+                            ));
+                        } else {
+                            let _ = sender.send(AppMessage::CleanProjectError(
+                                project.name.clone(),
+                                error.to_string(),
+                            ));
                         }
-                        result.add_failure_detail(CleanFailure {
-                            project_name: project.name.clone(),
-                            project_path: project.path.clone(),
-                            error: e.to_string(),
-                        });
-                        let _ = sender.send(AppMessage::CleanProjectError(
-                            project.name.clone(),
-                            e.to_string(),
-                        ));
-                        let _ = sender.send(AppMessage::CleanProgress(i + 1, total, total_freed));
-                        continue;
+                        let _ =
+                            sender.send(AppMessage::CleanProgress(index + 1, total, total_freed));
                     }
-                }
-            }
-
-            result.total_size_freed = total_freed;
-            result.duration_ms = start_time.elapsed().as_millis() as u64;
+                },
+            );
 
-            if !stop_flag.load(std::sync::atomic::Ordering::Relaxed) {
-                let _ = sender.send(AppMessage::CleanComplete(result));
+            if stop_flag.load(std::sync::atomic::Ordering::Relaxed) {
+                return;
+            }
+            let needs_confirm = needs_confirm.into_inner();
+            if !needs_confirm.is_empty() {
+                exclude_from_result(&mut result, &needs_confirm);
+                let _ = sender.send(AppMessage::CleanNeedsConfirm(needs_confirm));
             }
+            let _ = sender.send(AppMessage::CleanComplete(result));
         });
     }
 }
+
+/// 待重新确认的项目不计入本次结果的失败
+fn exclude_from_result(result: &mut CleanResult, deferred: &[(RustProject, u64, u64)]) {
+    let is_deferred = |path: &std::path::Path| deferred.iter().any(|(p, _, _)| p.path == path);
+    result
+        .failures
+        .retain(|failure| !is_deferred(&failure.project_path));
+    result
+        .failed_projects
+        .retain(|path| !is_deferred(std::path::Path::new(path)));
+    result
+        .outcomes
+        .retain(|outcome| !is_deferred(&outcome.path));
+}
@@ -4,6 +4,9 @@ use std::path::PathBuf;
 use std::sync::mpsc;
 use std::thread;
 
+/// Send a scan progress update every this many discovered manifests
+const SCAN_PROGRESS_EVERY: usize = 50;
+
 /// Scan event handler
 pub struct ScanHandler;
 
@@ -30,9 +33,12 @@ impl ScanHandler {
             let scanner = ProjectScanner::new(config);
             let _ = sender.send(AppMessage::ScanProgress(0, 0));
 
+            // 核心每发现一个 Cargo.toml 回调一次，这里限制消息数量避免刷屏
             let progress_sender = sender.clone();
             let on_found = move |found: usize| {
-                let _ = progress_sender.send(AppMessage::ScanProgress(found, 0));
+                if found.is_multiple_of(SCAN_PROGRESS_EVERY) {
+                    let _ = progress_sender.send(AppMessage::ScanProgress(found, 0));
+                }
             };
 
             match scanner.scan_with_cancel_and_progress(
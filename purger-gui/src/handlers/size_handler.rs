@@ -1,4 +1,5 @@
 use crate::state::AppMessage;
+use purger_core::LargestFile;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -12,15 +13,15 @@ pub struct SizeHandler;
 
 impl SizeHandler {
     pub fn start_size_calculation(
-        projects: Vec<(PathBuf, bool)>,
+        projects: Vec<(PathBuf, PathBuf, bool)>,
         sender: mpsc::Sender<AppMessage>,
         stop_flag: Arc<AtomicBool>,
     ) {
         thread::spawn(move || {
             let targets: Vec<_> = projects
                 .into_iter()
-                .filter(|(_, has_target)| *has_target)
-                .map(|(path, _)| path)
+                .filter(|(_, _, has_target)| *has_target)
+                .map(|(path, target_path, _)| (path, target_path))
                 .collect();
 
             let total = targets.len();
@@ -31,18 +32,21 @@ impl SizeHandler {
 
             let _ = sender.send(AppMessage::SizeProgress(0, total));
 
-            for (i, project_path) in targets.into_iter().enumerate() {
+            for (i, (project_path, target_path)) in targets.into_iter().enumerate() {
                 if stop_flag.load(std::sync::atomic::Ordering::Relaxed) {
                     return;
                 }
 
-                let target_path = project_path.join("target");
-                let size = calculate_dir_size(&target_path, &stop_flag);
+                let (size, largest_file) = calculate_dir_size(&target_path, &stop_flag);
                 if stop_flag.load(std::sync::atomic::Ordering::Relaxed) {
                     return;
                 }
 
-                let _ = sender.send(AppMessage::ProjectSizeUpdate(project_path, size));
+                let _ = sender.send(AppMessage::ProjectSizeUpdate(
+                    project_path,
+                    size,
+                    largest_file,
+                ));
                 let _ = sender.send(AppMessage::SizeProgress(i + 1, total));
                 thread::sleep(Duration::from_millis(2));
             }
@@ -50,15 +54,16 @@ impl SizeHandler {
     }
 }
 
-fn calculate_dir_size(path: &PathBuf, stop_flag: &AtomicBool) -> u64 {
+fn calculate_dir_size(path: &PathBuf, stop_flag: &AtomicBool) -> (u64, Option<LargestFile>) {
     if !path.exists() {
-        return 0;
+        return (0, None);
     }
 
     let mut total = 0u64;
+    let mut largest: Option<LargestFile> = None;
     for entry in WalkDir::new(path).follow_links(false).into_iter() {
         if stop_flag.load(std::sync::atomic::Ordering::Relaxed) {
-            return total;
+            return (total, largest);
         }
 
         let Ok(entry) = entry else {
@@ -71,8 +76,19 @@ fn calculate_dir_size(path: &PathBuf, stop_flag: &AtomicBool) -> u64 {
         let Ok(metadata) = entry.metadata() else {
             continue;
         };
-        total = total.saturating_add(metadata.len());
+        let len = metadata.len();
+        total = total.saturating_add(len);
+        if largest.as_ref().is_none_or(|file| len > file.size) {
+            largest = Some(LargestFile {
+                path: entry
+                    .path()
+                    .strip_prefix(path)
+                    .unwrap_or(entry.path())
+                    .to_path_buf(),
+                size: len,
+            });
+        }
     }
 
-    total
+    (total, largest)
 }
@@ -2,13 +2,17 @@ use anyhow::Result;
 use eframe::egui;
 
 mod app;
+mod disk_info;
 mod handlers;
+mod shortcuts;
 mod simple_i18n;
+mod size_format;
 mod state;
 mod ui;
 
 use app::PurgerApp;
 use simple_i18n::translate;
+use state::{AppSettings, MIN_WINDOW_SIZE};
 
 pub fn run_gui() -> Result<()> {
     // Logging is initialized by the caller binary or by the GUI-only binary.
@@ -17,10 +21,17 @@ pub fn run_gui() -> Result<()> {
         .with_max_level(tracing::Level::INFO)
         .try_init();
 
+    // 恢复上次关闭时的窗口大小和位置
+    let settings = AppSettings::load_from_file();
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(settings.window_size())
+        .with_min_inner_size(MIN_WINDOW_SIZE);
+    if let Some(pos) = settings.window_pos {
+        viewport = viewport.with_position(pos);
+    }
+
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([800.0, 600.0])
-            .with_min_inner_size([600.0, 400.0]),
+        viewport,
         ..Default::default()
     };
 
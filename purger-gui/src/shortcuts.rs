@@ -0,0 +1,136 @@
+use eframe::egui;
+
+/// Actions that can be triggered from the keyboard (and from the menu)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    SelectFolder,
+    Scan,
+    SelectAll,
+    Clean,
+    Stop,
+}
+
+impl ShortcutAction {
+    /// 按键 + 修饰键对应的操作：Ctrl+O / Ctrl+R / F5 / Ctrl+A / Delete / Escape（macOS 上 Ctrl 为 Cmd）
+    pub fn from_key(key: egui::Key, modifiers: egui::Modifiers) -> Option<Self> {
+        if modifiers.command_only() {
+            return match key {
+                egui::Key::O => Some(Self::SelectFolder),
+                egui::Key::R => Some(Self::Scan),
+                egui::Key::A => Some(Self::SelectAll),
+                _ => None,
+            };
+        }
+        if !modifiers.is_none() {
+            return None;
+        }
+        match key {
+            egui::Key::F5 => Some(Self::Scan),
+            egui::Key::Delete => Some(Self::Clean),
+            egui::Key::Escape => Some(Self::Stop),
+            _ => None,
+        }
+    }
+
+    /// Shortcut shown next to the menu item
+    pub fn shortcut(self) -> egui::KeyboardShortcut {
+        let (modifiers, key) = match self {
+            Self::SelectFolder => (egui::Modifiers::COMMAND, egui::Key::O),
+            Self::Scan => (egui::Modifiers::COMMAND, egui::Key::R),
+            Self::SelectAll => (egui::Modifiers::COMMAND, egui::Key::A),
+            Self::Clean => (egui::Modifiers::NONE, egui::Key::Delete),
+            Self::Stop => (egui::Modifiers::NONE, egui::Key::Escape),
+        };
+        egui::KeyboardShortcut::new(modifiers, key)
+    }
+
+    /// 文本框获得焦点时这些按键留给文本编辑（全选、删除、取消输入）
+    fn conflicts_with_text_input(self) -> bool {
+        matches!(self, Self::SelectAll | Self::Clean | Self::Stop)
+    }
+
+    /// Actions whose key chord was pressed this frame (key repeats ignored)
+    pub fn pressed(ctx: &egui::Context) -> Vec<Self> {
+        let typing = ctx.wants_keyboard_input();
+        ctx.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|event| match event {
+                    egui::Event::Key {
+                        key,
+                        pressed: true,
+                        repeat: false,
+                        modifiers,
+                        ..
+                    } => Self::from_key(*key, *modifiers),
+                    _ => None,
+                })
+                .filter(|action| !(typing && action.conflicts_with_text_input()))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{Key, Modifiers};
+
+    #[test]
+    fn test_shortcut_from_key() {
+        assert_eq!(
+            ShortcutAction::from_key(Key::O, Modifiers::COMMAND),
+            Some(ShortcutAction::SelectFolder)
+        );
+        assert_eq!(
+            ShortcutAction::from_key(Key::R, Modifiers::COMMAND),
+            Some(ShortcutAction::Scan)
+        );
+        assert_eq!(
+            ShortcutAction::from_key(Key::F5, Modifiers::NONE),
+            Some(ShortcutAction::Scan)
+        );
+        assert_eq!(
+            ShortcutAction::from_key(Key::A, Modifiers::COMMAND),
+            Some(ShortcutAction::SelectAll)
+        );
+        assert_eq!(
+            ShortcutAction::from_key(Key::Delete, Modifiers::NONE),
+            Some(ShortcutAction::Clean)
+        );
+        assert_eq!(
+            ShortcutAction::from_key(Key::Escape, Modifiers::NONE),
+            Some(ShortcutAction::Stop)
+        );
+
+        // 修饰键不匹配时不触发
+        assert_eq!(ShortcutAction::from_key(Key::O, Modifiers::NONE), None);
+        assert_eq!(
+            ShortcutAction::from_key(Key::A, Modifiers::COMMAND | Modifiers::SHIFT),
+            None
+        );
+        assert_eq!(
+            ShortcutAction::from_key(Key::Delete, Modifiers::SHIFT),
+            None
+        );
+        assert_eq!(ShortcutAction::from_key(Key::F5, Modifiers::COMMAND), None);
+        assert_eq!(ShortcutAction::from_key(Key::X, Modifiers::COMMAND), None);
+    }
+
+    #[test]
+    fn test_shortcut_round_trip() {
+        for action in [
+            ShortcutAction::SelectFolder,
+            ShortcutAction::Scan,
+            ShortcutAction::SelectAll,
+            ShortcutAction::Clean,
+            ShortcutAction::Stop,
+        ] {
+            let shortcut = action.shortcut();
+            assert_eq!(
+                ShortcutAction::from_key(shortcut.logical_key, shortcut.modifiers),
+                Some(action)
+            );
+        }
+    }
+}
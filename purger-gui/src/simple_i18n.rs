@@ -1,19 +1,22 @@
 use serde::{Deserialize, Serialize};
-use std::sync::RwLock;
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
 
 /// 支持的语言
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Language {
     #[serde(rename = "zh-CN")]
     Chinese,
     #[serde(rename = "en")]
     English,
+    #[serde(rename = "ja")]
+    Japanese,
 }
 
 impl Language {
     /// 获取所有支持的语言
     pub fn all() -> Vec<Language> {
-        vec![Language::Chinese, Language::English]
+        vec![Language::Chinese, Language::English, Language::Japanese]
     }
 
     /// 获取语言代码
@@ -22,6 +25,7 @@ impl Language {
         match self {
             Language::Chinese => "zh-CN",
             Language::English => "en",
+            Language::Japanese => "ja",
         }
     }
 
@@ -30,6 +34,16 @@ impl Language {
         match self {
             Language::Chinese => "中文",
             Language::English => "English",
+            Language::Japanese => "日本語",
+        }
+    }
+
+    /// 该语言的翻译表；新增语言只需添加一张表
+    fn entries(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Chinese => ZH_CN,
+            Language::English => EN,
+            Language::Japanese => JA,
         }
     }
 
@@ -39,6 +53,7 @@ impl Language {
         match code {
             "zh-CN" => Some(Language::Chinese),
             "en" => Some(Language::English),
+            "ja" => Some(Language::Japanese),
             _ => None,
         }
     }
@@ -62,6 +77,12 @@ pub fn detect_system_language() -> Language {
             return Language::Chinese;
         }
 
+        // 检查是否为日文环境
+        if locale.starts_with("ja") {
+            tracing::info!("使用日文界面");
+            return Language::Japanese;
+        }
+
         // 检查是否为英文环境
         if locale.starts_with("en") {
             tracing::info!("使用英文界面");
@@ -90,10 +111,10 @@ pub fn set_language(language: Language) {
 
 /// 获取当前语言
 pub fn current_language() -> Language {
-    if let Ok(lang) = CURRENT_LANGUAGE.read() {
-        if let Some(language) = *lang {
-            return language;
-        }
+    if let Ok(lang) = CURRENT_LANGUAGE.read()
+        && let Some(language) = *lang
+    {
+        return language;
     }
 
     // 如果没有设置过语言，使用系统检测的语言
@@ -117,297 +138,692 @@ macro_rules! tr {
     }};
 }
 
-/// 翻译函数
+/// 翻译函数：在当前语言的翻译表中查找，缺失时回退到英文，最后回退到 key 本身
 pub fn translate(key: &str) -> String {
-    let lang = current_language();
-    match lang {
-        Language::Chinese => translate_chinese(key),
-        Language::English => translate_english(key),
-    }
-    .unwrap_or_else(|| key.to_string())
+    lookup(current_language(), key)
+        .or_else(|| lookup(Language::English, key))
+        .unwrap_or(key)
+        .to_string()
 }
 
-fn translate_chinese(key: &str) -> Option<String> {
-    let text = match key {
-        "app.title" => "Rust Project Purger",
-        "menu.file" => "文件",
-        "menu.settings" => "设置",
-        "menu.help" => "帮助",
-        "menu.select_folder" => "选择文件夹...",
-        "menu.exit" => "退出",
-        "menu.preferences" => "首选项...",
-        "menu.about" => "关于...",
-        "scan.path_label" => "扫描路径:",
-        "scan.path_hint" => "例如: . 或 /path/to/projects",
-        "scan.max_depth_label" => "最大深度:",
-        "scan.strategy_label" => "清理策略:",
-        "scan.recent_paths_label" => "最近路径:",
-        "scan.recent_paths_placeholder" => "选择最近路径...",
-        "scan.start_button" => "开始扫描",
-        "scan.browse_button" => "选择...",
-        "scan.stop_button" => "停止",
-        "scan.scanning_status" => "正在扫描...",
-        "scan.sizing_status" => "正在计算大小...",
-        "scan.strategy_cargo_clean" => "Cargo Clean (推荐)",
-        "scan.strategy_direct_delete" => "直接删除",
-        "filters.title" => "筛选",
-        "filters.search_label" => "搜索",
-        "filters.search_placeholder" => "按名称或路径搜索...",
-        "filters.clear_search" => "清空",
-        "filters.sort_label" => "排序:",
-        "filters.sort.size_desc" => "按大小(大→小)",
-        "filters.sort.size_asc" => "按大小(小→大)",
-        "filters.sort.modified_desc" => "按最近编译(新→旧)",
-        "filters.sort.modified_asc" => "按最近编译(旧→新)",
-        "filters.sort.name_asc" => "按名称(A→Z)",
-        "filters.sort.name_desc" => "按名称(Z→A)",
-        "filters.sort.path_asc" => "按路径",
-        "filters.sort.path_desc" => "按路径(倒序)",
-        "filters.selected_only" => "只看已选",
-        "filters.target_only" => "只显示可清理(有 target)",
-        "filters.workspace_only" => "只看 workspace 根",
-        "filters.scan_filters" => "扫描过滤(需重新扫描)",
-        "filters.scan_filters_hint" => "这些选项会影响扫描结果；修改后需要重新扫描。",
-        "filters.advanced" => "高级选项",
-        "filters.advanced_hint" => {
-            "部分选项会影响扫描结果（修改后建议重新扫描）；大小筛选需要先计算大小。"
-        }
-        "filters.keep_days_label" => "保留最近编译(天)",
-        "filters.keep_days_hint" => "留空=不过滤",
-        "filters.keep_size_label" => "保留小项目(MB)",
-        "filters.keep_size_hint" => "留空=不过滤",
-        "filters.keep_executable" => "保留可执行文件",
-        "filters.backup_dir" => "备份目录:",
-        "filters.backup_dir_hint" => "留空=不备份",
-        "filters.ignore_paths" => "忽略路径",
-        "filters.ignore_add" => "添加",
-        "filters.ignore_remove" => "删除",
-        "projects.empty_message" => "点击扫描按钮开始查找Rust项目",
-        "projects.no_match" => "没有符合当前筛选条件的项目",
-        "projects.waiting_sizes" => "正在计算大小以应用筛选...",
-        "projects.size_filter_pending" => "大小筛选已启用：%{count} 个项目大小待计算",
-        "projects.size_unknown_disabled" => "大小计算中，暂无法选择（已启用大小筛选）",
-        "projects.found_message" => "找到 %{count} 个Rust项目",
-        "projects.showing_message" => "显示 %{visible}/%{total}",
-        "projects.selected_message" => "已选中: %{count} 个项目",
-        "projects.cleanable_size" => "可清理: %{size}",
-        "projects.clean_button" => "清理选中项目",
-        "projects.select_all" => "全选",
-        "projects.select_none" => "全不选",
-        "projects.invert_selection" => "反选",
-        "projects.column_name" => "项目",
-        "projects.column_size" => "大小",
-        "projects.column_modified" => "最近编译",
-        "projects.column_path" => "路径",
-        "projects.column_tags" => "标签",
-        "projects.tag_workspace" => "workspace",
-        "projects.no_target" => "无 target",
-        "details.title" => "详情",
-        "details.empty" => "点击列表中的项目查看详情",
-        "details.not_found" => "项目不存在或已被移除",
-        "details.path_label" => "路径",
-        "details.copy_path" => "复制路径",
-        "details.open_project" => "打开项目",
-        "details.open_target" => "打开 target",
-        "details.open_failed" => "打开失败",
-        "details.size_label" => "target 大小:",
-        "details.modified_label" => "最近编译:",
-        "details.selected" => "已选中",
-        "details.select_only" => "仅选中此项",
-        "details.time_unknown" => "未知",
-        "details.time_just_now" => "刚刚",
-        "details.time_minutes" => "%{n} 分钟前",
-        "details.time_hours" => "%{n} 小时前",
-        "details.time_days" => "%{n} 天前",
-        "actions.no_selection" => "未选择任何项目",
-        "actions.size_calculating" => "大小计算中…",
-        "actions.select_cleanable" => "全选(可清理)",
-        "clean.confirm_title" => "确认清理",
-        "clean.confirm_message" => "将清理 %{count} 个项目，预计释放 %{size}",
-        "clean.confirm_strategy" => "策略: %{strategy}",
-        "clean.confirm_button" => "开始清理",
-        "progress.scan_label" => "扫描进度:",
-        "progress.scan_found" => "已发现 %{count} 个Cargo.toml",
-        "progress.size_label" => "大小计算:",
-        "progress.clean_label" => "清理进度:",
-        "progress.current_project" => "当前项目:",
-        "progress.freed_size" => "已释放:",
-        "progress.last_result" => "上次清理结果:",
-        "progress.cleaned_projects" => "成功清理: %{count}",
-        "progress.freed_space" => "释放空间: %{size}",
-        "progress.duration" => "耗时: %{ms} ms",
-        "progress.failed_projects" => "失败: %{count}",
-        "progress.failed_so_far" => "已失败: %{count}",
-        "progress.failed_details" => "失败详情 (%{count})",
-        "progress.copy_failed" => "复制失败详情",
-        "dialog.settings_title" => "设置",
-        "dialog.about_title" => "关于",
-        "dialog.max_recent_paths" => "最大最近路径数:",
-        "dialog.auto_save_settings" => "自动保存设置",
-        "dialog.clean_timeout" => "清理超时(秒, 0=禁用):",
-        "dialog.direct_delete_backend" => "直接删除后端:",
-        "dialog.direct_delete_backend.native" => "原生(Rust)",
-        "dialog.direct_delete_backend.cmd_rmdir" => "极速(cmd rmdir)",
-        "dialog.direct_delete_backend_hint" => {
-            "Windows 可选：极速模式通常更快，但进度更粗；失败时会自动回退。"
-        }
-        "dialog.clear_recent_paths" => "清除最近路径",
-        "dialog.reset_defaults" => "重置为默认",
-        "dialog.ok" => "确定",
-        "dialog.cancel" => "取消",
-        "about.version" => "版本 0.4.1",
-        "about.description1" => "一个用于清理Rust项目构建目录的工具",
-        "about.description2" => "支持批量扫描和选择性清理",
-        "about.footer" => "使用egui构建 • 开源软件",
-        "strategy.cargo_clean" => "Cargo Clean",
-        "strategy.direct_delete" => "直接删除",
-        "language.label" => "语言:",
-        _ => return None,
-    };
-    Some(text.to_string())
+/// 各语言的翻译表（首次使用时由静态数据构建）
+static TRANSLATIONS: LazyLock<HashMap<Language, HashMap<&'static str, &'static str>>> =
+    LazyLock::new(|| {
+        Language::all()
+            .into_iter()
+            .map(|language| (language, language.entries().iter().copied().collect()))
+            .collect()
+    });
+
+fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    TRANSLATIONS.get(&language)?.get(key).copied()
 }
 
-fn translate_english(key: &str) -> Option<String> {
-    let text = match key {
-        "app.title" => "Rust Project Purger",
-        "menu.file" => "File",
-        "menu.settings" => "Settings",
-        "menu.help" => "Help",
-        "menu.select_folder" => "Select Folder...",
-        "menu.exit" => "Exit",
-        "menu.preferences" => "Preferences...",
-        "menu.about" => "About...",
-        "scan.path_label" => "Scan Path:",
-        "scan.path_hint" => "e.g. . or /path/to/projects",
-        "scan.max_depth_label" => "Max Depth:",
-        "scan.strategy_label" => "Clean Strategy:",
-        "scan.recent_paths_label" => "Recent Paths:",
-        "scan.recent_paths_placeholder" => "Select recent path...",
-        "scan.start_button" => "Start Scan",
-        "scan.browse_button" => "Browse...",
-        "scan.stop_button" => "Stop",
-        "scan.scanning_status" => "Scanning...",
-        "scan.sizing_status" => "Calculating sizes...",
-        "scan.strategy_cargo_clean" => "Cargo Clean (Recommended)",
-        "scan.strategy_direct_delete" => "Direct Delete",
-        "filters.title" => "Filters",
-        "filters.search_label" => "Search",
-        "filters.search_placeholder" => "Search by name or path...",
-        "filters.clear_search" => "Clear",
-        "filters.sort_label" => "Sort:",
-        "filters.sort.size_desc" => "Size (desc)",
-        "filters.sort.size_asc" => "Size (asc)",
-        "filters.sort.modified_desc" => "Last build (new→old)",
-        "filters.sort.modified_asc" => "Last build (old→new)",
-        "filters.sort.name_asc" => "Name (A→Z)",
-        "filters.sort.name_desc" => "Name (Z→A)",
-        "filters.sort.path_asc" => "Path",
-        "filters.sort.path_desc" => "Path (desc)",
-        "filters.selected_only" => "Selected only",
-        "filters.target_only" => "Only cleanable (has target)",
-        "filters.workspace_only" => "Workspace roots only",
-        "filters.scan_filters" => "Scan Filters (re-scan needed)",
-        "filters.scan_filters_hint" => "These options affect scan results; re-scan after changes.",
-        "filters.advanced" => "Advanced",
-        "filters.advanced_hint" => {
-            "Some options affect scan results (re-scan recommended); size filter needs size calculation."
-        }
-        "filters.keep_days_label" => "Keep recent (days)",
-        "filters.keep_days_hint" => "Empty = no filter",
-        "filters.keep_size_label" => "Keep small (MB)",
-        "filters.keep_size_hint" => "Empty = no filter",
-        "filters.keep_executable" => "Keep executables",
-        "filters.backup_dir" => "Backup dir:",
-        "filters.backup_dir_hint" => "Empty = no backup",
-        "filters.ignore_paths" => "Ignore paths",
-        "filters.ignore_add" => "Add",
-        "filters.ignore_remove" => "Remove",
-        "projects.empty_message" => "Click scan button to start finding Rust projects",
-        "projects.no_match" => "No projects match the current filters",
-        "projects.waiting_sizes" => "Calculating sizes to apply filters...",
-        "projects.size_filter_pending" => "Size filter enabled: %{count} pending size(s)",
-        "projects.size_unknown_disabled" => {
-            "Size pending; selection disabled (size filter enabled)"
-        }
-        "projects.found_message" => "Found %{count} Rust projects",
-        "projects.showing_message" => "Showing %{visible}/%{total}",
-        "projects.selected_message" => "Selected: %{count} projects",
-        "projects.cleanable_size" => "Cleanable: %{size}",
-        "projects.clean_button" => "Clean Selected Projects",
-        "projects.select_all" => "Select All",
-        "projects.select_none" => "Select None",
-        "projects.invert_selection" => "Invert Selection",
-        "projects.column_name" => "Project",
-        "projects.column_size" => "Size",
-        "projects.column_modified" => "Last build",
-        "projects.column_path" => "Path",
-        "projects.column_tags" => "Tags",
-        "projects.tag_workspace" => "workspace",
-        "projects.no_target" => "no target",
-        "details.title" => "Details",
-        "details.empty" => "Select a project to see details",
-        "details.not_found" => "Project not found",
-        "details.path_label" => "Path",
-        "details.copy_path" => "Copy Path",
-        "details.open_project" => "Open Project",
-        "details.open_target" => "Open target",
-        "details.open_failed" => "Open failed",
-        "details.size_label" => "target size:",
-        "details.modified_label" => "Last build:",
-        "details.selected" => "Selected",
-        "details.select_only" => "Select only",
-        "details.time_unknown" => "unknown",
-        "details.time_just_now" => "just now",
-        "details.time_minutes" => "%{n} min ago",
-        "details.time_hours" => "%{n} h ago",
-        "details.time_days" => "%{n} d ago",
-        "actions.no_selection" => "No selection",
-        "actions.size_calculating" => "Calculating sizes…",
-        "actions.select_cleanable" => "Select cleanable",
-        "clean.confirm_title" => "Confirm Clean",
-        "clean.confirm_message" => "Clean %{count} projects, estimate %{size} freed",
-        "clean.confirm_strategy" => "Strategy: %{strategy}",
-        "clean.confirm_button" => "Start Cleaning",
-        "progress.scan_label" => "Scan Progress:",
-        "progress.scan_found" => "Found %{count} Cargo.toml",
-        "progress.size_label" => "Size calculation:",
-        "progress.clean_label" => "Clean Progress:",
-        "progress.current_project" => "Current Project:",
-        "progress.freed_size" => "Freed:",
-        "progress.last_result" => "Last Clean Result:",
-        "progress.cleaned_projects" => "Cleaned: %{count}",
-        "progress.freed_space" => "Freed: %{size}",
-        "progress.duration" => "Duration: %{ms} ms",
-        "progress.failed_projects" => "Failed: %{count}",
-        "progress.failed_so_far" => "Failed: %{count}",
-        "progress.failed_details" => "Failure details (%{count})",
-        "progress.copy_failed" => "Copy failures",
-        "dialog.settings_title" => "Settings",
-        "dialog.about_title" => "About",
-        "dialog.max_recent_paths" => "Max Recent Paths:",
-        "dialog.auto_save_settings" => "Auto Save Settings",
-        "dialog.clean_timeout" => "Clean timeout (sec, 0=disabled):",
-        "dialog.direct_delete_backend" => "Direct delete backend:",
-        "dialog.direct_delete_backend.native" => "Native (Rust)",
-        "dialog.direct_delete_backend.cmd_rmdir" => "Turbo (cmd rmdir)",
-        "dialog.direct_delete_backend_hint" => {
-            "Windows only: Turbo is usually faster but has coarse progress; falls back on failure."
-        }
-        "dialog.clear_recent_paths" => "Clear Recent Paths",
-        "dialog.reset_defaults" => "Reset to Defaults",
-        "dialog.ok" => "OK",
-        "dialog.cancel" => "Cancel",
-        "about.version" => "Version 0.4.1",
-        "about.description1" => "A tool for cleaning Rust project build directories",
-        "about.description2" => "Supports batch scanning and selective cleaning",
-        "about.footer" => "Built with egui • Open Source Software",
-        "strategy.cargo_clean" => "Cargo Clean",
-        "strategy.direct_delete" => "Direct Delete",
-        "language.label" => "Language:",
-        _ => return None,
-    };
-    Some(text.to_string())
-}
+/// 中文翻译表
+const ZH_CN: &[(&str, &str)] = &[
+    ("app.title", "Rust Project Purger"),
+    ("menu.file", "文件"),
+    ("menu.settings", "设置"),
+    ("menu.help", "帮助"),
+    ("menu.select_folder", "选择文件夹..."),
+    ("menu.exit", "退出"),
+    ("menu.preferences", "首选项..."),
+    ("menu.about", "关于..."),
+    ("scan.path_label", "扫描路径:"),
+    ("scan.path_hint", "例如: . 或 /path/to/projects"),
+    ("scan.max_depth_label", "最大深度:"),
+    ("scan.strategy_label", "清理策略:"),
+    ("scan.recent_paths_label", "最近路径:"),
+    ("scan.recent_paths_placeholder", "选择最近路径..."),
+    ("scan.start_button", "开始扫描"),
+    ("scan.browse_button", "选择..."),
+    ("scan.stop_button", "停止"),
+    ("scan.scanning_status", "正在扫描..."),
+    ("scan.sizing_status", "正在计算大小..."),
+    ("scan.disk_space", "磁盘: 可用 %{free} / 共 %{total}"),
+    ("scan.strategy_cargo_clean", "Cargo Clean (推荐)"),
+    ("scan.strategy_direct_delete", "直接删除"),
+    ("scan.strategy_trash", "移到回收站 (可恢复)"),
+    ("filters.title", "筛选"),
+    ("filters.search_label", "搜索"),
+    ("filters.search_placeholder", "按名称或路径搜索..."),
+    ("filters.clear_search", "清空"),
+    ("filters.sort_label", "排序:"),
+    ("filters.sort.size_desc", "按大小(大→小)"),
+    ("filters.sort.size_asc", "按大小(小→大)"),
+    ("filters.sort.modified_desc", "按最近编译(新→旧)"),
+    ("filters.sort.modified_asc", "按最近编译(旧→新)"),
+    ("filters.sort.name_asc", "按名称(A→Z)"),
+    ("filters.sort.name_desc", "按名称(Z→A)"),
+    ("filters.sort.path_asc", "按路径"),
+    ("filters.sort.path_desc", "按路径(倒序)"),
+    ("filters.selected_only", "只看已选"),
+    ("filters.target_only", "只显示可清理(有 target)"),
+    ("filters.workspace_only", "只看 workspace 根"),
+    ("filters.scan_filters", "扫描过滤(需重新扫描)"),
+    (
+        "filters.scan_filters_hint",
+        "这些选项会影响扫描结果；修改后需要重新扫描。",
+    ),
+    ("filters.advanced", "高级选项"),
+    (
+        "filters.advanced_hint",
+        "部分选项会影响扫描结果（修改后建议重新扫描）；大小筛选需要先计算大小。",
+    ),
+    ("filters.keep_days_label", "保留最近编译(天)"),
+    ("filters.keep_days_hint", "留空=不过滤"),
+    ("filters.keep_size_label", "保留小项目(MB)"),
+    ("filters.keep_size_hint", "留空=不过滤"),
+    ("filters.keep_executable", "保留可执行文件"),
+    ("filters.backup_dir", "备份目录:"),
+    ("filters.backup_dir_hint", "留空=不备份"),
+    ("filters.ignore_paths", "忽略路径"),
+    ("filters.ignore_add", "添加"),
+    ("filters.ignore_remove", "删除"),
+    ("projects.empty_message", "点击扫描按钮开始查找Rust项目"),
+    ("projects.no_match", "没有符合当前筛选条件的项目"),
+    ("projects.waiting_sizes", "正在计算大小以应用筛选..."),
+    (
+        "projects.size_filter_pending",
+        "大小筛选已启用：%{count} 个项目大小待计算",
+    ),
+    (
+        "projects.size_unknown_disabled",
+        "大小计算中，暂无法选择（已启用大小筛选）",
+    ),
+    ("projects.found_message", "找到 %{count} 个Rust项目"),
+    ("projects.showing_message", "显示 %{visible}/%{total}"),
+    ("projects.selected_message", "已选中: %{count} 个项目"),
+    ("projects.cleanable_size", "可清理: %{size}"),
+    ("projects.clean_button", "清理选中项目"),
+    ("projects.preview_button", "预览清理"),
+    ("actions.dry_run", "预览模式"),
+    ("actions.pause", "暂停"),
+    ("actions.resume", "继续"),
+    (
+        "actions.dry_run_hint",
+        "开启后只统计将释放的空间，不会删除任何文件",
+    ),
+    ("projects.select_all", "全选"),
+    ("projects.select_none", "全不选"),
+    ("projects.invert_selection", "反选"),
+    ("projects.column_name", "项目"),
+    ("projects.column_size", "大小"),
+    ("projects.column_modified", "最近编译"),
+    ("projects.column_path", "路径"),
+    ("projects.column_tags", "标签"),
+    ("projects.tag_workspace", "workspace"),
+    ("projects.tag_member", "workspace 成员"),
+    ("projects.tag_pinned", "已固定 (.purger-keep)"),
+    ("projects.no_target", "无 target"),
+    ("projects.menu_clean", "清理此项目"),
+    ("projects.menu_open_folder", "打开文件夹"),
+    ("projects.menu_copy_path", "复制路径"),
+    ("projects.menu_ignore", "忽略（加入忽略列表）"),
+    ("details.title", "详情"),
+    ("details.empty", "点击列表中的项目查看详情"),
+    ("details.not_found", "项目不存在或已被移除"),
+    ("details.path_label", "路径"),
+    ("details.copy_path", "复制路径"),
+    ("details.open_project", "打开项目"),
+    ("details.open_target", "打开 target"),
+    ("details.open_failed", "打开失败"),
+    ("details.size_label", "target 大小:"),
+    ("details.largest_file_label", "最大文件:"),
+    ("details.members_label", "共享 target 的成员:"),
+    ("details.modified_label", "最近编译:"),
+    ("details.selected", "已选中"),
+    ("details.select_only", "仅选中此项"),
+    ("details.note_label", "备注:"),
+    ("details.note_hint", "例如：保留 —— 给客户演示用"),
+    ("details.time_unknown", "未知"),
+    ("details.time_just_now", "刚刚"),
+    ("details.time_minutes", "%{n} 分钟前"),
+    ("details.time_hours", "%{n} 小时前"),
+    ("details.time_days", "%{n} 天前"),
+    ("actions.no_selection", "未选择任何项目"),
+    ("actions.copy_summary_json", "复制 JSON 摘要"),
+    ("actions.size_calculating", "大小计算中…"),
+    ("actions.select_cleanable", "全选(可清理)"),
+    ("clean.confirm_title", "确认清理"),
+    (
+        "clean.confirm_message",
+        "将清理 %{count} 个项目，预计释放 %{size}",
+    ),
+    ("clean.confirm_strategy", "策略: %{strategy}"),
+    ("clean.confirm_button", "开始清理"),
+    ("clean.changed_title", "target 已变化"),
+    (
+        "clean.changed_message",
+        "以下 %{count} 个项目的 target 自扫描后发生了明显变化，尚未清理:",
+    ),
+    ("clean.changed_confirm_button", "仍然清理"),
+    ("clean.preview_confirm_button", "开始预览"),
+    ("clean.dry_run_notice", "预览模式：不会删除任何文件"),
+    (
+        "clean.direct_delete_warning",
+        "直接删除不经过回收站，删除后无法恢复",
+    ),
+    ("dialog.size_units", "大小单位:"),
+    ("dialog.size_units.binary", "二进制 (KiB/MiB, 1024)"),
+    ("dialog.size_units.decimal", "十进制 (KB/MB, 1000)"),
+    ("clean.phase_starting", "开始"),
+    ("clean.phase_analyzing", "分析"),
+    ("clean.phase_cleaning", "清理中"),
+    ("clean.phase_finalizing", "收尾中"),
+    ("clean.phase_complete", "完成"),
+    ("progress.scan_label", "扫描进度:"),
+    ("progress.scan_found", "已发现 %{count} 个Cargo.toml"),
+    ("progress.size_label", "大小计算:"),
+    ("progress.clean_label", "清理进度:"),
+    ("progress.paused", "已暂停"),
+    ("progress.eta", "剩余约 %{time}"),
+    ("progress.current_project", "当前项目:"),
+    ("progress.freed_size", "已释放:"),
+    ("progress.last_result", "上次清理结果:"),
+    ("progress.cleaned_projects", "成功清理: %{count}"),
+    ("progress.freed_space", "释放空间: %{size}"),
+    ("progress.last_preview", "上次预览结果（未删除任何文件）:"),
+    ("progress.would_free", "将释放空间: %{size}"),
+    ("progress.duration", "耗时: %{ms} ms"),
+    ("progress.failed_projects", "失败: %{count}"),
+    ("progress.failed_so_far", "已失败: %{count}"),
+    ("progress.failed_details", "失败详情 (%{count})"),
+    ("progress.copy_failed", "复制失败详情"),
+    ("dialog.settings_title", "设置"),
+    ("dialog.about_title", "关于"),
+    ("dialog.max_recent_paths", "最大最近路径数:"),
+    ("dialog.auto_save_settings", "自动保存设置"),
+    ("dialog.confirm_before_clean", "清理前确认"),
+    ("dialog.clean_timeout", "清理超时(秒, 0=禁用):"),
+    ("dialog.on_target_changed", "清理前大小变化时:"),
+    ("dialog.on_target_changed.ignore", "不检查"),
+    ("dialog.on_target_changed.warn", "仅警告"),
+    ("dialog.on_target_changed.skip", "跳过"),
+    ("dialog.on_target_changed.confirm", "重新确认"),
+    ("dialog.changed_threshold", "变化阈值(%):"),
+    ("dialog.direct_delete_backend", "直接删除后端:"),
+    ("dialog.direct_delete_backend.native", "原生(Rust)"),
+    ("dialog.direct_delete_backend.cmd_rmdir", "极速(cmd rmdir)"),
+    (
+        "dialog.direct_delete_backend_hint",
+        "Windows 可选：极速模式通常更快，但进度更粗；失败时会自动回退。",
+    ),
+    ("dialog.clear_recent_paths", "清除最近路径"),
+    ("dialog.reset_defaults", "重置为默认"),
+    ("dialog.ok", "确定"),
+    ("dialog.cancel", "取消"),
+    ("about.version", "版本 0.4.1"),
+    ("about.description1", "一个用于清理Rust项目构建目录的工具"),
+    ("about.description2", "支持批量扫描和选择性清理"),
+    ("about.footer", "使用egui构建 • 开源软件"),
+    ("strategy.cargo_clean", "Cargo Clean"),
+    ("strategy.direct_delete", "直接删除"),
+    ("strategy.trash", "移到回收站"),
+    ("language.label", "语言:"),
+];
+
+/// 英文翻译表
+const EN: &[(&str, &str)] = &[
+    ("app.title", "Rust Project Purger"),
+    ("menu.file", "File"),
+    ("menu.settings", "Settings"),
+    ("menu.help", "Help"),
+    ("menu.select_folder", "Select Folder..."),
+    ("menu.exit", "Exit"),
+    ("menu.preferences", "Preferences..."),
+    ("menu.about", "About..."),
+    ("scan.path_label", "Scan Path:"),
+    ("scan.path_hint", "e.g. . or /path/to/projects"),
+    ("scan.max_depth_label", "Max Depth:"),
+    ("scan.strategy_label", "Clean Strategy:"),
+    ("scan.recent_paths_label", "Recent Paths:"),
+    ("scan.recent_paths_placeholder", "Select recent path..."),
+    ("scan.start_button", "Start Scan"),
+    ("scan.browse_button", "Browse..."),
+    ("scan.stop_button", "Stop"),
+    ("scan.scanning_status", "Scanning..."),
+    ("scan.sizing_status", "Calculating sizes..."),
+    ("scan.disk_space", "Disk: %{free} free of %{total}"),
+    ("scan.strategy_cargo_clean", "Cargo Clean (Recommended)"),
+    ("scan.strategy_direct_delete", "Direct Delete"),
+    ("scan.strategy_trash", "Move to Trash (Recoverable)"),
+    ("filters.title", "Filters"),
+    ("filters.search_label", "Search"),
+    ("filters.search_placeholder", "Search by name or path..."),
+    ("filters.clear_search", "Clear"),
+    ("filters.sort_label", "Sort:"),
+    ("filters.sort.size_desc", "Size (desc)"),
+    ("filters.sort.size_asc", "Size (asc)"),
+    ("filters.sort.modified_desc", "Last build (new→old)"),
+    ("filters.sort.modified_asc", "Last build (old→new)"),
+    ("filters.sort.name_asc", "Name (A→Z)"),
+    ("filters.sort.name_desc", "Name (Z→A)"),
+    ("filters.sort.path_asc", "Path"),
+    ("filters.sort.path_desc", "Path (desc)"),
+    ("filters.selected_only", "Selected only"),
+    ("filters.target_only", "Only cleanable (has target)"),
+    ("filters.workspace_only", "Workspace roots only"),
+    ("filters.scan_filters", "Scan Filters (re-scan needed)"),
+    (
+        "filters.scan_filters_hint",
+        "These options affect scan results; re-scan after changes.",
+    ),
+    ("filters.advanced", "Advanced"),
+    (
+        "filters.advanced_hint",
+        "Some options affect scan results (re-scan recommended); size filter needs size calculation.",
+    ),
+    ("filters.keep_days_label", "Keep recent (days)"),
+    ("filters.keep_days_hint", "Empty = no filter"),
+    ("filters.keep_size_label", "Keep small (MB)"),
+    ("filters.keep_size_hint", "Empty = no filter"),
+    ("filters.keep_executable", "Keep executables"),
+    ("filters.backup_dir", "Backup dir:"),
+    ("filters.backup_dir_hint", "Empty = no backup"),
+    ("filters.ignore_paths", "Ignore paths"),
+    ("filters.ignore_add", "Add"),
+    ("filters.ignore_remove", "Remove"),
+    (
+        "projects.empty_message",
+        "Click scan button to start finding Rust projects",
+    ),
+    ("projects.no_match", "No projects match the current filters"),
+    (
+        "projects.waiting_sizes",
+        "Calculating sizes to apply filters...",
+    ),
+    (
+        "projects.size_filter_pending",
+        "Size filter enabled: %{count} pending size(s)",
+    ),
+    (
+        "projects.size_unknown_disabled",
+        "Size pending; selection disabled (size filter enabled)",
+    ),
+    ("projects.found_message", "Found %{count} Rust projects"),
+    ("projects.showing_message", "Showing %{visible}/%{total}"),
+    ("projects.selected_message", "Selected: %{count} projects"),
+    ("projects.cleanable_size", "Cleanable: %{size}"),
+    ("projects.clean_button", "Clean Selected Projects"),
+    ("projects.preview_button", "Preview Clean"),
+    ("actions.dry_run", "Dry run"),
+    ("actions.pause", "Pause"),
+    ("actions.resume", "Resume"),
+    (
+        "actions.dry_run_hint",
+        "Only report what would be freed; nothing is deleted",
+    ),
+    ("projects.select_all", "Select All"),
+    ("projects.select_none", "Select None"),
+    ("projects.invert_selection", "Invert Selection"),
+    ("projects.column_name", "Project"),
+    ("projects.column_size", "Size"),
+    ("projects.column_modified", "Last build"),
+    ("projects.column_path", "Path"),
+    ("projects.column_tags", "Tags"),
+    ("projects.tag_workspace", "workspace"),
+    ("projects.tag_member", "workspace member"),
+    ("projects.tag_pinned", "pinned (.purger-keep)"),
+    ("projects.no_target", "no target"),
+    ("projects.menu_clean", "Clean this project"),
+    ("projects.menu_open_folder", "Open folder"),
+    ("projects.menu_copy_path", "Copy path"),
+    ("projects.menu_ignore", "Ignore (add to ignore list)"),
+    ("details.title", "Details"),
+    ("details.empty", "Select a project to see details"),
+    ("details.not_found", "Project not found"),
+    ("details.path_label", "Path"),
+    ("details.copy_path", "Copy Path"),
+    ("details.open_project", "Open Project"),
+    ("details.open_target", "Open target"),
+    ("details.open_failed", "Open failed"),
+    ("details.size_label", "target size:"),
+    ("details.largest_file_label", "Largest file:"),
+    ("details.members_label", "Members sharing this target:"),
+    ("details.modified_label", "Last build:"),
+    ("details.selected", "Selected"),
+    ("details.select_only", "Select only"),
+    ("details.note_label", "Note:"),
+    ("details.note_hint", "e.g. keep — demo for a client"),
+    ("details.time_unknown", "unknown"),
+    ("details.time_just_now", "just now"),
+    ("details.time_minutes", "%{n} min ago"),
+    ("details.time_hours", "%{n} h ago"),
+    ("details.time_days", "%{n} d ago"),
+    ("actions.no_selection", "No selection"),
+    ("actions.copy_summary_json", "Copy Summary as JSON"),
+    ("actions.size_calculating", "Calculating sizes…"),
+    ("actions.select_cleanable", "Select cleanable"),
+    ("clean.confirm_title", "Confirm Clean"),
+    (
+        "clean.confirm_message",
+        "Clean %{count} projects, estimate %{size} freed",
+    ),
+    ("clean.confirm_strategy", "Strategy: %{strategy}"),
+    ("clean.confirm_button", "Start Cleaning"),
+    ("clean.changed_title", "Target Changed"),
+    (
+        "clean.changed_message",
+        "%{count} projects changed significantly since the scan and were not cleaned:",
+    ),
+    ("clean.changed_confirm_button", "Clean Anyway"),
+    ("clean.preview_confirm_button", "Start Preview"),
+    ("clean.dry_run_notice", "Dry run: no files will be deleted"),
+    (
+        "clean.direct_delete_warning",
+        "Direct delete bypasses the recycle bin and cannot be undone",
+    ),
+    ("dialog.size_units", "Size units:"),
+    ("dialog.size_units.binary", "Binary (KiB/MiB, 1024)"),
+    ("dialog.size_units.decimal", "Decimal (KB/MB, 1000)"),
+    ("clean.phase_starting", "Starting"),
+    ("clean.phase_analyzing", "Analyzing"),
+    ("clean.phase_cleaning", "Cleaning"),
+    ("clean.phase_finalizing", "Finalizing"),
+    ("clean.phase_complete", "Done"),
+    ("progress.scan_label", "Scan Progress:"),
+    ("progress.scan_found", "Found %{count} Cargo.toml"),
+    ("progress.size_label", "Size calculation:"),
+    ("progress.clean_label", "Clean Progress:"),
+    ("progress.paused", "Paused"),
+    ("progress.eta", "~%{time} remaining"),
+    ("progress.current_project", "Current Project:"),
+    ("progress.freed_size", "Freed:"),
+    ("progress.last_result", "Last Clean Result:"),
+    ("progress.cleaned_projects", "Cleaned: %{count}"),
+    ("progress.freed_space", "Freed: %{size}"),
+    ("progress.last_preview", "Last Preview (nothing deleted):"),
+    ("progress.would_free", "Would free: %{size}"),
+    ("progress.duration", "Duration: %{ms} ms"),
+    ("progress.failed_projects", "Failed: %{count}"),
+    ("progress.failed_so_far", "Failed: %{count}"),
+    ("progress.failed_details", "Failure details (%{count})"),
+    ("progress.copy_failed", "Copy failures"),
+    ("dialog.settings_title", "Settings"),
+    ("dialog.about_title", "About"),
+    ("dialog.max_recent_paths", "Max Recent Paths:"),
+    ("dialog.auto_save_settings", "Auto Save Settings"),
+    ("dialog.confirm_before_clean", "Confirm before cleaning"),
+    ("dialog.clean_timeout", "Clean timeout (sec, 0=disabled):"),
+    (
+        "dialog.on_target_changed",
+        "If target changed before clean:",
+    ),
+    ("dialog.on_target_changed.ignore", "Don't check"),
+    ("dialog.on_target_changed.warn", "Warn"),
+    ("dialog.on_target_changed.skip", "Skip"),
+    ("dialog.on_target_changed.confirm", "Ask again"),
+    ("dialog.changed_threshold", "Change threshold (%):"),
+    ("dialog.direct_delete_backend", "Direct delete backend:"),
+    ("dialog.direct_delete_backend.native", "Native (Rust)"),
+    (
+        "dialog.direct_delete_backend.cmd_rmdir",
+        "Turbo (cmd rmdir)",
+    ),
+    (
+        "dialog.direct_delete_backend_hint",
+        "Windows only: Turbo is usually faster but has coarse progress; falls back on failure.",
+    ),
+    ("dialog.clear_recent_paths", "Clear Recent Paths"),
+    ("dialog.reset_defaults", "Reset to Defaults"),
+    ("dialog.ok", "OK"),
+    ("dialog.cancel", "Cancel"),
+    ("about.version", "Version 0.4.1"),
+    (
+        "about.description1",
+        "A tool for cleaning Rust project build directories",
+    ),
+    (
+        "about.description2",
+        "Supports batch scanning and selective cleaning",
+    ),
+    ("about.footer", "Built with egui • Open Source Software"),
+    ("strategy.cargo_clean", "Cargo Clean"),
+    ("strategy.direct_delete", "Direct Delete"),
+    ("strategy.trash", "Move to Trash"),
+    ("language.label", "Language:"),
+];
+
+/// 日文翻译表
+const JA: &[(&str, &str)] = &[
+    ("app.title", "Rust Project Purger"),
+    ("menu.file", "ファイル"),
+    ("menu.settings", "設定"),
+    ("menu.help", "ヘルプ"),
+    ("menu.select_folder", "フォルダーを選択..."),
+    ("menu.exit", "終了"),
+    ("menu.preferences", "環境設定..."),
+    ("menu.about", "バージョン情報..."),
+    ("scan.path_label", "スキャンパス:"),
+    ("scan.path_hint", "例: . または /path/to/projects"),
+    ("scan.max_depth_label", "最大深度:"),
+    ("scan.strategy_label", "クリーン方式:"),
+    ("scan.recent_paths_label", "最近のパス:"),
+    ("scan.recent_paths_placeholder", "最近のパスを選択..."),
+    ("scan.start_button", "スキャン開始"),
+    ("scan.browse_button", "参照..."),
+    ("scan.stop_button", "停止"),
+    ("scan.scanning_status", "スキャン中..."),
+    ("scan.sizing_status", "サイズを計算中..."),
+    ("scan.disk_space", "ディスク: 空き %{free} / 全体 %{total}"),
+    ("scan.strategy_cargo_clean", "Cargo Clean (推奨)"),
+    ("scan.strategy_direct_delete", "直接削除"),
+    ("scan.strategy_trash", "ごみ箱へ移動 (復元可能)"),
+    ("filters.title", "フィルター"),
+    ("filters.search_label", "検索"),
+    ("filters.search_placeholder", "名前またはパスで検索..."),
+    ("filters.clear_search", "クリア"),
+    ("filters.sort_label", "並べ替え:"),
+    ("filters.sort.size_desc", "サイズ (大→小)"),
+    ("filters.sort.size_asc", "サイズ (小→大)"),
+    ("filters.sort.modified_desc", "最終ビルド (新→旧)"),
+    ("filters.sort.modified_asc", "最終ビルド (旧→新)"),
+    ("filters.sort.name_asc", "名前 (A→Z)"),
+    ("filters.sort.name_desc", "名前 (Z→A)"),
+    ("filters.sort.path_asc", "パス"),
+    ("filters.sort.path_desc", "パス (降順)"),
+    ("filters.selected_only", "選択済みのみ"),
+    ("filters.target_only", "クリーン可能のみ (target あり)"),
+    ("filters.workspace_only", "workspace ルートのみ"),
+    (
+        "filters.scan_filters",
+        "スキャンフィルター (再スキャンが必要)",
+    ),
+    (
+        "filters.scan_filters_hint",
+        "これらのオプションはスキャン結果に影響します。変更後は再スキャンしてください。",
+    ),
+    ("filters.advanced", "詳細オプション"),
+    (
+        "filters.advanced_hint",
+        "一部のオプションはスキャン結果に影響します (再スキャン推奨)。サイズフィルターにはサイズ計算が必要です。",
+    ),
+    ("filters.keep_days_label", "最近ビルドしたものを保持 (日)"),
+    ("filters.keep_days_hint", "空欄 = フィルターなし"),
+    ("filters.keep_size_label", "小さいプロジェクトを保持 (MB)"),
+    ("filters.keep_size_hint", "空欄 = フィルターなし"),
+    ("filters.keep_executable", "実行ファイルを保持"),
+    ("filters.backup_dir", "バックアップ先:"),
+    ("filters.backup_dir_hint", "空欄 = バックアップしない"),
+    ("filters.ignore_paths", "除外パス"),
+    ("filters.ignore_add", "追加"),
+    ("filters.ignore_remove", "削除"),
+    (
+        "projects.empty_message",
+        "スキャンボタンをクリックして Rust プロジェクトの検索を開始します",
+    ),
+    (
+        "projects.no_match",
+        "現在のフィルターに一致するプロジェクトはありません",
+    ),
+    (
+        "projects.waiting_sizes",
+        "フィルターを適用するためにサイズを計算中...",
+    ),
+    (
+        "projects.size_filter_pending",
+        "サイズフィルター有効: %{count} 件のサイズを計算待ち",
+    ),
+    (
+        "projects.size_unknown_disabled",
+        "サイズ計算中のため選択できません (サイズフィルター有効)",
+    ),
+    (
+        "projects.found_message",
+        "%{count} 件の Rust プロジェクトが見つかりました",
+    ),
+    ("projects.showing_message", "%{visible}/%{total} 件を表示"),
+    ("projects.selected_message", "選択中: %{count} 件"),
+    ("projects.cleanable_size", "クリーン可能: %{size}"),
+    ("projects.clean_button", "選択したプロジェクトをクリーン"),
+    ("projects.preview_button", "クリーンをプレビュー"),
+    ("actions.dry_run", "プレビューモード"),
+    ("actions.pause", "一時停止"),
+    ("actions.resume", "再開"),
+    (
+        "actions.dry_run_hint",
+        "解放される容量を集計するだけで、ファイルは削除しません",
+    ),
+    ("projects.select_all", "すべて選択"),
+    ("projects.select_none", "選択解除"),
+    ("projects.invert_selection", "選択を反転"),
+    ("projects.column_name", "プロジェクト"),
+    ("projects.column_size", "サイズ"),
+    ("projects.column_modified", "最終ビルド"),
+    ("projects.column_path", "パス"),
+    ("projects.column_tags", "タグ"),
+    ("projects.tag_workspace", "workspace"),
+    ("projects.tag_member", "workspace メンバー"),
+    ("projects.tag_pinned", "固定 (.purger-keep)"),
+    ("projects.no_target", "target なし"),
+    ("projects.menu_clean", "このプロジェクトをクリーン"),
+    ("projects.menu_open_folder", "フォルダーを開く"),
+    ("projects.menu_copy_path", "パスをコピー"),
+    ("projects.menu_ignore", "除外 (除外リストに追加)"),
+    ("details.title", "詳細"),
+    (
+        "details.empty",
+        "一覧のプロジェクトをクリックすると詳細を表示します",
+    ),
+    (
+        "details.not_found",
+        "プロジェクトが存在しないか、削除されました",
+    ),
+    ("details.path_label", "パス"),
+    ("details.copy_path", "パスをコピー"),
+    ("details.open_project", "プロジェクトを開く"),
+    ("details.open_target", "target を開く"),
+    ("details.open_failed", "開けませんでした"),
+    ("details.size_label", "target サイズ:"),
+    ("details.largest_file_label", "最大のファイル:"),
+    ("details.members_label", "target を共有するメンバー:"),
+    ("details.modified_label", "最終ビルド:"),
+    ("details.selected", "選択中"),
+    ("details.select_only", "これだけを選択"),
+    ("details.note_label", "メモ:"),
+    ("details.note_hint", "例: 保持 — 顧客デモ用"),
+    ("details.time_unknown", "不明"),
+    ("details.time_just_now", "たった今"),
+    ("details.time_minutes", "%{n} 分前"),
+    ("details.time_hours", "%{n} 時間前"),
+    ("details.time_days", "%{n} 日前"),
+    ("actions.no_selection", "何も選択されていません"),
+    ("actions.copy_summary_json", "JSON 概要をコピー"),
+    ("actions.size_calculating", "サイズを計算中…"),
+    ("actions.select_cleanable", "クリーン可能をすべて選択"),
+    ("clean.confirm_title", "クリーンの確認"),
+    (
+        "clean.confirm_message",
+        "%{count} 件のプロジェクトをクリーンし、約 %{size} を解放します",
+    ),
+    ("clean.confirm_strategy", "方式: %{strategy}"),
+    ("clean.confirm_button", "クリーン開始"),
+    ("clean.changed_title", "target が変化しました"),
+    (
+        "clean.changed_message",
+        "次の %{count} 件のプロジェクトはスキャン後に target が大きく変化したため、クリーンしていません:",
+    ),
+    ("clean.changed_confirm_button", "それでもクリーン"),
+    ("clean.preview_confirm_button", "プレビュー開始"),
+    (
+        "clean.dry_run_notice",
+        "プレビューモード: ファイルは削除されません",
+    ),
+    (
+        "clean.direct_delete_warning",
+        "直接削除はごみ箱を経由せず、元に戻せません",
+    ),
+    ("dialog.size_units", "サイズの単位:"),
+    ("dialog.size_units.binary", "2 進 (KiB/MiB, 1024)"),
+    ("dialog.size_units.decimal", "10 進 (KB/MB, 1000)"),
+    ("clean.phase_starting", "開始"),
+    ("clean.phase_analyzing", "分析中"),
+    ("clean.phase_cleaning", "クリーン中"),
+    ("clean.phase_finalizing", "仕上げ中"),
+    ("clean.phase_complete", "完了"),
+    ("progress.scan_label", "スキャン進捗:"),
+    ("progress.scan_found", "%{count} 個の Cargo.toml を発見"),
+    ("progress.size_label", "サイズ計算:"),
+    ("progress.clean_label", "クリーン進捗:"),
+    ("progress.paused", "一時停止中"),
+    ("progress.eta", "残り約 %{time}"),
+    ("progress.current_project", "現在のプロジェクト:"),
+    ("progress.freed_size", "解放済み:"),
+    ("progress.last_result", "前回のクリーン結果:"),
+    ("progress.cleaned_projects", "成功: %{count}"),
+    ("progress.freed_space", "解放した容量: %{size}"),
+    (
+        "progress.last_preview",
+        "前回のプレビュー結果 (ファイルは削除されていません):",
+    ),
+    ("progress.would_free", "解放予定の容量: %{size}"),
+    ("progress.duration", "所要時間: %{ms} ms"),
+    ("progress.failed_projects", "失敗: %{count}"),
+    ("progress.failed_so_far", "失敗: %{count}"),
+    ("progress.failed_details", "失敗の詳細 (%{count})"),
+    ("progress.copy_failed", "失敗の詳細をコピー"),
+    ("dialog.settings_title", "設定"),
+    ("dialog.about_title", "バージョン情報"),
+    ("dialog.max_recent_paths", "最近のパスの最大数:"),
+    ("dialog.auto_save_settings", "設定を自動保存"),
+    ("dialog.confirm_before_clean", "クリーン前に確認"),
+    (
+        "dialog.clean_timeout",
+        "クリーンのタイムアウト (秒, 0=無効):",
+    ),
+    (
+        "dialog.on_target_changed",
+        "クリーン前にサイズが変化した場合:",
+    ),
+    ("dialog.on_target_changed.ignore", "確認しない"),
+    ("dialog.on_target_changed.warn", "警告のみ"),
+    ("dialog.on_target_changed.skip", "スキップ"),
+    ("dialog.on_target_changed.confirm", "再確認"),
+    ("dialog.changed_threshold", "変化のしきい値 (%):"),
+    ("dialog.direct_delete_backend", "直接削除のバックエンド:"),
+    ("dialog.direct_delete_backend.native", "ネイティブ (Rust)"),
+    ("dialog.direct_delete_backend.cmd_rmdir", "高速 (cmd rmdir)"),
+    (
+        "dialog.direct_delete_backend_hint",
+        "Windows のみ: 高速モードは通常より速いものの進捗は粗くなります。失敗時は自動的にフォールバックします。",
+    ),
+    ("dialog.clear_recent_paths", "最近のパスをクリア"),
+    ("dialog.reset_defaults", "既定値に戻す"),
+    ("dialog.ok", "OK"),
+    ("dialog.cancel", "キャンセル"),
+    ("about.version", "バージョン 0.4.1"),
+    (
+        "about.description1",
+        "Rust プロジェクトのビルドディレクトリをクリーンするツール",
+    ),
+    ("about.description2", "一括スキャンと選択的なクリーンに対応"),
+    ("about.footer", "egui で構築 • オープンソースソフトウェア"),
+    ("strategy.cargo_clean", "Cargo Clean"),
+    ("strategy.direct_delete", "直接削除"),
+    ("strategy.trash", "ごみ箱へ移動"),
+    ("language.label", "言語:"),
+];
 
 #[cfg(test)]
 mod tests {
@@ -417,6 +833,7 @@ mod tests {
     fn test_language_display_name() {
         assert_eq!(Language::Chinese.display_name(), "中文");
         assert_eq!(Language::English.display_name(), "English");
+        assert_eq!(Language::Japanese.display_name(), "日本語");
     }
 
     #[test]
@@ -439,6 +856,21 @@ mod tests {
         assert_eq!(result, "找到 5 个Rust项目");
     }
 
+    #[test]
+    fn test_all_languages_have_every_key() {
+        let english: Vec<&str> = EN.iter().map(|(key, _)| *key).collect();
+        for language in Language::all() {
+            let entries = language.entries();
+            let keys: std::collections::HashSet<&str> =
+                entries.iter().map(|(key, _)| *key).collect();
+            assert_eq!(keys.len(), entries.len(), "duplicate keys in {language:?}");
+            for key in &english {
+                assert!(keys.contains(key), "{language:?} is missing {key}");
+            }
+            assert_eq!(keys.len(), english.len(), "extra keys in {language:?}");
+        }
+    }
+
     #[test]
     fn test_unknown_key() {
         assert_eq!(translate("unknown.key"), "unknown.key");
@@ -462,15 +894,16 @@ mod tests {
     fn test_system_language_detection() {
         let detected = detect_system_language();
         // 应该返回有效的语言
-        assert!(matches!(detected, Language::Chinese | Language::English));
+        assert!(Language::all().contains(&detected));
     }
 
     #[test]
     fn test_language_all() {
         let languages = Language::all();
-        assert_eq!(languages.len(), 2);
+        assert_eq!(languages.len(), 3);
         assert!(languages.contains(&Language::Chinese));
         assert!(languages.contains(&Language::English));
+        assert!(languages.contains(&Language::Japanese));
     }
 
     #[test]
@@ -483,6 +916,7 @@ mod tests {
     fn test_language_from_code() {
         assert_eq!(Language::from_code("zh-CN"), Some(Language::Chinese));
         assert_eq!(Language::from_code("en"), Some(Language::English));
+        assert_eq!(Language::from_code("ja"), Some(Language::Japanese));
         assert_eq!(Language::from_code("fr"), None);
         assert_eq!(Language::from_code(""), None);
         assert_eq!(Language::from_code("invalid"), None);
@@ -0,0 +1,39 @@
+use purger_core::SizeUnits;
+use std::sync::RwLock;
+
+// 全局大小单位设置（与界面语言相互独立）
+static CURRENT_SIZE_UNITS: RwLock<SizeUnits> = RwLock::new(SizeUnits::Binary);
+
+/// 设置当前大小单位
+pub fn set_size_units(units: SizeUnits) {
+    if let Ok(mut current) = CURRENT_SIZE_UNITS.write() {
+        *current = units;
+    }
+}
+
+/// 获取当前大小单位
+pub fn current_size_units() -> SizeUnits {
+    CURRENT_SIZE_UNITS
+        .read()
+        .map(|units| *units)
+        .unwrap_or_default()
+}
+
+/// 按当前单位设置格式化字节大小
+pub fn format_size(bytes: u64) -> String {
+    purger_core::format_bytes_with(bytes, current_size_units())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size_follows_setting() {
+        set_size_units(SizeUnits::Decimal);
+        assert_eq!(format_size(1000), "1.00 KB");
+
+        set_size_units(SizeUnits::Binary);
+        assert_eq!(format_size(1024), "1.00 KiB");
+    }
+}
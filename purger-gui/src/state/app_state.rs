@@ -1,6 +1,7 @@
-use purger_core::{CleanProgress, CleanResult, RustProject};
-use std::collections::HashSet;
-use std::path::PathBuf;
+use purger_core::{CleanProgress, CleanResult, LargestFile, RustProject};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 /// Application runtime state
 #[derive(PartialEq, Debug, Clone)]
@@ -16,13 +17,14 @@ pub enum AppMessage {
     ScanProgress(usize, usize), // (current, total)
     ScanComplete(Vec<RustProject>),
     ScanError(String),
-    SizeProgress(usize, usize),          // (current, total)
-    ProjectSizeUpdate(PathBuf, u64),     // (project_path, target_size)
-    CleanProgress(usize, usize, u64),    // (current, total, size_freed_so_far)
-    CleanProjectStart(String),           // project_name
-    CleanProjectProgress(CleanProgress), // 详细的项目清理进度
-    CleanProjectComplete(String, u64),   // (project_name, size_freed)
-    CleanProjectError(String, String),   // (project_name, error)
+    SizeProgress(usize, usize), // (current, total)
+    ProjectSizeUpdate(PathBuf, u64, Option<LargestFile>), // (project_path, target_size, largest_file)
+    CleanProgress(usize, usize, u64),                     // (current, total, size_freed_so_far)
+    CleanProjectStart(String),                            // project_name
+    CleanProjectProgress(CleanProgress),                  // 详细的项目清理进度
+    CleanProjectComplete(String, u64),                    // (project_name, size_freed)
+    CleanProjectError(String, String),                    // (project_name, error)
+    CleanNeedsConfirm(Vec<(RustProject, u64, u64)>),      // (project, scanned_size, current_size)
     CleanComplete(CleanResult),
 }
 
@@ -33,16 +35,20 @@ pub struct AppData {
     pub projects: Vec<RustProject>,
     pub selected_projects: HashSet<PathBuf>,
     pub focused_project: Option<PathBuf>,
+    pub notes: HashMap<PathBuf, String>, // 当前项目的备注（以 project.path 为键）
 
     // 进度状态
     pub scan_progress: Option<(usize, usize)>, // (current, total)
     pub size_progress: Option<(usize, usize)>, // (current, total)
     pub clean_progress: Option<(usize, usize, u64)>, // (current, total, size_freed)
+    pub clean_started: Option<Instant>,        // 本次清理开始的时间，用于估算剩余时间
     pub current_cleaning_project: Option<String>, // 当前正在清理的项目名
     pub clean_errors: Vec<(String, String)>,
+    pub clean_paused: bool,
 
     // 结果
     pub last_clean_result: Option<CleanResult>,
+    pub last_clean_dry_run: bool, // 上次结果是否来自预览模式
     pub error_message: Option<String>,
 }
 
@@ -115,20 +121,25 @@ impl AppData {
         self.focused_project = Some(project.path.clone());
     }
 
-    /// Select all cleanable projects (those with `target`)
+    /// Select exactly the cleanable projects (with a non-empty `target`), dropping any other selection
     pub fn select_all_cleanable(&mut self) {
+        self.selected_projects = self
+            .projects
+            .iter()
+            .filter(|project| project.has_target && project.target_size > 0 && !project.pinned)
+            .map(|project| project.path.clone())
+            .collect();
+    }
+
+    /// Select all (every project with `target`, even if its size is still unknown)
+    pub fn select_all(&mut self) {
         for project in &self.projects {
-            if project.has_target {
+            if project.has_target && !project.pinned {
                 self.selected_projects.insert(project.path.clone());
             }
         }
     }
 
-    /// Select all
-    pub fn select_all(&mut self) {
-        self.select_all_cleanable();
-    }
-
     /// Select none
     pub fn select_none(&mut self) {
         self.selected_projects.clear();
@@ -138,13 +149,51 @@ impl AppData {
     pub fn invert_selection(&mut self) {
         let mut next = HashSet::new();
         for project in &self.projects {
-            if project.has_target && !self.selected_projects.contains(&project.path) {
+            if project.has_target
+                && !project.pinned
+                && !self.selected_projects.contains(&project.path)
+            {
                 next.insert(project.path.clone());
             }
         }
         self.selected_projects = next;
     }
 
+    /// Track size-calculation progress; finished (or empty) runs clear it
+    pub fn set_size_progress(&mut self, current: usize, total: usize) {
+        self.size_progress = if total == 0 || current >= total {
+            None
+        } else {
+            Some((current, total))
+        };
+    }
+
+    /// 更新后台算出的项目大小（按路径匹配），项目已不在列表中时返回 false
+    pub fn update_project_size(
+        &mut self,
+        path: &Path,
+        size: u64,
+        largest_file: Option<LargestFile>,
+    ) -> bool {
+        match self.projects.iter_mut().find(|p| p.path == path) {
+            Some(project) => {
+                project.target_size = size;
+                project.largest_file = largest_file;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove a project from the list (and from selection/focus)
+    pub fn remove_project(&mut self, path: &Path) {
+        self.projects.retain(|project| project.path != path);
+        self.selected_projects.remove(path);
+        if self.focused_project.as_deref() == Some(path) {
+            self.focused_project = None;
+        }
+    }
+
     /// Clear the last error message
     #[allow(dead_code)]
     pub fn clear_error(&mut self) {
@@ -157,6 +206,7 @@ impl AppData {
         self.scan_progress = None;
         self.size_progress = None;
         self.clean_progress = None;
+        self.clean_started = None;
         self.current_cleaning_project = None;
     }
 }
@@ -172,8 +222,15 @@ mod tests {
             name: name.to_string(),
             target_size,
             last_modified: SystemTime::now(),
-            is_workspace: false,
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
             has_target,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
         }
     }
 
@@ -309,6 +366,37 @@ mod tests {
         assert_eq!(data.get_selected_count(), 2);
     }
 
+    #[test]
+    fn test_select_all_cleanable() {
+        let mut data = AppData::new();
+        let mut pinned = create_test_project("pinned", 4000, true);
+        pinned.pinned = true;
+        let projects = vec![
+            create_test_project("project1", 1000, true),
+            create_test_project("empty", 0, true), // target 为空（或大小未算出）
+            create_test_project("no_target", 3000, false),
+            create_test_project("project2", 2000, true),
+            pinned,
+        ];
+
+        data.set_projects(projects);
+        // 之前选中的不可清理项目会被取消
+        let empty = data.projects[1].clone();
+        data.set_selected(&empty, true);
+        assert_eq!(data.get_selected_count(), 1);
+
+        data.select_all_cleanable();
+
+        assert_eq!(data.get_selected_count(), 2);
+        assert_eq!(data.get_total_cleanable_size(), 3000);
+        let names: Vec<_> = data
+            .get_selected_projects()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, ["project1", "project2"]);
+    }
+
     #[test]
     fn test_select_none() {
         let mut data = AppData::new();
@@ -349,6 +437,131 @@ mod tests {
         assert!(!data.is_selected(&data.projects[2])); // 无 target 的项目不会被选中
     }
 
+    #[test]
+    fn test_focus_project() {
+        let mut data = AppData::new();
+        data.set_projects(vec![
+            create_test_project("project1", 1000, true),
+            create_test_project("project2", 2000, true),
+        ]);
+        assert!(data.focused_project.is_none());
+
+        data.focused_project = Some(data.projects[1].path.clone());
+        assert_eq!(
+            data.focused_project.as_deref(),
+            Some(Path::new("/test/project2"))
+        );
+        // 单纯聚焦不会改变选中状态
+        assert_eq!(data.get_selected_count(), 0);
+
+        // 重新扫描后清除焦点
+        data.set_projects(vec![create_test_project("project3", 0, true)]);
+        assert!(data.focused_project.is_none());
+    }
+
+    #[test]
+    fn test_set_selected_by_path() {
+        let mut data = AppData::new();
+        data.set_projects(vec![
+            create_test_project("project1", 1000, true),
+            create_test_project("project2", 2000, true),
+        ]);
+
+        // 按路径匹配：内容不同的副本指向同一个项目
+        let mut stale = data.projects[0].clone();
+        stale.target_size = 42;
+        data.set_selected(&stale, true);
+        assert!(data.is_selected(&data.projects[0]));
+        assert!(!data.is_selected(&data.projects[1]));
+
+        data.set_selected(&stale, false);
+        assert!(!data.is_selected(&data.projects[0]));
+        assert_eq!(data.get_selected_count(), 0);
+
+        // 重复取消选中不出错
+        data.set_selected(&stale, false);
+        assert_eq!(data.get_selected_count(), 0);
+    }
+
+    #[test]
+    fn test_select_only() {
+        let mut data = AppData::new();
+        data.set_projects(vec![
+            create_test_project("project1", 1000, true),
+            create_test_project("project2", 2000, true),
+            create_test_project("project3", 0, false),
+        ]);
+        data.select_all();
+        assert_eq!(data.get_selected_count(), 2);
+
+        let p1 = data.projects[1].clone();
+        data.select_only(&p1);
+        assert_eq!(data.get_selected_count(), 1);
+        assert!(data.is_selected(&p1));
+        assert!(!data.is_selected(&data.projects[0]));
+        assert_eq!(data.focused_project.as_deref(), Some(p1.path.as_path()));
+
+        // 无 target 的项目只获得焦点，不会被选中
+        let p2 = data.projects[2].clone();
+        data.select_only(&p2);
+        assert_eq!(data.get_selected_count(), 0);
+        assert_eq!(data.focused_project.as_deref(), Some(p2.path.as_path()));
+    }
+
+    #[test]
+    fn test_update_project_size() {
+        let mut data = AppData::new();
+        data.set_projects(vec![
+            create_test_project("project1", 0, true),
+            create_test_project("project2", 0, true),
+        ]);
+        let p1 = data.projects[1].clone();
+        data.set_selected(&p1, true);
+
+        let largest = LargestFile {
+            path: PathBuf::from("debug/app"),
+            size: 1500,
+        };
+        assert!(data.update_project_size(&p1.path, 2000, Some(largest.clone())));
+
+        assert_eq!(data.projects[0].target_size, 0);
+        assert_eq!(data.projects[1].target_size, 2000);
+        assert_eq!(data.projects[1].largest_file, Some(largest));
+        assert_eq!(data.get_total_cleanable_size(), 2000);
+
+        // 已移除的项目忽略迟到的更新
+        assert!(!data.update_project_size(Path::new("/test/gone"), 10, None));
+    }
+
+    #[test]
+    fn test_set_size_progress() {
+        let mut data = AppData::new();
+        data.set_size_progress(1, 3);
+        assert_eq!(data.size_progress, Some((1, 3)));
+        data.set_size_progress(3, 3);
+        assert_eq!(data.size_progress, None);
+        data.set_size_progress(0, 0);
+        assert_eq!(data.size_progress, None);
+    }
+
+    #[test]
+    fn test_remove_project() {
+        let mut data = AppData::new();
+        data.set_projects(vec![
+            create_test_project("project1", 1000, true),
+            create_test_project("project2", 2000, true),
+        ]);
+
+        let p0 = data.projects[0].clone();
+        data.select_only(&p0);
+        data.remove_project(&p0.path);
+
+        assert_eq!(data.projects.len(), 1);
+        assert_eq!(data.projects[0].name, "project2");
+        assert_eq!(data.get_selected_count(), 0);
+        assert!(data.focused_project.is_none());
+    }
+
     #[test]
     fn test_clear_error() {
         let mut data = AppData::new();
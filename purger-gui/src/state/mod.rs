@@ -2,4 +2,4 @@ pub mod app_state;
 pub mod settings;
 
 pub use app_state::{AppData, AppMessage, AppState};
-pub use settings::AppSettings;
+pub use settings::{AppSettings, MIN_WINDOW_SIZE};
@@ -1,6 +1,11 @@
 use crate::simple_i18n::Language;
-use purger_core::{CleanStrategy, DirectDeleteBackend};
+use purger_core::{ChangedPolicy, CleanStrategy, DirectDeleteBackend, SizeUnits};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 窗口允许的最小尺寸
+pub const MIN_WINDOW_SIZE: [f32; 2] = [600.0, 400.0];
 
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +18,9 @@ pub struct AppSettings {
     pub auto_save_settings: bool,
     pub max_recent_paths: usize,
     pub language: Language,
+    /// 大小显示单位（与语言无关）
+    #[serde(default)]
+    pub size_units: SizeUnits,
 
     // 过滤选项
     pub keep_days: Option<u32>,
@@ -30,6 +38,33 @@ pub struct AppSettings {
     // Direct delete options
     #[serde(default)]
     pub direct_delete_backend: DirectDeleteBackend,
+
+    /// 清理前 target 大小相对扫描结果明显变化时的处理方式
+    #[serde(default)]
+    pub on_target_changed: ChangedPolicy,
+    #[serde(default = "default_changed_threshold_percent")]
+    pub changed_threshold_percent: f64,
+
+    /// 默认以预览模式运行清理（只统计不删除）
+    #[serde(default)]
+    pub dry_run_by_default: bool,
+
+    /// 开始清理前弹出确认对话框
+    #[serde(default = "default_confirm_before_clean")]
+    pub confirm_before_clean: bool,
+
+    /// 项目备注（以规范化后的项目路径为键，重新扫描后仍然有效）
+    #[serde(default)]
+    pub project_notes: HashMap<PathBuf, String>,
+
+    /// 上次关闭时的窗口大小
+    #[serde(default = "default_window_width")]
+    pub window_width: f32,
+    #[serde(default = "default_window_height")]
+    pub window_height: f32,
+    /// 上次关闭时的窗口位置（外框左上角）
+    #[serde(default)]
+    pub window_pos: Option<[f32; 2]>,
 }
 
 impl Default for AppSettings {
@@ -43,6 +78,7 @@ impl Default for AppSettings {
             auto_save_settings: true,
             max_recent_paths: 10,
             language: Language::default(),
+            size_units: SizeUnits::Binary,
 
             // 过滤选项默认值
             keep_days: None,
@@ -55,10 +91,34 @@ impl Default for AppSettings {
 
             clean_timeout_seconds: 0,
             direct_delete_backend: DirectDeleteBackend::Native,
+            on_target_changed: ChangedPolicy::Ignore,
+            changed_threshold_percent: default_changed_threshold_percent(),
+            dry_run_by_default: false,
+            confirm_before_clean: default_confirm_before_clean(),
+            project_notes: HashMap::new(),
+            window_width: default_window_width(),
+            window_height: default_window_height(),
+            window_pos: None,
         }
     }
 }
 
+fn default_changed_threshold_percent() -> f64 {
+    10.0
+}
+
+fn default_confirm_before_clean() -> bool {
+    true
+}
+
+fn default_window_width() -> f32 {
+    800.0
+}
+
+fn default_window_height() -> f32 {
+    600.0
+}
+
 impl AppSettings {
     /// Get config file path
     fn config_file_path() -> Option<std::path::PathBuf> {
@@ -67,15 +127,13 @@ impl AppSettings {
 
     /// Load settings from file
     pub fn load_from_file() -> Self {
-        if let Some(config_path) = Self::config_file_path() {
-            if config_path.exists() {
-                if let Ok(content) = std::fs::read_to_string(&config_path) {
-                    if let Ok(settings) = serde_json::from_str::<AppSettings>(&content) {
-                        tracing::info!("已加载配置文件: {:?}", config_path);
-                        return settings;
-                    }
-                }
-            }
+        if let Some(config_path) = Self::config_file_path()
+            && config_path.exists()
+            && let Ok(content) = std::fs::read_to_string(&config_path)
+            && let Ok(settings) = serde_json::from_str::<AppSettings>(&content)
+        {
+            tracing::info!("已加载配置文件: {:?}", config_path);
+            return settings;
         }
 
         tracing::info!("使用默认配置");
@@ -121,6 +179,54 @@ impl AppSettings {
     pub fn clear_recent_paths(&mut self) {
         self.recent_paths.clear();
     }
+
+    /// Initial window size, never smaller than [`MIN_WINDOW_SIZE`]
+    pub fn window_size(&self) -> [f32; 2] {
+        let clamp = |value: f32, min: f32| {
+            if value.is_finite() {
+                value.max(min)
+            } else {
+                min
+            }
+        };
+        [
+            clamp(self.window_width, MIN_WINDOW_SIZE[0]),
+            clamp(self.window_height, MIN_WINDOW_SIZE[1]),
+        ]
+    }
+
+    /// Remember the current window geometry (persisted with the other settings)
+    pub fn remember_window(&mut self, size: [f32; 2], pos: Option<[f32; 2]>) {
+        self.window_width = size[0];
+        self.window_height = size[1];
+        [self.window_width, self.window_height] = self.window_size();
+        if pos.is_some() {
+            self.window_pos = pos;
+        }
+    }
+
+    /// Get the note attached to a project
+    pub fn note_for(&self, project_path: &Path) -> Option<&str> {
+        self.project_notes
+            .get(&Self::note_key(project_path))
+            .map(String::as_str)
+    }
+
+    /// Set or clear (when blank) the note attached to a project
+    pub fn set_note(&mut self, project_path: &Path, note: &str) {
+        let key = Self::note_key(project_path);
+        if note.trim().is_empty() {
+            self.project_notes.remove(&key);
+        } else {
+            self.project_notes.insert(key, note.to_string());
+        }
+    }
+
+    fn note_key(project_path: &Path) -> PathBuf {
+        project_path
+            .canonicalize()
+            .unwrap_or_else(|_| project_path.to_path_buf())
+    }
 }
 
 #[cfg(test)]
@@ -139,6 +245,7 @@ mod tests {
         assert!(settings.auto_save_settings);
         assert_eq!(settings.max_recent_paths, 10);
         assert_eq!(settings.language, Language::default());
+        assert_eq!(settings.size_units, SizeUnits::Binary);
 
         assert!(settings.keep_days.is_none());
         assert!(settings.keep_size_mb.is_none());
@@ -236,6 +343,105 @@ mod tests {
         assert_eq!(deserialized.clean_strategy, settings.clean_strategy);
     }
 
+    #[test]
+    fn test_size_units_missing_in_old_settings() {
+        // 旧版本配置文件没有 size_units 字段，应回退到默认值
+        let mut value = serde_json::to_value(AppSettings::default()).unwrap();
+        value.as_object_mut().unwrap().remove("size_units");
+
+        let settings: AppSettings = serde_json::from_value(value).unwrap();
+        assert_eq!(settings.size_units, SizeUnits::Binary);
+    }
+
+    #[test]
+    fn test_dry_run_by_default_missing_in_old_settings() {
+        let mut value = serde_json::to_value(AppSettings::default()).unwrap();
+        value.as_object_mut().unwrap().remove("dry_run_by_default");
+
+        let settings: AppSettings = serde_json::from_value(value).unwrap();
+        assert!(!settings.dry_run_by_default);
+
+        let enabled = AppSettings {
+            dry_run_by_default: true,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&enabled).unwrap();
+        let restored: AppSettings = serde_json::from_str(&json).unwrap();
+        assert!(restored.dry_run_by_default);
+    }
+
+    #[test]
+    fn test_confirm_before_clean_missing_in_old_settings() {
+        let mut value = serde_json::to_value(AppSettings::default()).unwrap();
+        value
+            .as_object_mut()
+            .unwrap()
+            .remove("confirm_before_clean");
+
+        let settings: AppSettings = serde_json::from_value(value).unwrap();
+        assert!(settings.confirm_before_clean);
+    }
+
+    #[test]
+    fn test_changed_policy_missing_in_old_settings() {
+        let mut value = serde_json::to_value(AppSettings::default()).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("on_target_changed");
+        object.remove("changed_threshold_percent");
+
+        let settings: AppSettings = serde_json::from_value(value).unwrap();
+        assert_eq!(settings.on_target_changed, ChangedPolicy::Ignore);
+        assert_eq!(settings.changed_threshold_percent, 10.0);
+    }
+
+    #[test]
+    fn test_window_geometry_round_trip() {
+        let mut settings = AppSettings::default();
+        settings.remember_window([1280.0, 720.0], Some([40.0, 60.0]));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let restored: AppSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.window_size(), [1280.0, 720.0]);
+        assert_eq!(restored.window_pos, Some([40.0, 60.0]));
+
+        // 过小的尺寸按最小值处理
+        settings.remember_window([100.0, 50.0], None);
+        assert_eq!(settings.window_size(), MIN_WINDOW_SIZE);
+        assert_eq!(settings.window_pos, Some([40.0, 60.0]));
+
+        // 旧版本配置文件没有窗口字段
+        let mut value = serde_json::to_value(AppSettings::default()).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("window_width");
+        object.remove("window_height");
+        object.remove("window_pos");
+        let old: AppSettings = serde_json::from_value(value).unwrap();
+        assert_eq!(old.window_size(), [800.0, 600.0]);
+        assert_eq!(old.window_pos, None);
+    }
+
+    #[test]
+    fn test_project_notes() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let project = temp_dir.path().join("demo");
+        std::fs::create_dir_all(&project).unwrap();
+
+        let mut settings = AppSettings::default();
+        settings.set_note(&project, "keep - client demo");
+
+        // 不同写法的同一路径命中同一条备注
+        let alias = temp_dir.path().join(".").join("demo");
+        assert_eq!(settings.note_for(&alias), Some("keep - client demo"));
+
+        let json = serde_json::to_string(&settings).unwrap();
+        let restored: AppSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.note_for(&project), Some("keep - client demo"));
+
+        settings.set_note(&project, "   ");
+        assert_eq!(settings.note_for(&project), None);
+        assert!(settings.project_notes.is_empty());
+    }
+
     #[test]
     fn test_config_file_path() {
         let path = AppSettings::config_file_path();
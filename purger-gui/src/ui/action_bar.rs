@@ -10,7 +10,9 @@ impl ActionBar {
         ui: &mut egui::Ui,
         data: &mut AppData,
         state: &AppState,
+        dry_run: &mut bool,
         on_request_clean: &mut bool,
+        on_toggle_pause: &mut bool,
     ) {
         let selected_count = data.get_selected_count();
         let total_selected_size = data.get_total_cleanable_size();
@@ -28,7 +30,7 @@ impl ActionBar {
                     ui.separator();
                     ui.label(tr!(
                         "projects.cleanable_size",
-                        size = purger_core::format_bytes(total_selected_size)
+                        size = crate::size_format::format_size(total_selected_size)
                     ));
                 } else if selected_count > 0 && data.size_progress.is_some() {
                     ui.separator();
@@ -37,12 +39,32 @@ impl ActionBar {
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     let can_clean = *state == AppState::Idle && selected_count > 0;
+                    let clean_label = if *dry_run {
+                        tr!("projects.preview_button")
+                    } else {
+                        tr!("projects.clean_button")
+                    };
                     if ui
-                        .add_enabled(can_clean, egui::Button::new(tr!("projects.clean_button")))
+                        .add_enabled(can_clean, egui::Button::new(clean_label))
                         .clicked()
                     {
                         *on_request_clean = true;
                     }
+                    ui.add_enabled(
+                        *state == AppState::Idle,
+                        egui::Checkbox::new(dry_run, tr!("actions.dry_run")),
+                    )
+                    .on_hover_text(tr!("actions.dry_run_hint"));
+                    if *state == AppState::Cleaning {
+                        let pause_label = if data.clean_paused {
+                            tr!("actions.resume")
+                        } else {
+                            tr!("actions.pause")
+                        };
+                        if ui.button(pause_label).clicked() {
+                            *on_toggle_pause = true;
+                        }
+                    }
                 });
             });
 
@@ -83,6 +105,25 @@ impl ActionBar {
                 {
                     data.invert_selection();
                 }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .add_enabled(
+                            !data.projects.is_empty() || data.last_clean_result.is_some(),
+                            egui::Button::new(tr!("actions.copy_summary_json")),
+                        )
+                        .clicked()
+                    {
+                        let report = purger_core::report::Report::new(
+                            &data.projects,
+                            data.last_clean_result.as_ref(),
+                        );
+                        match serde_json::to_string_pretty(&report) {
+                            Ok(json) => ui.ctx().copy_text(json),
+                            Err(e) => data.error_message = Some(e.to_string()),
+                        }
+                    }
+                });
             });
         });
     }
@@ -1,7 +1,9 @@
 use crate::simple_i18n::{Language, set_language};
+use crate::size_format::set_size_units;
 use crate::state::AppSettings;
 use crate::tr;
 use eframe::egui;
+use purger_core::{ChangedPolicy, CleanStrategy, SizeUnits};
 
 /// 对话框组件
 pub struct Dialogs;
@@ -50,6 +52,22 @@ impl Dialogs {
                         });
                 });
 
+                // 大小单位设置
+                ui.horizontal(|ui| {
+                    ui.label(tr!("dialog.size_units"));
+                    egui::ComboBox::from_id_salt("size_units_selector")
+                        .selected_text(size_units_label(draft_settings.size_units))
+                        .show_ui(ui, |ui| {
+                            for units in [SizeUnits::Binary, SizeUnits::Decimal] {
+                                ui.selectable_value(
+                                    &mut draft_settings.size_units,
+                                    units,
+                                    size_units_label(units),
+                                );
+                            }
+                        });
+                });
+
                 ui.separator();
 
                 ui.horizontal(|ui| {
@@ -63,6 +81,10 @@ impl Dialogs {
                     &mut draft_settings.auto_save_settings,
                     tr!("dialog.auto_save_settings"),
                 );
+                ui.checkbox(
+                    &mut draft_settings.confirm_before_clean,
+                    tr!("dialog.confirm_before_clean"),
+                );
 
                 ui.horizontal(|ui| {
                     ui.label(tr!("scan.strategy_label"));
@@ -72,6 +94,7 @@ impl Dialogs {
                             purger_core::CleanStrategy::DirectDelete => {
                                 tr!("strategy.direct_delete")
                             }
+                            purger_core::CleanStrategy::Trash => tr!("strategy.trash"),
                         })
                         .show_ui(ui, |ui| {
                             ui.selectable_value(
@@ -84,6 +107,11 @@ impl Dialogs {
                                 purger_core::CleanStrategy::DirectDelete,
                                 tr!("strategy.direct_delete"),
                             );
+                            ui.selectable_value(
+                                &mut draft_settings.clean_strategy,
+                                purger_core::CleanStrategy::Trash,
+                                tr!("strategy.trash"),
+                            );
                         });
                 });
 
@@ -132,6 +160,35 @@ impl Dialogs {
                     );
                 });
 
+                ui.horizontal(|ui| {
+                    ui.label(tr!("dialog.on_target_changed"));
+                    egui::ComboBox::from_id_salt("on_target_changed")
+                        .selected_text(changed_policy_label(draft_settings.on_target_changed))
+                        .show_ui(ui, |ui| {
+                            for policy in [
+                                ChangedPolicy::Ignore,
+                                ChangedPolicy::Warn,
+                                ChangedPolicy::Skip,
+                                ChangedPolicy::Confirm,
+                            ] {
+                                ui.selectable_value(
+                                    &mut draft_settings.on_target_changed,
+                                    policy,
+                                    changed_policy_label(policy),
+                                );
+                            }
+                        });
+                });
+                ui.horizontal(|ui| {
+                    ui.label(tr!("dialog.changed_threshold"));
+                    ui.add_enabled(
+                        draft_settings.on_target_changed != ChangedPolicy::Ignore,
+                        egui::DragValue::new(&mut draft_settings.changed_threshold_percent)
+                            .range(0.0..=1000.0)
+                            .speed(1.0),
+                    );
+                });
+
                 ui.horizontal(|ui| {
                     if ui.button(tr!("dialog.clear_recent_paths")).clicked() {
                         draft_settings.clear_recent_paths();
@@ -158,6 +215,7 @@ impl Dialogs {
                 *settings = draft_settings;
             }
             set_language(settings.language);
+            set_size_units(settings.size_units);
             *show_settings = false;
         }
 
@@ -167,6 +225,71 @@ impl Dialogs {
         }
     }
 
+    /// 显示清理确认对话框，返回用户是否点击了确认
+    pub fn show_clean_confirm(
+        ctx: &egui::Context,
+        show_confirm: &mut bool,
+        selected_count: usize,
+        total_size: u64,
+        strategy: CleanStrategy,
+        dry_run: bool,
+        can_confirm: bool,
+    ) -> bool {
+        if !*show_confirm {
+            return false;
+        }
+
+        let strategy_text = match strategy {
+            CleanStrategy::CargoClean => tr!("strategy.cargo_clean"),
+            CleanStrategy::DirectDelete => tr!("strategy.direct_delete"),
+            CleanStrategy::Trash => tr!("strategy.trash"),
+        };
+
+        let mut confirmed = false;
+        egui::Window::new(tr!("clean.confirm_title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(tr!(
+                    "clean.confirm_message",
+                    count = selected_count,
+                    size = crate::size_format::format_size(total_size)
+                ));
+                ui.label(tr!("clean.confirm_strategy", strategy = strategy_text));
+                if dry_run {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(80, 160, 220),
+                        tr!("clean.dry_run_notice"),
+                    );
+                } else if strategy == CleanStrategy::DirectDelete {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(220, 120, 60),
+                        tr!("clean.direct_delete_warning"),
+                    );
+                }
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button(tr!("dialog.cancel")).clicked() {
+                        *show_confirm = false;
+                    }
+                    let confirm_label = if dry_run {
+                        tr!("clean.preview_confirm_button")
+                    } else {
+                        tr!("clean.confirm_button")
+                    };
+                    if ui
+                        .add_enabled(can_confirm, egui::Button::new(confirm_label))
+                        .clicked()
+                    {
+                        confirmed = true;
+                        *show_confirm = false;
+                    }
+                });
+            });
+        confirmed
+    }
+
     /// 显示关于对话框
     pub fn show_about(ctx: &egui::Context, show_about: &mut bool) {
         if !*show_about {
@@ -193,3 +316,19 @@ impl Dialogs {
             });
     }
 }
+
+fn changed_policy_label(policy: ChangedPolicy) -> String {
+    match policy {
+        ChangedPolicy::Ignore => tr!("dialog.on_target_changed.ignore"),
+        ChangedPolicy::Warn => tr!("dialog.on_target_changed.warn"),
+        ChangedPolicy::Skip => tr!("dialog.on_target_changed.skip"),
+        ChangedPolicy::Confirm => tr!("dialog.on_target_changed.confirm"),
+    }
+}
+
+fn size_units_label(units: SizeUnits) -> String {
+    match units {
+        SizeUnits::Binary => tr!("dialog.size_units.binary"),
+        SizeUnits::Decimal => tr!("dialog.size_units.decimal"),
+    }
+}
@@ -1,6 +1,10 @@
 use crate::state::AppSettings;
 use crate::tr;
 use eframe::egui;
+use purger_core::{RustProject, SortKey, compare_projects};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProjectSort {
@@ -27,6 +31,84 @@ impl ProjectSort {
             ProjectSort::PathDesc => "filters.sort.path_desc",
         }
     }
+
+    /// Shared sort key and direction behind this option
+    pub fn key(&self) -> (SortKey, bool) {
+        match self {
+            ProjectSort::SizeDesc => (SortKey::Size, true),
+            ProjectSort::SizeAsc => (SortKey::Size, false),
+            ProjectSort::ModifiedDesc => (SortKey::Modified, true),
+            ProjectSort::ModifiedAsc => (SortKey::Modified, false),
+            ProjectSort::NameAsc => (SortKey::Name, false),
+            ProjectSort::NameDesc => (SortKey::Name, true),
+            ProjectSort::PathAsc => (SortKey::Path, false),
+            ProjectSort::PathDesc => (SortKey::Path, true),
+        }
+    }
+
+    /// Ordering of two projects under this sort; used with a stable sort so ties keep scan order
+    pub fn compare(&self, a: &RustProject, b: &RustProject) -> Ordering {
+        let (key, reverse) = self.key();
+        compare_projects(a, b, key, reverse)
+    }
+}
+
+/// Filters panel flags that narrow the displayed project list
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DisplayFilters<'a> {
+    /// When set, show only these selected projects and ignore the other flags
+    pub selected: Option<&'a HashSet<PathBuf>>,
+    pub workspace_only: bool,
+    pub target_only: bool,
+    pub keep_size_mb: Option<f64>,
+}
+
+/// Indices into `projects` that match the search query (case-insensitive substring of
+/// name or path) and the panel flags, in their original order
+pub fn filter_projects_for_display(
+    projects: &[RustProject],
+    query: &str,
+    filters: DisplayFilters,
+) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    let matches_flags = |project: &RustProject| {
+        if let Some(selected) = filters.selected {
+            return project.has_target && selected.contains(&project.path);
+        }
+        if filters.target_only && !project.has_target {
+            return false;
+        }
+        if filters.workspace_only && !project.is_workspace_root {
+            return false;
+        }
+        if let Some(size_mb) = filters.keep_size_mb
+            && project.has_target
+            && project.target_size != 0
+        {
+            let keep_bytes = (size_mb * 1_000_000.0) as u64;
+            if project.target_size > keep_bytes {
+                return false;
+            }
+        }
+        true
+    };
+    let matches_query = |project: &RustProject| {
+        query.is_empty()
+            || project.name.to_lowercase().contains(&query)
+            || project
+                .path
+                .display()
+                .to_string()
+                .to_lowercase()
+                .contains(&query)
+    };
+
+    projects
+        .iter()
+        .enumerate()
+        .filter(|(_, project)| matches_flags(project) && matches_query(project))
+        .map(|(i, _)| i)
+        .collect()
 }
 
 /// Left filters panel
@@ -192,3 +274,121 @@ impl FiltersPanel {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    fn project(name: &str, dir: &str, target_size: u64, modified_secs: u64) -> RustProject {
+        RustProject {
+            path: PathBuf::from(format!("/test/{dir}")),
+            name: name.to_string(),
+            target_size,
+            last_modified: SystemTime::UNIX_EPOCH + Duration::from_secs(modified_secs),
+            is_workspace_root: false,
+            is_package: true,
+            is_workspace_member: false,
+            has_target: target_size > 0,
+            largest_file: None,
+            target_dir: None,
+            members: Vec::new(),
+            extra_targets: Vec::new(),
+            pinned: false,
+        }
+    }
+
+    fn sorted_names(sort: ProjectSort) -> Vec<String> {
+        let mut projects = vec![
+            project("beta", "c", 300, 20),
+            project("alpha", "b", 100, 30),
+            project("gamma", "a", 0, 10),
+            project("delta", "d", 200, 40),
+        ];
+        projects.sort_by(|a, b| sort.compare(a, b));
+        projects.into_iter().map(|p| p.name).collect()
+    }
+
+    #[test]
+    fn test_project_sort_orders() {
+        let cases = [
+            (ProjectSort::SizeDesc, ["beta", "delta", "alpha", "gamma"]),
+            (ProjectSort::SizeAsc, ["alpha", "delta", "beta", "gamma"]),
+            (
+                ProjectSort::ModifiedDesc,
+                ["delta", "alpha", "beta", "gamma"],
+            ),
+            (
+                ProjectSort::ModifiedAsc,
+                ["gamma", "beta", "alpha", "delta"],
+            ),
+            (ProjectSort::NameAsc, ["alpha", "beta", "delta", "gamma"]),
+            (ProjectSort::NameDesc, ["gamma", "delta", "beta", "alpha"]),
+            (ProjectSort::PathAsc, ["gamma", "alpha", "beta", "delta"]),
+            (ProjectSort::PathDesc, ["delta", "beta", "alpha", "gamma"]),
+        ];
+        for (sort, expected) in cases {
+            assert_eq!(sorted_names(sort), expected, "{sort:?}");
+        }
+    }
+
+    #[test]
+    fn test_project_sort_is_stable_for_ties() {
+        let mut projects = [
+            project("same", "first", 100, 10),
+            project("same", "second", 100, 10),
+        ];
+        projects.sort_by(|a, b| ProjectSort::ModifiedDesc.compare(a, b));
+        assert_eq!(projects[0].path, PathBuf::from("/test/first"));
+        assert_eq!(projects[1].path, PathBuf::from("/test/second"));
+    }
+
+    #[test]
+    fn test_filter_projects_for_display() {
+        let mut projects = vec![
+            project("Server", "apps/server", 300, 20),
+            project("client", "apps/web-client", 100, 30),
+            project("tools", "misc/tools", 0, 10),
+        ];
+        projects[1].is_workspace_root = true;
+        let all = DisplayFilters::default();
+
+        // 名称匹配不区分大小写
+        assert_eq!(filter_projects_for_display(&projects, "SERV", all), [0]);
+        // 路径匹配
+        assert_eq!(filter_projects_for_display(&projects, " web-", all), [1]);
+        assert_eq!(filter_projects_for_display(&projects, "apps", all), [0, 1]);
+        assert_eq!(filter_projects_for_display(&projects, "", all), [0, 1, 2]);
+
+        let workspace_only = DisplayFilters {
+            workspace_only: true,
+            ..all
+        };
+        assert_eq!(
+            filter_projects_for_display(&projects, "", workspace_only),
+            [1]
+        );
+        assert!(filter_projects_for_display(&projects, "server", workspace_only).is_empty());
+
+        let target_only = DisplayFilters {
+            target_only: true,
+            ..all
+        };
+        assert_eq!(
+            filter_projects_for_display(&projects, "", target_only),
+            [0, 1]
+        );
+
+        let selected = HashSet::from([PathBuf::from("/test/apps/server")]);
+        let selected_only = DisplayFilters {
+            selected: Some(&selected),
+            workspace_only: true,
+            ..all
+        };
+        assert_eq!(
+            filter_projects_for_display(&projects, "", selected_only),
+            [0]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::shortcuts::ShortcutAction;
 use crate::tr;
 use eframe::egui;
 
@@ -10,15 +11,29 @@ impl MenuBar {
         ctx: &egui::Context,
         show_settings: &mut bool,
         show_about: &mut bool,
-        on_select_folder: &mut bool,
+        enabled_actions: &[ShortcutAction],
+        on_action: &mut Option<ShortcutAction>,
     ) {
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
                 ui.menu_button(tr!("menu.file"), |ui| {
-                    if ui.button(tr!("menu.select_folder")).clicked() {
-                        *on_select_folder = true;
-                        ui.close();
-                    }
+                    let mut item = |ui: &mut egui::Ui, label: String, action: ShortcutAction| {
+                        let button = egui::Button::new(label)
+                            .shortcut_text(ui.ctx().format_shortcut(&action.shortcut()));
+                        if ui
+                            .add_enabled(enabled_actions.contains(&action), button)
+                            .clicked()
+                        {
+                            *on_action = Some(action);
+                            ui.close();
+                        }
+                    };
+                    item(ui, tr!("menu.select_folder"), ShortcutAction::SelectFolder);
+                    item(ui, tr!("scan.start_button"), ShortcutAction::Scan);
+                    item(ui, tr!("scan.stop_button"), ShortcutAction::Stop);
+                    ui.separator();
+                    item(ui, tr!("projects.select_all"), ShortcutAction::SelectAll);
+                    item(ui, tr!("projects.clean_button"), ShortcutAction::Clean);
                     ui.separator();
                     if ui.button(tr!("menu.exit")).clicked() {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
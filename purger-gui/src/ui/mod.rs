@@ -9,9 +9,9 @@ pub mod scan_panel;
 
 pub use action_bar::ActionBar;
 pub use dialogs::Dialogs;
-pub use filters_panel::{FiltersPanel, ProjectSort};
+pub use filters_panel::{DisplayFilters, FiltersPanel, ProjectSort, filter_projects_for_display};
 pub use menu_bar::MenuBar;
 pub use progress_bar::ProgressBar;
 pub use project_details::ProjectDetails;
-pub use project_list::ProjectList;
+pub use project_list::{ProjectList, ProjectRowAction};
 pub use scan_panel::ScanPanel;
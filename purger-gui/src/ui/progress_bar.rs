@@ -1,6 +1,7 @@
 use crate::state::{AppData, AppState};
 use crate::tr;
 use eframe::egui;
+use std::time::Duration;
 
 /// Progress display
 pub struct ProgressBar;
@@ -11,8 +12,7 @@ impl ProgressBar {
         if let Some((current, total)) = data.scan_progress {
             ui.horizontal(|ui| {
                 ui.label(tr!("progress.scan_label"));
-                if total > 0 {
-                    let progress = current as f32 / total as f32;
+                if let Some(progress) = progress_fraction(current, total) {
                     ui.add(egui::ProgressBar::new(progress).text(format!("{current}/{total}")));
                 } else {
                     ui.spinner();
@@ -22,12 +22,12 @@ impl ProgressBar {
         }
     }
 
+    /// Show background size-calculation progress (shown while idle, after a scan)
     pub fn show_size_progress(ui: &mut egui::Ui, data: &AppData) {
         if let Some((current, total)) = data.size_progress {
             ui.horizontal(|ui| {
                 ui.label(tr!("progress.size_label"));
-                if total > 0 {
-                    let progress = current as f32 / total as f32;
+                if let Some(progress) = progress_fraction(current, total) {
                     ui.add(egui::ProgressBar::new(progress).text(format!("{current}/{total}")));
                 } else {
                     ui.spinner();
@@ -42,12 +42,23 @@ impl ProgressBar {
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
                     ui.label(tr!("progress.clean_label"));
-                    let progress = if total > 0 {
-                        current as f32 / total as f32
-                    } else {
-                        0.0
-                    };
+                    let progress = progress_fraction(current, total).unwrap_or(0.0);
                     ui.add(egui::ProgressBar::new(progress).text(format!("{current}/{total}")));
+                    if data.clean_paused {
+                        ui.colored_label(
+                            egui::Color32::from_rgb(220, 160, 40),
+                            tr!("progress.paused"),
+                        );
+                    } else if let Some(eta) = data.clean_started.and_then(|started| {
+                        purger_core::estimate_remaining(
+                            current as u64,
+                            total as u64,
+                            started.elapsed(),
+                        )
+                    }) && eta > Duration::ZERO
+                    {
+                        ui.label(tr!("progress.eta", time = format_eta(eta)));
+                    }
                 });
 
                 if let Some(ref project_name) = data.current_cleaning_project {
@@ -60,7 +71,7 @@ impl ProgressBar {
                 if size_freed > 0 {
                     ui.horizontal(|ui| {
                         ui.label(tr!("progress.freed_size"));
-                        ui.label(purger_core::format_bytes(size_freed));
+                        ui.label(crate::size_format::format_size(size_freed));
                     });
                 }
 
@@ -89,13 +100,31 @@ impl ProgressBar {
                 if let Some(ref result) = data.last_clean_result {
                     ui.group(|ui| {
                         ui.vertical(|ui| {
-                            ui.label(tr!("progress.last_result"));
+                            if data.last_clean_dry_run {
+                                ui.label(tr!("progress.last_preview"));
+                            } else {
+                                ui.label(tr!("progress.last_result"));
+                            }
                             ui.horizontal(|ui| {
                                 ui.label(tr!(
                                     "progress.cleaned_projects",
                                     count = result.cleaned_projects
                                 ));
-                                ui.label(tr!("progress.freed_space", size = result.format_size()));
+                                if data.last_clean_dry_run {
+                                    ui.label(tr!(
+                                        "progress.would_free",
+                                        size = crate::size_format::format_size(
+                                            result.total_size_freed
+                                        )
+                                    ));
+                                } else {
+                                    ui.label(tr!(
+                                        "progress.freed_space",
+                                        size = crate::size_format::format_size(
+                                            result.total_size_freed
+                                        )
+                                    ));
+                                }
                                 ui.label(tr!("progress.duration", ms = result.duration_ms));
                             });
 
@@ -151,3 +180,47 @@ impl ProgressBar {
         }
     }
 }
+
+/// 进度比例（0.0..=1.0），总数未知（为 0）时返回 None
+fn progress_fraction(current: usize, total: usize) -> Option<f32> {
+    if total == 0 {
+        return None;
+    }
+    Some(current.min(total) as f32 / total as f32)
+}
+
+/// 剩余时间的粗略显示：`45s`、`3m`、`1h 5m`
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    if secs < 60 {
+        format!("{}s", secs.max(1))
+    } else if secs < 3600 {
+        format!("{}m", secs.div_ceil(60))
+    } else {
+        format!("{}h {}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_fraction() {
+        assert_eq!(progress_fraction(0, 0), None);
+        assert_eq!(progress_fraction(5, 0), None);
+        assert_eq!(progress_fraction(0, 4), Some(0.0));
+        assert_eq!(progress_fraction(1, 4), Some(0.25));
+        assert_eq!(progress_fraction(4, 4), Some(1.0));
+        // 计数超过总数时不溢出进度条
+        assert_eq!(progress_fraction(6, 4), Some(1.0));
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(Duration::from_millis(300)), "1s");
+        assert_eq!(format_eta(Duration::from_secs(45)), "45s");
+        assert_eq!(format_eta(Duration::from_secs(150)), "3m");
+        assert_eq!(format_eta(Duration::from_secs(3900)), "1h 5m");
+    }
+}
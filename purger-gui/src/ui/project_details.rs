@@ -1,7 +1,8 @@
 use crate::state::AppData;
 use crate::tr;
 use eframe::egui;
-use std::path::Path;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::{Duration, SystemTime};
 
@@ -9,7 +10,11 @@ use std::time::{Duration, SystemTime};
 pub struct ProjectDetails;
 
 impl ProjectDetails {
-    pub fn show(ui: &mut egui::Ui, data: &mut AppData) {
+    pub fn show(
+        ui: &mut egui::Ui,
+        data: &mut AppData,
+        note_changed: &mut Option<(PathBuf, String)>,
+    ) {
         ui.strong(tr!("details.title"));
         ui.separator();
 
@@ -24,8 +29,15 @@ impl ProjectDetails {
         };
 
         ui.label(&project.name);
-        if project.is_workspace {
+        if project.is_workspace_root {
             ui.colored_label(egui::Color32::BLUE, tr!("projects.tag_workspace"));
+        } else if project.is_workspace_member {
+            ui.colored_label(egui::Color32::GRAY, tr!("projects.tag_member"));
+        }
+        if !project.members.is_empty() {
+            ui.label(tr!("details.members_label"))
+                .on_hover_text(project.members.join("\n"));
+            ui.label(project.members.join(", "));
         }
 
         ui.add_space(8.0);
@@ -48,10 +60,10 @@ impl ProjectDetails {
                 ui.ctx().copy_text(project.path.display().to_string());
             }
 
-            if ui.button(tr!("details.open_project")).clicked() {
-                if let Err(e) = open_in_file_manager(&project.path) {
-                    data.error_message = Some(format!("{}: {e}", tr!("details.open_failed")));
-                }
+            if ui.button(tr!("details.open_project")).clicked()
+                && let Err(e) = open_in_file_manager(&project.path)
+            {
+                data.error_message = Some(format!("{}: {e}", tr!("details.open_failed")));
             }
 
             if project.has_target && ui.button(tr!("details.open_target")).clicked() {
@@ -69,12 +81,22 @@ impl ProjectDetails {
                 if project.target_size == 0 {
                     ui.colored_label(egui::Color32::GRAY, "…");
                 } else {
-                    ui.monospace(purger_core::format_bytes(project.target_size));
+                    ui.monospace(crate::size_format::format_size(project.target_size));
                 }
             } else {
                 ui.colored_label(egui::Color32::GRAY, "-");
             }
         });
+        if let Some(largest) = &project.largest_file {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(tr!("details.largest_file_label"));
+                ui.monospace(format!(
+                    "{} ({})",
+                    largest.path.display(),
+                    crate::size_format::format_size(largest.size)
+                ));
+            });
+        }
         ui.horizontal(|ui| {
             ui.label(tr!("details.modified_label"));
             if project.has_target {
@@ -84,6 +106,20 @@ impl ProjectDetails {
             }
         });
 
+        ui.add_space(8.0);
+        ui.label(tr!("details.note_label"));
+        let note = data.notes.entry(project.path.clone()).or_default();
+        let note_resp = ui.add(
+            egui::TextEdit::multiline(note)
+                .desired_rows(2)
+                .desired_width(f32::INFINITY)
+                .hint_text(tr!("details.note_hint")),
+        );
+        // 失去焦点时再提交，避免每次按键都写配置文件
+        if note_resp.lost_focus() {
+            *note_changed = Some((project.path.clone(), note.clone()));
+        }
+
         ui.add_space(8.0);
         if project.has_target {
             let mut selected = data.is_selected(&project);
@@ -101,35 +137,88 @@ impl ProjectDetails {
     }
 }
 
-fn open_in_file_manager(path: &Path) -> std::io::Result<()> {
-    #[cfg(target_os = "windows")]
+pub(crate) fn open_in_file_manager(path: &Path) -> std::io::Result<()> {
+    let Some(manager) = FileManager::current() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "unsupported platform",
+        ));
+    };
+
+    // 相对路径可能以 `-` 开头而被当作选项，统一转为绝对路径
+    let path = std::path::absolute(path)?;
+    let mut command = Command::new(manager.program());
+
+    // explorer 自己解析命令行，不认 Rust 对含空格参数的整体引号，需要原样传入 `/select,"..."`
+    #[cfg(windows)]
     {
-        Command::new("explorer").arg(path).spawn()?;
-        return Ok(());
+        use std::os::windows::process::CommandExt;
+        for arg in manager.args(&path) {
+            command.raw_arg(arg);
+        }
     }
+    #[cfg(not(windows))]
+    command.args(manager.args(&path));
 
-    #[cfg(target_os = "macos")]
-    {
-        Command::new("open").arg(path).spawn()?;
-        return Ok(());
+    command.spawn()?;
+    Ok(())
+}
+
+/// Platform file manager used to reveal a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileManager {
+    Explorer,
+    Finder,
+    XdgOpen,
+}
+
+impl FileManager {
+    fn current() -> Option<Self> {
+        if cfg!(target_os = "windows") {
+            Some(Self::Explorer)
+        } else if cfg!(target_os = "macos") {
+            Some(Self::Finder)
+        } else if cfg!(unix) {
+            Some(Self::XdgOpen)
+        } else {
+            None
+        }
     }
 
-    #[cfg(all(unix, not(target_os = "macos")))]
-    {
-        Command::new("xdg-open").arg(path).spawn()?;
-        return Ok(());
+    fn program(self) -> &'static str {
+        match self {
+            Self::Explorer => "explorer",
+            Self::Finder => "open",
+            Self::XdgOpen => "xdg-open",
+        }
     }
 
-    #[cfg(not(any(target_os = "windows", target_os = "macos", unix)))]
-    {
-        let _ = path;
-        return Err(std::io::Error::new(
-            std::io::ErrorKind::Unsupported,
-            "unsupported platform",
-        ));
+    /// Arguments for revealing an absolute `path` (passed as a single `OsStr`, never through a shell)
+    fn args(self, path: &Path) -> Vec<OsString> {
+        match self {
+            Self::Explorer => {
+                let mut arg = OsString::from("/select,\"");
+                arg.push(explorer_path(path));
+                arg.push("\"");
+                vec![arg]
+            }
+            Self::Finder | Self::XdgOpen => vec![path.as_os_str().to_os_string()],
+        }
     }
 }
 
+/// explorer 不认 `\\?\` 前缀和 `/` 分隔符
+fn explorer_path(path: &Path) -> OsString {
+    let Some(path) = path.to_str() else {
+        return path.as_os_str().to_os_string();
+    };
+    let path = match path.strip_prefix(r"\\?\UNC\") {
+        Some(share) => format!(r"\\{share}"),
+        None => path.strip_prefix(r"\\?\").unwrap_or(path).to_string(),
+    };
+    path.replace('/', "\\").into()
+}
+
 fn format_relative_time(time: SystemTime) -> String {
     let Ok(elapsed) = SystemTime::now().duration_since(time) else {
         return tr!("details.time_unknown");
@@ -147,3 +236,31 @@ fn format_relative_time(time: SystemTime) -> String {
 
     tr!("details.time_days", n = elapsed.as_secs() / (24 * 60 * 60))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_manager_args() {
+        let windows = Path::new(r"\\?\C:\Users\李 雷\my project");
+        assert_eq!(
+            FileManager::Explorer.args(windows),
+            [OsString::from(r#"/select,"C:\Users\李 雷\my project""#)]
+        );
+        assert_eq!(
+            FileManager::Explorer.args(Path::new("C:/code/a b")),
+            [OsString::from(r#"/select,"C:\code\a b""#)]
+        );
+        assert_eq!(
+            FileManager::Explorer.args(Path::new(r"\\?\UNC\server\share\proj")),
+            [OsString::from(r#"/select,"\\server\share\proj""#)]
+        );
+
+        // open/xdg-open 直接收到完整路径，空格和非 ASCII 字符不做任何转义
+        let unix = Path::new("/home/user/my projects/データ");
+        for manager in [FileManager::Finder, FileManager::XdgOpen] {
+            assert_eq!(manager.args(unix), [OsString::from(unix)]);
+        }
+    }
+}
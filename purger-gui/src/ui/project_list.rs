@@ -1,14 +1,26 @@
 use super::ProjectSort;
+use super::project_details::open_in_file_manager;
 use crate::state::{AppData, AppState};
 use crate::tr;
 use eframe::egui;
 use egui_extras::{Column, TableBuilder};
+use std::path::PathBuf;
 use std::time::{Duration, SystemTime};
 
 /// Project list (table view)
 pub struct ProjectList;
 
+/// Row actions that need to be handled by the app (from the context menu)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectRowAction {
+    /// Clean only this project (goes through the confirmation dialog)
+    Clean(PathBuf),
+    /// Add this project to the ignore list
+    Ignore(PathBuf),
+}
+
 impl ProjectList {
+    #[allow(clippy::too_many_arguments)]
     pub fn show(
         ui: &mut egui::Ui,
         data: &mut AppData,
@@ -17,6 +29,7 @@ impl ProjectList {
         sort: &mut ProjectSort,
         sort_changed: &mut bool,
         keep_size_filter_enabled: bool,
+        row_action: &mut Option<ProjectRowAction>,
     ) {
         if data.projects.is_empty() {
             ui.label(tr!("projects.empty_message"));
@@ -117,7 +130,7 @@ impl ProjectList {
                     let index = visible[row_index];
                     let project = &data.projects[index];
 
-                    let cleanable = project.has_target;
+                    let cleanable = project.has_target && !project.pinned;
                     let selectable =
                         cleanable && (!keep_size_filter_enabled || project.target_size != 0);
 
@@ -156,7 +169,7 @@ impl ProjectList {
                             if project.target_size == 0 {
                                 ui.colored_label(egui::Color32::GRAY, "…");
                             } else {
-                                ui.monospace(purger_core::format_bytes(project.target_size));
+                                ui.monospace(crate::size_format::format_size(project.target_size));
                             }
                         } else {
                             ui.colored_label(egui::Color32::GRAY, "-");
@@ -185,17 +198,64 @@ impl ProjectList {
 
                     row.col(|ui| {
                         ui.horizontal(|ui| {
-                            if project.is_workspace {
+                            if project.is_workspace_root {
                                 ui.colored_label(
                                     egui::Color32::BLUE,
                                     tr!("projects.tag_workspace"),
                                 );
+                            } else if project.is_workspace_member {
+                                ui.colored_label(egui::Color32::GRAY, tr!("projects.tag_member"));
+                            }
+                            if project.pinned {
+                                ui.colored_label(egui::Color32::GRAY, tr!("projects.tag_pinned"));
                             }
-                            if !cleanable {
+                            if !project.has_target {
                                 ui.colored_label(egui::Color32::GRAY, tr!("projects.no_target"));
                             }
+                            if let Some(note) = data
+                                .notes
+                                .get(&project.path)
+                                .filter(|note| !note.trim().is_empty())
+                            {
+                                ui.label("📝").on_hover_text(note);
+                            }
                         });
                     });
+
+                    row.response().context_menu(|ui| {
+                        if ui
+                            .add_enabled(
+                                selection_enabled && cleanable,
+                                egui::Button::new(tr!("projects.menu_clean")),
+                            )
+                            .clicked()
+                        {
+                            *row_action = Some(ProjectRowAction::Clean(project.path.clone()));
+                            ui.close();
+                        }
+                        if ui.button(tr!("projects.menu_open_folder")).clicked() {
+                            if let Err(e) = open_in_file_manager(&project.path) {
+                                data.error_message =
+                                    Some(format!("{}: {e}", tr!("details.open_failed")));
+                            }
+                            ui.close();
+                        }
+                        if ui.button(tr!("projects.menu_copy_path")).clicked() {
+                            ui.ctx().copy_text(project.path.display().to_string());
+                            ui.close();
+                        }
+                        ui.separator();
+                        if ui
+                            .add_enabled(
+                                selection_enabled,
+                                egui::Button::new(tr!("projects.menu_ignore")),
+                            )
+                            .clicked()
+                        {
+                            *row_action = Some(ProjectRowAction::Ignore(project.path.clone()));
+                            ui.close();
+                        }
+                    });
                 });
             });
     }
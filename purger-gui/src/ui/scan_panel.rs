@@ -1,3 +1,4 @@
+use crate::disk_info::DiskInfo;
 use crate::state::{AppSettings, AppState};
 use crate::tr;
 use eframe::egui;
@@ -6,12 +7,14 @@ use eframe::egui;
 pub struct ScanPanel;
 
 impl ScanPanel {
+    #[allow(clippy::too_many_arguments)]
     pub fn show(
         ui: &mut egui::Ui,
         scan_path: &mut String,
         settings: &mut AppSettings,
         state: &AppState,
         can_stop_extra: bool,
+        disk_info: Option<&DiskInfo>,
         on_select_folder: &mut bool,
         on_start_scan: &mut bool,
         on_stop: &mut bool,
@@ -50,6 +53,7 @@ impl ScanPanel {
                 .selected_text(match settings.clean_strategy {
                     purger_core::CleanStrategy::CargoClean => tr!("strategy.cargo_clean"),
                     purger_core::CleanStrategy::DirectDelete => tr!("strategy.direct_delete"),
+                    purger_core::CleanStrategy::Trash => tr!("strategy.trash"),
                 })
                 .show_ui(ui, |ui| {
                     ui.selectable_value(
@@ -62,6 +66,11 @@ impl ScanPanel {
                         purger_core::CleanStrategy::DirectDelete,
                         tr!("scan.strategy_direct_delete"),
                     );
+                    ui.selectable_value(
+                        &mut settings.clean_strategy,
+                        purger_core::CleanStrategy::Trash,
+                        tr!("scan.strategy_trash"),
+                    );
                 });
 
             ui.separator();
@@ -86,6 +95,18 @@ impl ScanPanel {
             } else if can_stop_extra {
                 ui.label(tr!("scan.sizing_status"));
             }
+
+            if let Some(info) = disk_info {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let (free, total) = info.figures(crate::size_format::current_size_units());
+                    ui.add(
+                        egui::ProgressBar::new(info.used_fraction())
+                            .desired_width(80.0)
+                            .desired_height(10.0),
+                    );
+                    ui.label(tr!("scan.disk_space", free = free, total = total));
+                });
+            }
         });
     }
 }